@@ -0,0 +1,92 @@
+//! Benchmarks for the operation table behind `apply_generic`.
+//! `apply_generic` 所用操作表的基准测试。
+//!
+//! Run with `cargo bench --bench generic_dispatch`.
+
+use std::hint::black_box;
+
+use criterion::{Criterion, criterion_group, criterion_main};
+use sicp_rs::ch2::ch2_5::{
+    ArithmeticContext, install_arithmetic_package, install_dense_terms_package,
+    install_polynomial_coercion, install_polynomial_package, install_sparse_terms_package,
+    make_polynomial_from_dense,
+};
+use sicp_rs::ch3::ch3_3::make_table_2d;
+use sicp_rs::prelude::*;
+
+fn polynomial_context() -> ArithmeticContext {
+    let mut arith = ArithmeticContext::new();
+    install_arithmetic_package(&arith);
+    install_sparse_terms_package(&arith);
+    install_dense_terms_package(&arith);
+    install_polynomial_package(&arith);
+    install_polynomial_coercion(&mut arith);
+    arith
+}
+
+// 多项式 GCD 需要数千次通用操作分派
+fn bench_polynomial_gcd(c: &mut Criterion) {
+    let arith = polynomial_context();
+    let x = sym!(x).to_listv();
+    let p1 = make_polynomial_from_dense(&x, &list![1, -2, 1], &arith);
+    let p2 = make_polynomial_from_dense(&x, &list![11, 0, 7], &arith);
+    let p3 = make_polynomial_from_dense(&x, &list![13, 5], &arith);
+    let q1 = arith.mul(&p1, &p2);
+    let q2 = arith.mul(&p1, &p3);
+    c.bench_function("polynomial_gcd", |b| {
+        b.iter(|| arith.gcd(black_box(&q1), black_box(&q2)))
+    });
+}
+
+// 与 SICP 3.3.3 中基于关联表的二维表格（make_table_2d）对比单次查找
+fn bench_lookup(c: &mut Criterion) {
+    let arith = polynomial_context();
+    let legacy = make_table_2d();
+    let ops = [
+        "add",
+        "sub",
+        "mul",
+        "div",
+        "is_equal",
+        "is_equal_to_zero",
+        "negative",
+        "raise",
+        "project",
+        "gcd",
+        "reduce",
+        "make",
+        "first_term",
+        "rest_terms",
+        "adjoin_term",
+    ];
+    for op in ops {
+        for signature in arith.signatures(op).iter() {
+            legacy("insert").call(&list![op, signature.head(), signature.tail()]);
+        }
+    }
+    let keys = [
+        list!["add", list!["integer", "integer"]],
+        list!["mul", list!["polynomial", "polynomial"]],
+        list!["adjoin_term", list!["sparse", "sparse"]],
+    ];
+
+    let mut group = c.benchmark_group("op_table_lookup");
+    group.bench_function("hash_table", |b| {
+        b.iter(|| {
+            for key in &keys {
+                black_box(arith.get(black_box(key.clone())));
+            }
+        })
+    });
+    group.bench_function("alist_table_2d", |b| {
+        b.iter(|| {
+            for key in &keys {
+                black_box(legacy("lookup").call(black_box(key)));
+            }
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_polynomial_gcd, bench_lookup);
+criterion_main!(benches);
@@ -1,12 +1,10 @@
-use std::rc::Rc;
-
 use num::Num;
 use num::pow::Pow;
 use sicp_rs::ch2::ch2_3::{
     addend, augend, base, exponent, is_number, is_same_variable, is_variable, make_exp,
     make_product, make_sum, multiplicand, multiplier,
 };
-use sicp_rs::ch3::ch3_3::make_table_2d;
+use sicp_rs::ch3::ch3_3::{Table2d, make_table_2d};
 use sicp_rs::prelude::*;
 
 /// 获取表达式的运算符
@@ -21,7 +19,7 @@ fn _operands(exp: &List) -> List {
 }
 
 /// 通用的求导函数，基于数据导向分派
-fn deriv<T: Num + Clone + std::fmt::Debug + 'static>(
+fn deriv<T: Num + Clone + std::fmt::Debug + MaybeSendSync + 'static>(
     exp: &List,
     variable: &List,
     optable: &impl Fn(List) -> Option<List>,
@@ -36,19 +34,18 @@ fn deriv<T: Num + Clone + std::fmt::Debug + 'static>(
         }
     } else {
         // 从操作符表中获取对应的求导规则
-        if let Some(op) = optable(list!["deriv", operator(exp).clone()]) {
-            if let Ok(op) = op.try_as_basis_value::<ClosureWrapper>() {
-                if let Some(result) = op.call(&list![exp.clone(), variable.clone()]) {
-                    return result;
-                }
-            }
+        if let Some(op) = optable(list!["deriv", operator(exp).clone()])
+            && let Ok(op) = op.try_as_basis_value::<ClosureWrapper>()
+            && let Some(result) = op.call(&list![exp.clone(), variable.clone()])
+        {
+            return result;
         }
         panic!("unknown operator -- DERIV, exp {}", exp)
     }
 }
 
 /// 求和表达式的求导规则
-fn deriv_sum<T: Num + Clone + std::fmt::Debug + 'static>(
+fn deriv_sum<T: Num + Clone + std::fmt::Debug + MaybeSendSync + 'static>(
     exp: &List,
     variable: &List,
     optable: impl Fn(List) -> Option<List>,
@@ -60,7 +57,7 @@ fn deriv_sum<T: Num + Clone + std::fmt::Debug + 'static>(
 }
 
 /// 乘积表达式的求导规则
-fn deriv_product<T: Num + Clone + std::fmt::Debug + 'static>(
+fn deriv_product<T: Num + Clone + std::fmt::Debug + MaybeSendSync + 'static>(
     exp: &List,
     variable: &List,
     optable: impl Fn(List) -> Option<List>,
@@ -78,7 +75,7 @@ fn deriv_product<T: Num + Clone + std::fmt::Debug + 'static>(
 }
 
 /// 幂表达式的求导规则
-fn deriv_exp<T: Num + Clone + std::fmt::Debug + Pow<T, Output = T> + 'static>(
+fn deriv_exp<T: Num + Clone + std::fmt::Debug + Pow<T, Output = T> + MaybeSendSync + 'static>(
     exp: &List,
     variable: &List,
     optable: impl Fn(List) -> Option<List>,
@@ -97,20 +94,20 @@ fn deriv_exp<T: Num + Clone + std::fmt::Debug + Pow<T, Output = T> + 'static>(
 
 fn main() {
     // 创建操作符表
-    let optable: Rc<dyn Fn(&str) -> ClosureWrapper> = make_table_2d();
+    let optable: Table2d = make_table_2d();
     let op_cloned = optable.clone();
     let get = move |args: List| optable("lookup").call(&args);
     let put = move |args: List| op_cloned("insert").call(&args);
 
     // 安装求导规则
-    let install_rule = |op: String, rule: ClosureWrapper| {
+    let install_rule = |op: Symbol, rule: ClosureWrapper| {
         put(list!["deriv", op, rule]);
     };
 
     // 安装求和规则
     let get_cloned = get.clone();
     install_rule(
-        "+".to_string(),
+        sym!("+"),
         ClosureWrapper::new(move |args: &List| {
             Some(deriv_sum::<f64>(
                 &args.head(),
@@ -123,7 +120,7 @@ fn main() {
     // 安装乘积规则
     let get_cloned = get.clone();
     install_rule(
-        "*".to_string(),
+        sym!("*"),
         ClosureWrapper::new(move |args: &List| {
             Some(deriv_product::<f64>(
                 &args.head(),
@@ -136,7 +133,7 @@ fn main() {
     // 安装幂运算规则
     let get_cloned = get.clone();
     install_rule(
-        "**".to_string(),
+        sym!("**"),
         ClosureWrapper::new(move |args: &List| {
             Some(deriv_exp::<f64>(
                 &args.head(),
@@ -147,22 +144,26 @@ fn main() {
     );
 
     // 测试求导规则
-    let exp1 = list!("*", list!("*", "x", "y"), list!("+", "x", 4.0));
+    let exp1 = list!(
+        sym!("*"),
+        list!(sym!("*"), sym!(x), sym!(y)),
+        list!(sym!("+"), sym!(x), 4.0)
+    );
     println!(
         "{}",
-        deriv::<f64>(&exp1, &"x".to_listv(), &get).pretty_print()
+        deriv::<f64>(&exp1, &sym!(x).to_listv(), &get).pretty_print()
     );
 
-    let exp2 = list!("**", "x", list!("+", "y", 3.0));
+    let exp2 = list!(sym!("**"), sym!(x), list!(sym!("+"), sym!(y), 3.0));
     println!(
         "{}",
-        deriv::<f64>(&exp2, &"x".to_listv(), &get).pretty_print()
+        deriv::<f64>(&exp2, &sym!(x).to_listv(), &get).pretty_print()
     );
 
-    let exp3 = list!("**", "x", "n");
+    let exp3 = list!(sym!("**"), sym!(x), sym!(n));
     println!(
         "{}",
-        deriv::<f64>(&exp3, &"x".to_listv(), &get).pretty_print()
+        deriv::<f64>(&exp3, &sym!(x).to_listv(), &get).pretty_print()
     );
 }
 // Output
@@ -11,9 +11,9 @@ fn apply_generic(op: &List, args: &List, arith: &ArithmeticContext) -> Option<Li
         args.clone()
     };
     println!("apply generic op:{}, args:{}", op, args);
-    let type_tags = args.map(|x| type_tag(x));
+    let type_tags = args.map(type_tag);
     if let Some(op) = arith.get(list![op.clone(), type_tags]) {
-        op.call(&args.map(|x| contents(x)))
+        op.call(&args.map(contents))
     } else {
         panic!(
             "apply_generic no method for these types op:{}, args:{}",
@@ -34,7 +34,6 @@ fn magnitude(z: &List, arith: &ArithmeticContext) -> List {
 fn install_rectangular_package(arith: &ArithmeticContext) -> Option<List> {
     let tag = |x: &List| attach_tag("rectangular", x);
     arith.put("make_from_real_imag", list!["rectangular"], {
-        let tag = tag.clone();
         ClosureWrapper::new(move |args| {
             let (x, y) = (args.head(), args.tail().head());
             Some(tag(&pair!(x, y)))
@@ -49,7 +48,6 @@ fn install_rectangular_package(arith: &ArithmeticContext) -> Option<List> {
 
     let extract_real_imag = {
         let arith = arith.clone();
-        let tag = tag.clone();
         move |arg: &List| {
             // 使用 tag 函数重新附加数据类型标签：
             // apply_generic 在处理参数时会移除类型标签，
@@ -78,7 +76,6 @@ fn install_rectangular_package(arith: &ArithmeticContext) -> Option<List> {
 fn install_complex_package(arith: &ArithmeticContext) -> Option<List> {
     let tag = |x| attach_tag("complex", &x);
     arith.put("make_from_real_imag", list!["complex"], {
-        let tag = tag.clone();
         let arith = arith.clone();
         ClosureWrapper::new(move |args| {
             let (x, y) = (args.head(), args.tail().head());
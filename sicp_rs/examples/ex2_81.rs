@@ -8,7 +8,7 @@ use sicp_rs::{
 };
 
 fn exp(x: &List, y: &List, arith: &ArithmeticContext) -> List {
-    apply_generic(&"exp".to_listv(), &list![x.clone(), y.clone()], &arith).unwrap()
+    apply_generic(&"exp".to_listv(), &list![x.clone(), y.clone()], arith).unwrap()
 }
 fn main() {
     // 创建通用算术包上下文
@@ -44,7 +44,7 @@ fn main() {
         complex_to_complex,
     );
     arith.put(
-        &"exp",
+        "exp",
         list!["float", "float"],
         ClosureWrapper::new({
             let arith = arith.clone();
@@ -122,8 +122,7 @@ fn test_transform() {
     ];
 
     // 获取函数签名
-    let func_name = "complex_func".to_string().to_listv();
-    let func_types = get_func_argtypes(&func_name, input_args.length(), &arith);
+    let func_types = get_func_argtypes("complex_func", input_args.length(), &arith);
 
     // 执行参数转换
     let (transformed_args, selected_func) = transform(&input_args, func_types, &arith);
@@ -152,13 +151,13 @@ fn transform(
     let mut best_func = None;
     let mut func_types = func_types.clone();
     // func_types: list[pair[list[type1,type2,type3...],some_func],...]
-    while func_types.is_empty() == false {
+    while !func_types.is_empty() {
         let fun = func_types.head();
 
         let func = fun.tail();
         let (cost, flag, results) =
-            transform_argtypes(&input_args, fun, &arith.coercion.borrow().clone(), List::Nil, 0);
-        if flag == true && cost < min_cost {
+            transform_argtypes(input_args, fun, &arith.coercions(), List::Nil, 0);
+        if flag && cost < min_cost {
             min_cost = cost;
             println!("min arg tranforms: {}", min_cost);
             best_results = Some(results);
@@ -166,7 +165,7 @@ fn transform(
         }
         func_types = func_types.tail();
     }
-    return (best_results, best_func);
+    (best_results, best_func)
 }
 // 处理单个函数签名，并返回成本和转换后的参数列表。
 fn transform_argtypes(
@@ -194,8 +193,8 @@ fn transform_argtypes(
         // func_types: pair[list[type2,type3...],some_func]
         let func_argtypes = list![func_argtypes.head().tail(), func_argtypes.tail()];
         let (cost, flag, results) =
-            transform_argtypes(&input_args, func_argtypes, &coercion, results, cost);
-        return (cost, flag, results.clone());
+            transform_argtypes(&input_args, func_argtypes, coercion, results, cost);
+        (cost, flag, results.clone())
     } else {
         // 当前参数不匹配，尝试转换参数类型，可能需要经过多次转换。
         use std::collections::HashSet;
@@ -232,14 +231,14 @@ fn transform_argtypes(
                 }
                 argtypes = argtypes.tail();
             }
-            return (cost, None);
+            (cost, None)
         }
         let (new_cost, trans_arg) = dfs(
             &type_x,
             input_args.head().head(),
             &func_argtypes.head().head(),
             &mut visited,
-            &coercion,
+            coercion,
             cost,
         );
         if let Some(trans_arg) = trans_arg {
@@ -252,22 +251,22 @@ fn transform_argtypes(
             // func_types: list[list[type2,type3...],some_func]
             let func_argtypes = list![func_argtypes.head().tail(), func_argtypes.tail()];
             let (cost, flag, results) =
-                transform_argtypes(&input_args, func_argtypes, &coercion, results, new_cost);
-            return (cost, flag, results.clone());
+                transform_argtypes(&input_args, func_argtypes, coercion, results, new_cost);
+            (cost, flag, results.clone())
         } else {
-            return (cost, false, List::Nil);
+            (cost, false, List::Nil)
         }
     }
 }
 // 获取函数的所有可能参数类型列表
-fn get_func_argtypes(func_name: &List, args_len: usize, arith: &ArithmeticContext) -> List {
-    let optable = arith.optable.clone();
-    let assoc = move |args: List| optable("assoc").call(&args);
-    let args = assoc(list![func_name.clone()]);
-    let msg = format!("get_func_argtypes: func_name {} not found", func_name);
-    args.expect(&msg)
-        .tail()
-        .filter(|args| args.head().length() == args_len)
+fn get_func_argtypes(func_name: &str, args_len: usize, arith: &ArithmeticContext) -> List {
+    let args = arith.signatures(func_name);
+    assert!(
+        !args.is_empty(),
+        "get_func_argtypes: func_name {} not found",
+        func_name
+    );
+    args.filter(|args| args.head().length() == args_len)
 }
 
 // 获取参数可转换类型列表
@@ -312,10 +311,7 @@ fn _test_get_func_argtypes() {
             Some("some_func(type2,type5,type4)".to_string().to_listv())
         }),
     );
-    println!(
-        "{}",
-        get_func_argtypes(&"some_func".to_string().to_listv(), 3, &arith)
-    )
+    println!("{}", get_func_argtypes("some_func", 3, &arith))
     //((("type2", ("type5", ("type4", Nil))), A closure wrapped in ClosureWrapper), ((("type1", ("type2", ("type3", Nil))), A closure wrapped in ClosureWrapper), Nil))
 }
 fn _test_get_type_coercion() {
@@ -347,7 +343,7 @@ fn _test_get_type_coercion() {
     );
     println!(
         "{}",
-        get_type_coercion(&"type1".to_listv(), &arith.coercion.borrow())
+        get_type_coercion(&"type1".to_listv(), &arith.coercions())
     )
     //(("type4", (A closure wrapped in ClosureWrapper, Nil)), (("type2", (A closure wrapped in ClosureWrapper, Nil)), Nil))
 }
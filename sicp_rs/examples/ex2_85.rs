@@ -14,11 +14,8 @@ fn main() {
     // 创建测试数据
     let complex1 = make_complex_from_real_imag(1.5.to_listv(), 0.0.to_listv(), &arith);
     let complex2 = make_complex_from_real_imag(1.3.to_listv(), 0.0.to_listv(), &arith);
-    let complex3 = make_complex_from_real_imag(
-        0.333333333333333333333333333.to_listv(),
-        0.0.to_listv(),
-        &arith,
-    );
+    let complex3 =
+        make_complex_from_real_imag(0.333_333_333_333_333_3.to_listv(), 0.0.to_listv(), &arith);
     let complex4 = make_complex_from_real_imag(1.0.to_listv(), 0.0.to_listv(), &arith);
     let complex5 = make_complex_from_real_imag(2.0.to_listv(), 3.0.to_listv(), &arith);
 
@@ -14,7 +14,7 @@ fn main() {
     install_polynomial_package(&arith);
 
     let p1 = make_polynomial_from_sparse(
-        &"x".to_listv(),
+        &sym!(x).to_listv(),
         &list![
             make_term(2.to_listv(), make_integer(4, &arith)),
             make_term(1.to_listv(), make_integer(3, &arith)),
@@ -23,7 +23,7 @@ fn main() {
         &arith,
     );
     let p2 = make_polynomial_from_sparse(
-        &"x".to_listv(),
+        &sym!(x).to_listv(),
         &list![
             make_term(2.to_listv(), make_integer(5, &arith)),
             make_term(1.to_listv(), make_float(2.0, &arith)),
@@ -48,7 +48,7 @@ fn main() {
         pretty_polynomial(&arith.mul(&p1, &p2), &arith)
     );
     let p3 = make_polynomial_from_sparse(
-        &"x".to_listv(),
+        &sym!(x).to_listv(),
         &list![
             make_term(2.to_listv(), make_integer(5, &arith)),
             make_term(1.to_listv(), p2.clone()),
@@ -58,7 +58,7 @@ fn main() {
     );
     println!("polynomial as coeff: {}\n", pretty_polynomial(&p3, &arith));
     let zero = make_polynomial_from_sparse(
-        &"x".to_listv(),
+        &sym!(x).to_listv(),
         &list![
             make_term(2.to_listv(), make_integer(0, &arith)),
             make_term(1.to_listv(), make_integer(0, &arith)),
@@ -67,7 +67,7 @@ fn main() {
         &arith,
     );
     let p3 = make_polynomial_from_sparse(
-        &"x".to_listv(),
+        &sym!(x).to_listv(),
         &list![
             make_term(2.to_listv(), make_integer(5, &arith)),
             make_term(1.to_listv(), zero.clone()),
@@ -85,9 +85,9 @@ fn main() {
         pretty_polynomial(&p3, &arith),
         pretty_polynomial(&arith.add(&p3, &p3), &arith)
     );
-    let zero = make_polynomial_from_sparse(&"x".to_listv(), &List::Nil, &arith);
+    let zero = make_polynomial_from_sparse(&sym!(x).to_listv(), &List::Nil, &arith);
     let p3 = make_polynomial_from_sparse(
-        &"x".to_listv(),
+        &sym!(x).to_listv(),
         &list![
             make_term(2.to_listv(), make_integer(5, &arith)),
             make_term(1.to_listv(), p2),
@@ -102,7 +102,7 @@ fn main() {
         pretty_polynomial(&p3, &arith),
         pretty_polynomial(&p3, &arith),
         pretty_polynomial(&p3, &arith),
-        pretty_polynomial(&arith.add(&p3, &p3), &arith)
+        pretty_polynomial(&arith.add(&p3, &p3), &arith),
         // p3 + p1 will panic because now not support raising numbers to polynomials.
         // pretty_polynomial(&arith.add(&p3, &p1), &arith)
     );
@@ -14,7 +14,7 @@ fn main() {
     install_polynomial_package(&arith);
 
     let p1 = make_polynomial_from_sparse(
-        &"x".to_listv(),
+        &sym!(x).to_listv(),
         &list![
             make_term(2.to_listv(), make_integer(4, &arith)),
             make_term(1.to_listv(), make_integer(3, &arith)),
@@ -23,7 +23,7 @@ fn main() {
         &arith,
     );
     let p2 = make_polynomial_from_sparse(
-        &"x".to_listv(),
+        &sym!(x).to_listv(),
         &list![
             make_term(2.to_listv(), make_integer(5, &arith)),
             make_term(1.to_listv(), make_float(2.0, &arith)),
@@ -52,60 +52,57 @@ fn adjoin_term_dense(term: List, term_list: List, arith: &ArithmeticContext) ->
     }
 }
 fn add_terms_dense(l1: &List, l2: &List, arith: &ArithmeticContext) -> List {
-    if is_empty_term_list(&l1) {
+    if is_empty_term_list(l1) {
         l2.clone()
-    } else if is_empty_term_list(&l2) {
+    } else if is_empty_term_list(l2) {
         l1.clone()
     } else {
-        let t1 = first_term_dense(&l1);
-        let t2 = first_term_dense(&l2);
+        let t1 = first_term_dense(l1);
+        let t2 = first_term_dense(l2);
         if order(&t1).get_basis_value() > order(&t2).get_basis_value() {
-            let x = adjoin_term_dense(
+            adjoin_term_dense(
                 t1.clone(),
-                add_terms_dense(&rest_terms(&l1), l2, &arith),
-                &arith,
-            );
-            x
+                add_terms_dense(&rest_terms(l1), l2, arith),
+                arith,
+            )
         } else if order(&t1).get_basis_value() < order(&t2).get_basis_value() {
-            let x = adjoin_term_dense(
+            adjoin_term_dense(
                 t2.clone(),
-                add_terms_dense(l1, &rest_terms(&l2), &arith),
-                &arith,
-            );
-            x
+                add_terms_dense(l1, &rest_terms(l2), arith),
+                arith,
+            )
         } else {
-            let x = adjoin_term_dense(
+            adjoin_term_dense(
                 make_term(order(&t1), arith.add(&coeff(&t1), &coeff(&t2))),
-                add_terms_dense(&rest_terms(&l1), &rest_terms(&l2), &arith),
-                &arith,
-            );
-            x
+                add_terms_dense(&rest_terms(l1), &rest_terms(l2), arith),
+                arith,
+            )
         }
     }
 }
 pub fn mul_term_by_all_terms_dense(t1: &List, l: &List, arith: &ArithmeticContext) -> List {
-    if is_empty_term_list(&l) {
+    if is_empty_term_list(l) {
         List::Nil
     } else {
-        let t2 = first_term_dense(&l);
+        let t2 = first_term_dense(l);
         adjoin_term_dense(
             make_term(
-                arith.add(&order(&t1), &order(&t2)),
-                arith.mul(&coeff(&t1), &coeff(&t2)),
+                arith.add(&order(t1), &order(&t2)),
+                arith.mul(&coeff(t1), &coeff(&t2)),
             ),
-            mul_term_by_all_terms_dense(t1, &rest_terms(&l), &arith),
-            &arith,
+            mul_term_by_all_terms_dense(t1, &rest_terms(l), arith),
+            arith,
         )
     }
 }
 pub fn mul_terms_dense(l1: &List, l2: &List, arith: &ArithmeticContext) -> List {
-    if is_empty_term_list(&l1) {
+    if is_empty_term_list(l1) {
         List::Nil
     } else {
         add_terms_dense(
-            &mul_term_by_all_terms_dense(&first_term_dense(&l1), &l2, &arith),
-            &mul_terms_dense(&rest_terms(&l1), &l2, &arith),
-            &arith,
+            &mul_term_by_all_terms_dense(&first_term_dense(l1), l2, arith),
+            &mul_terms_dense(&rest_terms(l1), l2, arith),
+            arith,
         )
     }
 }
@@ -130,7 +127,7 @@ pub fn pretty_polynomial_dense(p: &List) -> String {
             )
         }
     }
-    if term_list(&contents(&p)).is_empty() {
+    if term_list(&contents(p)).is_empty() {
         format!("({}:{})", type_tag(p), term_list(&contents(p)))
     } else {
         format!("({}:{})", type_tag(p), iter(&term_list(&contents(p))))
@@ -157,7 +154,7 @@ pub fn install_polynomial_dense_package(arith: &ArithmeticContext) -> Option<Lis
         if is_same_variable(&variable(p1), &variable(p2)) {
             make_poly(
                 variable(p1),
-                add_terms_dense(&term_list(p1), &term_list(p2), &arith),
+                add_terms_dense(&term_list(p1), &term_list(p2), arith),
             )
         } else {
             panic!(
@@ -171,7 +168,7 @@ pub fn install_polynomial_dense_package(arith: &ArithmeticContext) -> Option<Lis
         if is_same_variable(&variable(p1), &variable(p2)) {
             make_poly(
                 variable(p1),
-                mul_terms_dense(&term_list(p1), &term_list(p2), &arith),
+                mul_terms_dense(&term_list(p1), &term_list(p2), arith),
             )
         } else {
             panic!(
@@ -189,17 +186,17 @@ pub fn install_polynomial_dense_package(arith: &ArithmeticContext) -> Option<Lis
             if arith.is_equal_to_zero(&coeff(&t)) == false.to_listv() {
                 false.to_listv()
             } else {
-                is_equal_to_zero(&rest_terms(term_list), &arith)
+                is_equal_to_zero(&rest_terms(term_list), arith)
             }
         }
     }
     fn negative_terms(l: &List, arith: &ArithmeticContext) -> List {
-        if is_empty_term_list(&l) {
+        if is_empty_term_list(l) {
             List::Nil
         } else {
-            let t1 = first_term_dense(&l);
+            let t1 = first_term_dense(l);
             let t1 = make_term(order(&t1), arith.negative(&coeff(&t1)));
-            adjoin_term_dense(t1, negative_terms(&rest_terms(&l), &arith), &arith)
+            adjoin_term_dense(t1, negative_terms(&rest_terms(l), arith), arith)
         }
     }
     fn tag(x: &List) -> List {
@@ -267,7 +264,7 @@ fn main() {
 
     // 创建两个稠密多项式
     let p1 = make_polynomial_from_dense(
-        &"x".to_listv(),
+        &sym!(x).to_listv(),
         &list![
             // x^4 + 2x^3 + 0x^2 + 3x^1 + -2x^0
             make_integer(1, &arith),  // x^4
@@ -280,7 +277,7 @@ fn main() {
     );
 
     let p2 = make_polynomial_from_dense(
-        &"x".to_listv(),
+        &sym!(x).to_listv(),
         &list![5, 0, -1, 4], // 5x^3 + 0x^2 -x^1 + 4
         &arith,
     );
@@ -33,8 +33,8 @@ fn main() {
         ],
         &arith,
     );
-    let sparse_poly = make_polynomial_from_sparse(&"x".to_listv(), &sparse_term_list, &arith);
-    let dense_poly = make_polynomial_from_dense(&"x".to_listv(), &dense_term_list, &arith);
+    let sparse_poly = make_polynomial_from_sparse(&sym!(x).to_listv(), &sparse_term_list, &arith);
+    let dense_poly = make_polynomial_from_dense(&sym!(x).to_listv(), &dense_term_list, &arith);
     println!("Sparse Polynomial: {}", sparse_poly);
     println!(
         "Sparse Polynomial: {}",
@@ -104,14 +104,14 @@ fn main() {
     // 测试7：零多项式判定
     println!("\n==== Test 7: Zero Polynomial Detection ====");
     let zero_poly =
-        make_polynomial_from_sparse(&"x".to_listv(), &make_empty_term_list(&arith), &arith);
+        make_polynomial_from_sparse(&sym!(x).to_listv(), &make_empty_term_list(&arith), &arith);
     println!("Zero Polynomial: {}", pretty_polynomial(&zero_poly, &arith));
     println!("Is Zero Polynomial: {}", arith.is_equal_to_zero(&zero_poly));
 
     // 测试8：复杂嵌套多项式
     println!("\n==== Test 8: Nested Polynomials ====");
     let nested_poly = make_polynomial_from_sparse(
-        &"y".to_listv(),
+        &sym!(y).to_listv(),
         &make_terms_from_sparse(
             &list![
                 make_term(1.to_listv(), sparse_poly.clone()),
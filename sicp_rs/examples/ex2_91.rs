@@ -18,7 +18,7 @@ fn main() {
     // 测试1：多项式除法
     println!("==== Test 1: Polynomial Division ====");
     let dividend_sparse = make_polynomial_from_sparse(
-        &"x".to_listv(),
+        &sym!(x).to_listv(),
         &make_terms_from_sparse(
             &list![
                 make_term(5.to_listv(), 1.to_listv()),    // x^5
@@ -30,7 +30,7 @@ fn main() {
     );
 
     let divisor_sparse = make_polynomial_from_sparse(
-        &"x".to_listv(),
+        &sym!(x).to_listv(),
         &make_terms_from_sparse(
             &list![
                 make_term(2.to_listv(), 1.to_listv()),    // x^2
@@ -78,7 +78,7 @@ fn main() {
     // 测试2：零多项式除法
     println!("\n==== Test 2: Zero Polynomial Division ====");
     let zero_poly =
-        make_polynomial_from_sparse(&"x".to_listv(), &make_empty_term_list(&arith), &arith);
+        make_polynomial_from_sparse(&sym!(x).to_listv(), &make_empty_term_list(&arith), &arith);
 
     let zero_div_result = arith.div(&zero_poly, &divisor_sparse);
     let zero_quotient = zero_div_result.head();
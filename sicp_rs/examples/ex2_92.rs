@@ -21,7 +21,7 @@ fn main() {
     println!("==== Test 1: Polynomial Addition with Different Variables ====");
     // 多项式1：x^2 + 2x + 1
     let poly1 = make_polynomial_from_sparse(
-        &"x".to_listv(),
+        &sym!(x).to_listv(),
         &make_terms_from_sparse(
             &list![
                 make_term(2.to_listv(), 1.to_listv()), // x^2
@@ -34,7 +34,7 @@ fn main() {
     );
     // 多项式2：y^3 + y
     let poly2 = make_polynomial_from_sparse(
-        &"y".to_listv(),
+        &sym!(y).to_listv(),
         &make_terms_from_sparse(
             &list![
                 make_term(3.to_listv(), 1.to_listv()), // y^3
@@ -57,7 +57,7 @@ fn main() {
     println!("\n==== Test 2: Polynomial Multiplication with Different Variables ====");
     // 多项式3：z^2 + 3z + 2
     let poly3 = make_polynomial_from_sparse(
-        &"z".to_listv(),
+        &sym!(z).to_listv(),
         &make_terms_from_sparse(
             &list![
                 make_term(2.to_listv(), 1.to_listv()), // z^2
@@ -95,7 +95,7 @@ fn main() {
     println!("\n==== Test 4: Polynomial with Nested Coefficients ====");
     // 嵌套多项式：x^2 + (y^2 + y)*x + (y^3 + y)
     let nested_coeff = make_polynomial_from_sparse(
-        &"y".to_listv(),
+        &sym!(y).to_listv(),
         &make_terms_from_sparse(
             &list![
                 make_term(2.to_listv(), 1.to_listv()), // y^2
@@ -107,7 +107,7 @@ fn main() {
     );
 
     let nested_poly = make_polynomial_from_sparse(
-        &"x".to_listv(),
+        &sym!(x).to_listv(),
         &make_terms_from_sparse(
             &list![
                 make_term(2.to_listv(), 1.to_listv()),         // x^2
@@ -126,7 +126,7 @@ fn main() {
     println!("\n==== Test 5: Rational Function Operations ====");
     // 有理函数1： (x + 1) / (x^3 - 1)
     let numerator1 = make_polynomial_from_sparse(
-        &"x".to_listv(),
+        &sym!(x).to_listv(),
         &make_terms_from_sparse(
             &list![
                 make_term(1.to_listv(), 1.to_listv()), // x
@@ -138,7 +138,7 @@ fn main() {
     );
 
     let denominator1 = make_polynomial_from_sparse(
-        &"x".to_listv(),
+        &sym!(x).to_listv(),
         &make_terms_from_sparse(
             &list![
                 make_term(3.to_listv(), 1.to_listv()),    // x^3
@@ -153,7 +153,7 @@ fn main() {
 
     // 有理函数2： (x^2 + 2x^2 + 3x + 1) / (x^3 - x - 1)
     let numerator2 = make_polynomial_from_sparse(
-        &"x".to_listv(),
+        &sym!(x).to_listv(),
         &make_terms_from_sparse(
             &list![
                 make_term(2.to_listv(), 2.to_listv()), // 2x^2
@@ -166,7 +166,7 @@ fn main() {
     );
 
     let denominator2 = make_polynomial_from_sparse(
-        &"x".to_listv(),
+        &sym!(x).to_listv(),
         &make_terms_from_sparse(
             &list![
                 make_term(3.to_listv(), 1.to_listv()),    // x^3
@@ -232,13 +232,19 @@ fn main() {
     // 有理函数乘法
     //let rational_mul = arith.mul(&rational1, &rational2);
     println!("Rational Multiplication Result:");
-        println!(
+    println!(
         "  Numerator: {}",
-        pretty_polynomial(&arith.mul(&arith.numer(&rational1), &arith.numer(&rational2)), &arith)
+        pretty_polynomial(
+            &arith.mul(&arith.numer(&rational1), &arith.numer(&rational2)),
+            &arith
+        )
     );
     println!(
         "  Denominator: {}",
-        pretty_polynomial(&arith.mul(&arith.denom(&rational1), &arith.denom(&rational2)), &arith)
+        pretty_polynomial(
+            &arith.mul(&arith.denom(&rational1), &arith.denom(&rational2)),
+            &arith
+        )
     );
     // println!(
     //     "  Numerator: {}",
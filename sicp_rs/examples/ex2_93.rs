@@ -22,7 +22,7 @@ fn main() {
 
     // 多项式 p1: x^2 + 1
     let p1 = make_polynomial_from_sparse(
-        &"x".to_listv(),
+        &sym!(x).to_listv(),
         &make_terms_from_sparse(
             &list![
                 make_term(2.to_listv(), 1.to_listv()), // x^2
@@ -35,7 +35,7 @@ fn main() {
 
     // 多项式 p2: x^3 + 1
     let p2 = make_polynomial_from_sparse(
-        &"x".to_listv(),
+        &sym!(x).to_listv(),
         &make_terms_from_sparse(
             &list![
                 make_term(3.to_listv(), 1.to_listv()), // x^3
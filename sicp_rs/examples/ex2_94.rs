@@ -21,7 +21,7 @@ fn main() {
 
     // 测试两个多项式的最大公约数
     let p1 = make_polynomial_from_sparse(
-        &"x".to_listv(),
+        &sym!(x).to_listv(),
         &make_terms_from_sparse(
             &list![
                 make_term(4.to_listv(), 1.to_listv()),    // x^4
@@ -35,7 +35,7 @@ fn main() {
     );
 
     let p2 = make_polynomial_from_sparse(
-        &"x".to_listv(),
+        &sym!(x).to_listv(),
         &make_terms_from_sparse(
             &list![
                 make_term(3.to_listv(), 1.to_listv()),    // x^3
@@ -57,7 +57,7 @@ fn main() {
     let integer = 2.to_listv();
 
     let poly = make_polynomial_from_sparse(
-        &"x".to_listv(),
+        &sym!(x).to_listv(),
         &make_terms_from_sparse(
             &list![
                 make_term(2.to_listv(), 2.to_listv()), // 2x^2
@@ -22,13 +22,13 @@ fn main() {
 
     // 测试两个多项式的最大公约数
     let p1 = make_polynomial_from_sparse(
-        &"x".to_listv(),
+        &sym!(x).to_listv(),
         &make_terms_from_dense(&list![1, -2, 1], &arith), // 1*x^2 + (-2)*x^1 + 1*x^0
         &arith,
     );
 
     let p2 = make_polynomial_from_sparse(
-        &"x".to_listv(),
+        &sym!(x).to_listv(),
         &make_terms_from_sparse(
             &list![
                 make_term(2.to_listv(), 11.to_listv()),  // 11*x^2
@@ -39,7 +39,7 @@ fn main() {
         &arith,
     );
     let p3 = make_polynomial_from_sparse(
-        &"x".to_listv(),
+        &sym!(x).to_listv(),
         &make_terms_from_sparse(
             &list![
                 make_term(1.to_listv(), 13.to_listv()),  // 13*x^1
@@ -33,7 +33,7 @@ fn main() {
     println!("\n==== Testing Polynomial Reduction ====");
     let p1 = make_polynomial_from_sparse(
         // x + 1
-        &"x".to_listv(),
+        &sym!(x).to_listv(),
         &make_terms_from_sparse(
             &list![
                 make_term(1.to_listv(), 1.to_listv()), // x^1
@@ -46,7 +46,7 @@ fn main() {
 
     let p2 = make_polynomial_from_sparse(
         // x^3 - 1 = (x-1)(x^2 + x + 1)
-        &"x".to_listv(),
+        &sym!(x).to_listv(),
         &make_terms_from_sparse(
             &list![
                 make_term(3.to_listv(), 1.to_listv()),    // x^3
@@ -73,7 +73,7 @@ fn main() {
     println!("\n==== Testing Polynomial Rational Reduction ====");
     let p3 = make_polynomial_from_sparse(
         // x^1
-        &"x".to_listv(),
+        &sym!(x).to_listv(),
         &make_terms_from_sparse(
             &list![
                 make_term(1.to_listv(), 1.to_listv()), // x^1
@@ -85,7 +85,7 @@ fn main() {
 
     let p4 = make_polynomial_from_sparse(
         // x^2 - 1
-        &"x".to_listv(),
+        &sym!(x).to_listv(),
         &make_terms_from_sparse(
             &list![
                 make_term(2.to_listv(), 1.to_listv()),    // x^2
@@ -13,4 +13,4 @@ fn main() {
 }
 // (1, (2, (3, (4, Nil))))
 // (2, Nil)
-// (2, (3, (4, Nil)))
\ No newline at end of file
+// (2, (3, (4, Nil)))
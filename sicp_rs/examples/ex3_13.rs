@@ -51,14 +51,14 @@ fn create_cons() -> NewList {
 }
 
 fn test() {
-    let cons = create_cons(); 
+    let cons = create_cons();
 
     println!("\nInside test:");
     match &cons {
         NewList::Cons(node1, node2) => {
             println!(
                 "node1 strong_count: {} (held by cons)",
-                Rc::strong_count(&node1)
+                Rc::strong_count(node1)
             );
             println!(
                 "node2 value: {:?} upgrade: {:?} weak_count: {}",
@@ -30,7 +30,7 @@ fn main() {
         ])
     );
 
-    let y = pair![1,2];
+    let y = pair![1, 2];
     y.set_tail(y.clone());
     // println!("never return: {}", count_pairs(y));
 }
@@ -38,4 +38,4 @@ fn main() {
 // pairs: 4
 // pairs: 5
 // pairs: 6
-// pairs: 7
\ No newline at end of file
+// pairs: 7
@@ -1,11 +1,10 @@
+use sicp_rs::list_impl::ByIdentity;
 use sicp_rs::prelude::*;
 use std::collections::HashSet;
 
-fn count_pairs(x: List, visited: &mut HashSet<u64>) -> i32 {
-    let x_unique_id = x.unique_id(); // unique_id 实际上底层数据指针
-
-    if x.is_pair() && !visited.contains(&x_unique_id) {
-        visited.insert(x_unique_id);
+fn count_pairs(x: List, visited: &mut HashSet<ByIdentity>) -> i32 {
+    // ByIdentity 按序对的指针比较，即 eq?
+    if x.is_pair() && visited.insert(ByIdentity(x.clone())) {
         1 + count_pairs(x.head(), visited) + count_pairs(x.tail(), visited)
     } else {
         0
@@ -52,4 +51,4 @@ fn main() {
 // pairs: 4
 // pairs: 4
 // pairs: 4
-// never return: 1
\ No newline at end of file
+// never return: 1
@@ -1,28 +1,27 @@
 use std::collections::HashSet;
 
+use sicp_rs::list_impl::ByIdentity;
 use sicp_rs::prelude::*;
 
 fn has_cycle(x: List) -> bool {
-    fn iter(a: List, visited: &mut HashSet<u64>) -> bool {
+    fn iter(a: List, visited: &mut HashSet<ByIdentity>) -> bool {
         if a.is_pair() {
-            // println!("a unique: {}", a.unique_id());
             // if a.head().is_value() {
             //     println!("a head value {}", a.head())
             // }
             // if a.tail().is_value() {
             //     println!("a tail value {}", a.tail())
             // }
-            if visited.contains(&a.unique_id()) {
+            if !visited.insert(ByIdentity(a.clone())) {
                 return true;
             }
-            visited.insert(a.unique_id());
 
             (
                 // 若head也是pair，尝试遍历并查看是否会构成环路。
                 // 为避免误判共享子结构，构建新的visited，并从此节点开始，若能重新回到该节点，即为有环。
                 a.head().is_pair() && {
                     let mut new_visited = HashSet::new();
-                    new_visited.insert(a.unique_id());
+                    new_visited.insert(ByIdentity(a.clone()));
                     iter(a.head(), &mut new_visited)
                 }
             ) || (a.tail().is_pair() && iter(a.tail(), visited))
@@ -1,9 +1,9 @@
 use sicp_rs::prelude::*;
 
 fn main() {
-    let x =  pair!(1,2);
-    let z = pair!(x.clone(),x.clone());
+    let x = pair!(1, 2);
+    let z = pair!(x.clone(), x.clone());
     z.tail().set_head(17.to_listv());
 
     println!("{}", z);
-}
\ No newline at end of file
+}
@@ -1,22 +1,21 @@
-use std::{cell::RefCell, rc::Rc};
-
+use sicp_rs::list_impl::{InnerCell, InnerCellExt, Shared};
 use sicp_rs::prelude::*;
 
 fn make_queue() -> impl Fn(&str) -> ClosureWrapper {
-    let front_ptr = Rc::new(RefCell::new(List::Nil));
-    let rear_ptr = Rc::new(RefCell::new(List::Nil));
+    let front_ptr = Shared::new(InnerCell::new(List::Nil));
+    let rear_ptr = Shared::new(InnerCell::new(List::Nil));
 
     let set_front_ptr = {
         let front_ptr = front_ptr.clone();
-        move |item: List| *front_ptr.clone().borrow_mut() = item
+        move |item: List| *front_ptr.clone().write_cell() = item
     };
     let set_rear_ptr = {
         let rear_ptr = rear_ptr.clone();
-        move |item: List| *rear_ptr.clone().borrow_mut() = item
+        move |item: List| *rear_ptr.clone().write_cell() = item
     };
     let is_empty_queue = {
         let front_ptr = front_ptr.clone();
-        move || front_ptr.borrow().is_empty()
+        move || front_ptr.read_cell().is_empty()
     };
     let insert_queue = {
         let is_empty_queue = is_empty_queue.clone();
@@ -29,7 +28,7 @@ fn make_queue() -> impl Fn(&str) -> ClosureWrapper {
                 set_front_ptr(new_pair.clone());
                 set_rear_ptr(new_pair.clone());
             } else {
-                rear_ptr.borrow().set_tail(new_pair.clone());
+                rear_ptr.read_cell().set_tail(new_pair.clone());
                 set_rear_ptr(new_pair.clone());
             }
         }
@@ -42,13 +41,13 @@ fn make_queue() -> impl Fn(&str) -> ClosureWrapper {
             if is_empty_queue() {
                 panic!("delete_queue called with an empty queue");
             } else {
-                let new_front_ptr = front_ptr.borrow().tail();
+                let new_front_ptr = front_ptr.read_cell().tail();
                 set_front_ptr(new_front_ptr.clone());
             }
         }
     };
 
-    let dispatch = move |msg: &str| match msg {
+    move |msg: &str| match msg {
         "insert_queue" => {
             let insert_queue = insert_queue.clone();
             ClosureWrapper::new(move |item: &List| {
@@ -66,13 +65,12 @@ fn make_queue() -> impl Fn(&str) -> ClosureWrapper {
         "print_queue" => {
             let front_ptr = front_ptr.clone();
             ClosureWrapper::new(move |_| {
-                println!("{}", front_ptr.borrow());
+                println!("{}", *front_ptr.read_cell());
                 Some("ok".to_listv())
             })
         }
         _ => panic!("unknown message"),
-    };
-    dispatch
+    }
 }
 fn main() {
     let q1 = make_queue();
@@ -1,20 +1,18 @@
-use std::{cell::RefCell, rc::Rc};
-
+use sicp_rs::list_impl::{InnerCell, InnerCellExt, Shared};
 use sicp_rs::prelude::*;
 fn extract_value(x: &List) -> i32 {
-    x.try_as_basis_value::<i32>()
+    *x.try_as_basis_value::<i32>()
         .expect("Expected an i32 value")
-        .clone()
 }
 fn make_account(balance: i32, passwd: String) -> impl FnMut(&str, &str) -> List {
-    let balance = Rc::new(RefCell::new(balance));
+    let balance = Shared::new(InnerCell::new(balance));
     let mut cnt = 0;
 
     let withdraw = {
         let balance = balance.clone();
-        Rc::new(move |x: &List| {
+        Shared::new(move |x: &List| {
             let x = extract_value(x);
-            let mut b = balance.borrow_mut();
+            let mut b = balance.write_cell();
             if *b < x {
                 return "Insufficient funds".to_listv();
             };
@@ -24,9 +22,9 @@ fn make_account(balance: i32, passwd: String) -> impl FnMut(&str, &str) -> List
     };
     let deposit = {
         let balance = balance.clone();
-        Rc::new(move |x: &List| {
+        Shared::new(move |x: &List| {
             let x = extract_value(x);
-            let mut b = balance.borrow_mut();
+            let mut b = balance.write_cell();
             *b += x;
             (*b).to_listv()
         })
@@ -40,7 +38,8 @@ fn make_account(balance: i32, passwd: String) -> impl FnMut(&str, &str) -> List
             "Incorrect password".to_listv()
         }
     };
-    let dispatch = {
+
+    {
         move |pass: &str, m: &str| {
             if pass != passwd.as_str() {
                 return handle_incorrect_password();
@@ -60,8 +59,7 @@ fn make_account(balance: i32, passwd: String) -> impl FnMut(&str, &str) -> List
                 _ => "Unknown request -- MAKE-ACCOUNT".to_listv(),
             }
         }
-    };
-    dispatch
+    }
 }
 
 fn handle_response(response: List, x: i32) -> List {
@@ -1,15 +1,14 @@
-use std::cell::RefCell;
-use std::rc::Rc;
-
 use rand::Rng;
 use rand::SeedableRng;
 use rand::rngs::SmallRng;
+use sicp_rs::list_impl::{InnerCell, InnerCellExt, Shared};
 use sicp_rs::prelude::*;
 fn rand(seed: u64) -> impl Fn(&str) -> List {
-    let rng = Rc::new(RefCell::new(SmallRng::seed_from_u64(seed)));
-    let dispatch = move |cmd: &str| match cmd {
+    let rng = Shared::new(InnerCell::new(SmallRng::seed_from_u64(seed)));
+
+    move |cmd: &str| match cmd {
         "generate" => {
-            let mut r = rng.borrow_mut();
+            let mut r = rng.write_cell();
             let n = r.random_range(0..100);
             n.to_listv()
         }
@@ -20,15 +19,14 @@ fn rand(seed: u64) -> impl Fn(&str) -> List {
                 let new_seed = *(new_seed
                     .try_as_basis_value::<u64>()
                     .expect("Expected a u64 value"));
-                let mut rng = rng.borrow_mut();
+                let mut rng = rng.write_cell();
                 *rng = SmallRng::seed_from_u64(new_seed);
                 Some("done".to_listv())
             }
         })
         .to_listv(),
         _ => panic!("Unknown command"),
-    };
-    dispatch
+    }
 }
 
 fn main() {
@@ -1,19 +1,17 @@
-use std::{cell::RefCell, rc::Rc};
-
+use sicp_rs::list_impl::{InnerCell, InnerCellExt, Shared};
 use sicp_rs::prelude::*;
 fn extract_value(x: &List) -> i32 {
-    x.try_as_basis_value::<i32>()
+    *x.try_as_basis_value::<i32>()
         .expect("Expected an i32 value")
-        .clone()
 }
 fn make_account(balance: i32, passwd: String) -> impl Fn(&str, &str) -> List {
-    let balance = Rc::new(RefCell::new(balance));
+    let balance = Shared::new(InnerCell::new(balance));
 
     let withdraw = {
         let balance = balance.clone();
-        Rc::new(move |x: &List| {
+        Shared::new(move |x: &List| {
             let x = extract_value(x);
-            let mut b = balance.borrow_mut();
+            let mut b = balance.write_cell();
             if *b < x {
                 return "Insufficient funds".to_listv();
             };
@@ -23,14 +21,15 @@ fn make_account(balance: i32, passwd: String) -> impl Fn(&str, &str) -> List {
     };
     let deposit = {
         let balance = balance.clone();
-        Rc::new(move |x: &List| {
+        Shared::new(move |x: &List| {
             let x = extract_value(x);
-            let mut b = balance.borrow_mut();
+            let mut b = balance.write_cell();
             *b += x;
             (*b).to_listv()
         })
     };
-    let dispatch = {
+
+    {
         move |pass: &str, m: &str| {
             if pass != passwd.as_str() {
                 return "Incorrect password".to_listv();
@@ -50,8 +49,7 @@ fn make_account(balance: i32, passwd: String) -> impl Fn(&str, &str) -> List {
                 _ => "Unknown request -- MAKE-ACCOUNT".to_listv(),
             }
         }
-    };
-    dispatch
+    }
 }
 
 fn make_joint(
@@ -68,7 +66,7 @@ fn make_joint(
     let with_draw = acc_closure(acc, old_passwd.as_str(), "withdraw");
     let deposit = acc_closure(acc, old_passwd.as_str(), "deposit");
 
-    let dispatch = {
+    {
         move |pass: &str, m: &str| {
             if pass != new_passwd.as_str() {
                 return "Wrong joint account password".to_listv();
@@ -79,8 +77,7 @@ fn make_joint(
                 _ => "Unknown request -- MAKE-ACCOUNT".to_listv(),
             }
         }
-    };
-    dispatch
+    }
 }
 fn handle_response(response: List, x: i32) -> List {
     response.try_as_basis_value::<ClosureWrapper>().map_or_else(
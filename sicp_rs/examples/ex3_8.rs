@@ -1,11 +1,11 @@
 fn make_f() -> impl FnMut(i32) -> i32 {
     let mut x = 1;
     // FnMut 的调用特性只表示闭包可以修改自身的状态，但它并不限定闭包如何捕获外部变量，并不一定是按可变引用捕获。
-    let closure = move |y: i32| {
+
+    move |y: i32| {
         x *= y;
         x
-    };
-    closure
+    }
 }
 fn main() {
     let mut f = make_f();
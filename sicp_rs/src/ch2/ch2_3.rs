@@ -7,7 +7,7 @@ pub fn is_number(exp: &List) -> bool {
     exp.is_value() && exp.is_number_value()
 }
 pub fn is_variable(exp: &List) -> bool {
-    exp.is_value() && exp.is_string_value()
+    exp.is_symbol()
 }
 pub fn is_same_variable(exp1: &List, exp2: &List) -> bool {
     is_variable(exp1) && exp1 == exp2
@@ -18,7 +18,7 @@ pub fn number_equal(exp: &List, num: &List) -> bool {
 }
 
 pub fn is_sum(x: &List) -> bool {
-    x.is_pair() && x.head() == "+".to_listv()
+    x.is_pair() && x.head() == sym!("+").to_listv()
 }
 pub fn addend(s: &List) -> List {
     s.tail().head()
@@ -26,7 +26,10 @@ pub fn addend(s: &List) -> List {
 pub fn augend(s: &List) -> List {
     s.tail().tail().head()
 }
-pub fn make_sum<T: Num + Clone + std::fmt::Debug + 'static>(a1: List, a2: List) -> List {
+pub fn make_sum<T: Num + Clone + std::fmt::Debug + MaybeSendSync + 'static>(
+    a1: List,
+    a2: List,
+) -> List {
     if a1 == T::zero().to_listv() {
         a2
     } else if a2 == T::zero().to_listv() {
@@ -36,11 +39,11 @@ pub fn make_sum<T: Num + Clone + std::fmt::Debug + 'static>(a1: List, a2: List)
             + a2.try_as_basis_value::<T>().unwrap().clone())
         .to_listv()
     } else {
-        list!["+", a1, a2]
+        list![sym!("+"), a1, a2]
     }
 }
 pub fn is_product(x: &List) -> bool {
-    x.is_pair() && x.head() == "*".to_listv()
+    x.is_pair() && x.head() == sym!("*").to_listv()
 }
 pub fn multiplier(p: &List) -> List {
     p.tail().head()
@@ -48,7 +51,10 @@ pub fn multiplier(p: &List) -> List {
 pub fn multiplicand(p: &List) -> List {
     p.tail().tail().head()
 }
-pub fn make_product<T: Num + Clone + std::fmt::Debug + 'static>(m1: List, m2: List) -> List {
+pub fn make_product<T: Num + Clone + std::fmt::Debug + MaybeSendSync + 'static>(
+    m1: List,
+    m2: List,
+) -> List {
     if m1 == T::zero().to_listv() || m2 == T::zero().to_listv() {
         T::zero().to_listv()
     } else if m1 == T::one().to_listv() {
@@ -56,15 +62,15 @@ pub fn make_product<T: Num + Clone + std::fmt::Debug + 'static>(m1: List, m2: Li
     } else if m2 == T::one().to_listv() {
         m1
     } else if is_number(&m1) && is_number(&m2) {
-        return (m1.try_as_basis_value::<T>().unwrap().clone()
+        (m1.try_as_basis_value::<T>().unwrap().clone()
             * m2.try_as_basis_value::<T>().unwrap().clone())
-        .to_listv();
+        .to_listv()
     } else {
-        list!["*", m1, m2]
+        list![sym!("*"), m1, m2]
     }
 }
 pub fn is_exp(x: &List) -> bool {
-    x.is_pair() && x.head() == "**".to_listv()
+    x.is_pair() && x.head() == sym!("**").to_listv()
 }
 pub fn base(e: &List) -> List {
     e.tail().head()
@@ -72,7 +78,7 @@ pub fn base(e: &List) -> List {
 pub fn exponent(e: &List) -> List {
     e.tail().tail().head()
 }
-pub fn make_exp<T: Num + Clone + std::fmt::Debug + Pow<T, Output = T> + 'static>(
+pub fn make_exp<T: Num + Clone + std::fmt::Debug + Pow<T, Output = T> + MaybeSendSync + 'static>(
     b: List,
     e: List,
 ) -> List {
@@ -85,6 +91,1217 @@ pub fn make_exp<T: Num + Clone + std::fmt::Debug + Pow<T, Output = T> + 'static>
         let exp = e.try_as_basis_value::<T>().unwrap().clone();
         Pow::pow(base, exp).to_listv()
     } else {
-        list!["**", b, e]
+        list![sym!("**"), b, e]
+    }
+}
+
+/// 2.3.3 实例：集合的表示
+///
+/// Sets as unordered lists: `element_of_set` and `adjoin_set` take O(n) steps,
+/// `intersection_set` and `union_set` (exercise 2.59) take O(n²).
+/// 集合作为未排序的表。
+pub mod unordered_set {
+    use crate::prelude::*;
+
+    pub fn element_of_set(x: &List, set: &List) -> bool {
+        set.member(x).is_some()
+    }
+    pub fn adjoin_set(x: &List, set: &List) -> List {
+        if element_of_set(x, set) {
+            set.clone()
+        } else {
+            List::pair(x.clone(), set.clone())
+        }
+    }
+    pub fn intersection_set(set1: &List, set2: &List) -> List {
+        set1.filter(|x| element_of_set(x, set2))
+    }
+    /// 练习 2.59
+    pub fn union_set(set1: &List, set2: &List) -> List {
+        set1.filter(|x| !element_of_set(x, set2)).append(set2)
+    }
+}
+
+/// Sets as lists in ascending order: `element_of_set` and `adjoin_set` (exercise 2.61) take
+/// n/2 steps on average, `intersection_set` and `union_set` (exercise 2.62) take O(n).
+/// 集合作为排好序的表，元素须可按 `PartialOrd` 比较。
+pub mod ordered_set {
+    use crate::prelude::*;
+
+    pub fn element_of_set(x: &List, set: &List) -> bool {
+        for item in set.iter() {
+            if *x == item {
+                return true;
+            } else if *x < item {
+                return false;
+            }
+        }
+        false
+    }
+    /// 练习 2.61
+    pub fn adjoin_set(x: &List, set: &List) -> List {
+        let mut smaller = vec![];
+        let mut rest = set.clone();
+        while rest.is_pair() && rest.head() < *x {
+            smaller.push(rest.head());
+            rest = rest.tail();
+        }
+        if rest.is_pair() && rest.head() == *x {
+            return set.clone();
+        }
+        smaller
+            .into_iter()
+            .rev()
+            .fold(List::pair(x.clone(), rest), |result, item| {
+                List::pair(item, result)
+            })
+    }
+    pub fn intersection_set(set1: &List, set2: &List) -> List {
+        let mut result = vec![];
+        let (mut s1, mut s2) = (set1.clone(), set2.clone());
+        while s1.is_pair() && s2.is_pair() {
+            let (x1, x2) = (s1.head(), s2.head());
+            if x1 == x2 {
+                result.push(x1);
+                s1 = s1.tail();
+                s2 = s2.tail();
+            } else if x1 < x2 {
+                s1 = s1.tail();
+            } else {
+                s2 = s2.tail();
+            }
+        }
+        List::from_slice(&result)
+    }
+    /// 练习 2.62
+    pub fn union_set(set1: &List, set2: &List) -> List {
+        let mut result = vec![];
+        let (mut s1, mut s2) = (set1.clone(), set2.clone());
+        while s1.is_pair() && s2.is_pair() {
+            let (x1, x2) = (s1.head(), s2.head());
+            if x1 == x2 {
+                result.push(x1);
+                s1 = s1.tail();
+                s2 = s2.tail();
+            } else if x1 < x2 {
+                result.push(x1);
+                s1 = s1.tail();
+            } else {
+                result.push(x2);
+                s2 = s2.tail();
+            }
+        }
+        // 剩余部分直接共享
+        let rest = if s1.is_pair() { s1 } else { s2 };
+        result
+            .into_iter()
+            .rev()
+            .fold(rest, |tail, item| List::pair(item, tail))
+    }
+}
+
+/// Sets as binary trees `(entry left-branch right-branch)`, the empty tree being `Nil`:
+/// `element_of_set` and `adjoin_set` take O(log n) steps on a balanced tree; `union_set` and
+/// `intersection_set` (exercise 2.65) go through ordered lists and take O(n).
+/// 集合作为二叉树。
+pub mod tree_set {
+    use super::ordered_set;
+    use crate::prelude::*;
+
+    pub fn entry(tree: &List) -> List {
+        tree.head()
+    }
+    pub fn left_branch(tree: &List) -> List {
+        tree.tail().head()
+    }
+    pub fn right_branch(tree: &List) -> List {
+        tree.tail().tail().head()
+    }
+    pub fn make_tree(entry: List, left: List, right: List) -> List {
+        list![entry, left, right]
+    }
+    pub fn element_of_set(x: &List, set: &List) -> bool {
+        if set.is_empty() {
+            false
+        } else if *x == entry(set) {
+            true
+        } else if *x < entry(set) {
+            element_of_set(x, &left_branch(set))
+        } else {
+            element_of_set(x, &right_branch(set))
+        }
+    }
+    pub fn adjoin_set(x: &List, set: &List) -> List {
+        if set.is_empty() {
+            make_tree(x.clone(), List::Nil, List::Nil)
+        } else if *x == entry(set) {
+            set.clone()
+        } else if *x < entry(set) {
+            make_tree(
+                entry(set),
+                adjoin_set(x, &left_branch(set)),
+                right_branch(set),
+            )
+        } else {
+            make_tree(
+                entry(set),
+                left_branch(set),
+                adjoin_set(x, &right_branch(set)),
+            )
+        }
+    }
+    /// 练习 2.63 中的 `tree->list-2`，O(n)
+    pub fn tree_to_list(tree: &List) -> List {
+        fn copy_to_list(tree: &List, result_list: List) -> List {
+            if tree.is_empty() {
+                result_list
+            } else {
+                copy_to_list(
+                    &left_branch(tree),
+                    List::pair(entry(tree), copy_to_list(&right_branch(tree), result_list)),
+                )
+            }
+        }
+        copy_to_list(tree, List::Nil)
+    }
+    /// 练习 2.64：由有序表构造平衡树，O(n)
+    pub fn list_to_tree(elements: &List) -> List {
+        fn partial_tree(elts: List, n: usize) -> (List, List) {
+            if n == 0 {
+                (List::Nil, elts)
+            } else {
+                let left_size = (n - 1) / 2;
+                let (left_tree, non_left_elts) = partial_tree(elts, left_size);
+                let right_size = n - (left_size + 1);
+                let this_entry = non_left_elts.head();
+                let (right_tree, remaining_elts) = partial_tree(non_left_elts.tail(), right_size);
+                (make_tree(this_entry, left_tree, right_tree), remaining_elts)
+            }
+        }
+        partial_tree(elements.clone(), elements.length()).0
+    }
+    /// 练习 2.65
+    pub fn union_set(set1: &List, set2: &List) -> List {
+        list_to_tree(&ordered_set::union_set(
+            &tree_to_list(set1),
+            &tree_to_list(set2),
+        ))
+    }
+    /// 练习 2.65
+    pub fn intersection_set(set1: &List, set2: &List) -> List {
+        list_to_tree(&ordered_set::intersection_set(
+            &tree_to_list(set1),
+            &tree_to_list(set2),
+        ))
+    }
+}
+
+/// 2.3.4 实例：Huffman 编码树
+///
+/// A leaf is `(leaf symbol weight)` and a tree is `(left right symbols weight)`; bits are the
+/// integers 0 and 1. `compress`/`decompress` apply the same trees to arbitrary bytes.
+/// 树叶表示为 `(leaf symbol weight)`，一般的树表示为 `(left right symbols weight)`，二进制位为整数 0 和 1。
+pub mod huffman {
+    use crate::list_impl::panic_with_location;
+    use crate::prelude::*;
+
+    pub fn make_leaf(symbol: List, weight: u64) -> List {
+        list![sym!(leaf), symbol, weight]
+    }
+    pub fn is_leaf(object: &List) -> bool {
+        object.is_pair() && object.head() == sym!(leaf).to_listv()
+    }
+    pub fn symbol_leaf(x: &List) -> List {
+        x.tail().head()
+    }
+    pub fn weight_leaf(x: &List) -> u64 {
+        *x.tail().tail().head().try_as_basis_value::<u64>().unwrap()
+    }
+    pub fn make_code_tree(left: List, right: List) -> List {
+        let symbols = symbols(&left).append(&symbols(&right));
+        let weight = weight(&left) + weight(&right);
+        list![left, right, symbols, weight]
+    }
+    pub fn left_branch(tree: &List) -> List {
+        tree.head()
+    }
+    pub fn right_branch(tree: &List) -> List {
+        tree.tail().head()
+    }
+    pub fn symbols(tree: &List) -> List {
+        if is_leaf(tree) {
+            list![symbol_leaf(tree)]
+        } else {
+            tree.tail().tail().head()
+        }
+    }
+    pub fn weight(tree: &List) -> u64 {
+        if is_leaf(tree) {
+            weight_leaf(tree)
+        } else {
+            *tree
+                .tail()
+                .tail()
+                .tail()
+                .head()
+                .try_as_basis_value::<u64>()
+                .unwrap()
+        }
+    }
+
+    pub fn decode(bits: &List, tree: &List) -> List {
+        let mut result = vec![];
+        let mut current_branch = tree.clone();
+        for bit in bits.iter() {
+            let next_branch = choose_branch(&bit, &current_branch);
+            if is_leaf(&next_branch) {
+                result.push(symbol_leaf(&next_branch));
+                current_branch = tree.clone();
+            } else {
+                current_branch = next_branch;
+            }
+        }
+        List::from_slice(&result)
+    }
+    pub fn choose_branch(bit: &List, branch: &List) -> List {
+        if *bit == 0.to_listv() {
+            left_branch(branch)
+        } else if *bit == 1.to_listv() {
+            right_branch(branch)
+        } else {
+            panic_with_location("bad bit -- choose_branch", bit)
+        }
+    }
+
+    /// 练习 2.68
+    pub fn encode(message: &List, tree: &List) -> List {
+        message
+            .iter()
+            .flat_map(|symbol| encode_symbol(&symbol, tree).iter())
+            .collect()
+    }
+    /// 练习 2.68：沿包含该符号的分支向下，左分支记 0，右分支记 1
+    pub fn encode_symbol(symbol: &List, tree: &List) -> List {
+        if symbols(tree).member(symbol).is_none() {
+            panic_with_location("symbol not in tree -- encode_symbol", symbol);
+        }
+        let mut bits = vec![];
+        let mut branch = tree.clone();
+        while !is_leaf(&branch) {
+            let left = left_branch(&branch);
+            if symbols(&left).member(symbol).is_some() {
+                bits.push(0.to_listv());
+                branch = left;
+            } else {
+                bits.push(1.to_listv());
+                branch = right_branch(&branch);
+            }
+        }
+        List::from_slice(&bits)
+    }
+
+    /// 按权重升序插入，权重相同时排在已有元素之后
+    pub fn adjoin_set(x: &List, set: &List) -> List {
+        let lighter: Vec<List> = set.iter().take_while(|y| weight(y) <= weight(x)).collect();
+        let mut rest = set.clone();
+        for _ in 0..lighter.len() {
+            rest = rest.tail();
+        }
+        lighter
+            .into_iter()
+            .rev()
+            .fold(List::pair(x.clone(), rest), |result, y| {
+                List::pair(y, result)
+            })
+    }
+    /// 由 `((symbol weight) ...)` 构造按权重排序的树叶集合
+    pub fn make_leaf_set(pairs: &List) -> List {
+        pairs.reverse().iter().fold(List::Nil, |set, pair| {
+            let weight = pair.tail().head();
+            let weight = if weight.is_integer_value() {
+                weight.get_basis_value().as_string().parse::<u64>().ok()
+            } else {
+                None
+            };
+            let weight = weight.unwrap_or_else(|| {
+                panic_with_location("weight must be a non-negative integer", &pair)
+            });
+            adjoin_set(&make_leaf(pair.head(), weight), &set)
+        })
+    }
+    /// 练习 2.69
+    pub fn generate_huffman_tree(pairs: &List) -> List {
+        successive_merge(&make_leaf_set(pairs))
+    }
+    /// 练习 2.69：反复合并权重最小的两个元素，直至只剩一棵树
+    pub fn successive_merge(set: &List) -> List {
+        let mut set = set.clone();
+        while !set.tail().is_empty() {
+            let merged = make_code_tree(set.head(), set.tail().head());
+            set = adjoin_set(&merged, &set.tail().tail());
+        }
+        set.head()
+    }
+
+    /// Compresses bytes into a self-describing buffer: the input length (u64, little-endian),
+    /// the number of distinct bytes (u16), each distinct byte with its count (u64), and then
+    /// the Huffman code bits, most significant bit first.
+    /// 压缩字节序列；结果中包含重建 Huffman 树所需的频率表。
+    pub fn compress(data: &[u8]) -> Vec<u8> {
+        let mut counts = [0usize; 256];
+        for &b in data {
+            counts[b as usize] += 1;
+        }
+        let present: Vec<u8> = (0..=255u8).filter(|&b| counts[b as usize] > 0).collect();
+
+        let mut out = Vec::new();
+        out.extend_from_slice(&(data.len() as u64).to_le_bytes());
+        out.extend_from_slice(&(present.len() as u16).to_le_bytes());
+        for &b in &present {
+            out.push(b);
+            out.extend_from_slice(&(counts[b as usize] as u64).to_le_bytes());
+        }
+        if present.is_empty() {
+            return out;
+        }
+
+        let tree = byte_tree(&present, &counts);
+        let mut codes = vec![vec![]; 256];
+        for &b in &present {
+            codes[b as usize] = encode_symbol(&b.to_listv(), &tree)
+                .iter()
+                .map(|bit| bit == 1.to_listv())
+                .collect::<Vec<bool>>();
+        }
+        let (mut byte, mut filled) = (0u8, 0);
+        for &b in data {
+            for &bit in &codes[b as usize] {
+                byte = (byte << 1) | bit as u8;
+                filled += 1;
+                if filled == 8 {
+                    out.push(byte);
+                    (byte, filled) = (0, 0);
+                }
+            }
+        }
+        if filled > 0 {
+            out.push(byte << (8 - filled));
+        }
+        out
+    }
+
+    /// Restores the bytes produced by `compress`, or returns `None` if `data` is malformed.
+    /// With two or more distinct bytes every byte takes at least one code bit, so a header
+    /// claiming more bytes than the remaining input has bits is rejected up front. Input with a
+    /// single distinct byte has no code bits; its output is allocated fallibly and `None` is
+    /// returned if that fails.
+    /// 解压 `compress` 的结果；数据格式有误时返回 `None`。
+    /// 每个字节至少占一位编码（只有一种字节时除外），因此先按剩余输入的位数校验头部中的长度。
+    pub fn decompress(data: &[u8]) -> Option<Vec<u8>> {
+        fn read_u64(data: &[u8], at: usize) -> Option<u64> {
+            Some(u64::from_le_bytes(data.get(at..at + 8)?.try_into().ok()?))
+        }
+        let len = usize::try_from(read_u64(data, 0)?).ok()?;
+        let n = u16::from_le_bytes(data.get(8..10)?.try_into().ok()?) as usize;
+        let mut counts = [0usize; 256];
+        let mut present = vec![];
+        let mut at = 10;
+        for _ in 0..n {
+            let b = *data.get(at)?;
+            if counts[b as usize] > 0 {
+                return None;
+            }
+            counts[b as usize] = usize::try_from(read_u64(data, at + 1)?).ok()?;
+            if counts[b as usize] == 0 {
+                return None;
+            }
+            present.push(b);
+            at += 9;
+        }
+        if counts.iter().try_fold(0usize, |sum, &c| sum.checked_add(c)) != Some(len) {
+            return None;
+        }
+        if len == 0 {
+            return Some(vec![]);
+        }
+
+        if present.len() == 1 {
+            // 只有一种字节时，编码长度为 0；长度无法由输入校验，分配失败时返回 None
+            let mut out = Vec::new();
+            out.try_reserve_exact(len).ok()?;
+            out.resize(len, present[0]);
+            return Some(out);
+        }
+        // 每个字节至少占一位编码
+        if len > (data.len() - at).saturating_mul(8) {
+            return None;
+        }
+
+        let tree = byte_tree(&present, &counts);
+        let byte_of = |leaf: &List| *symbol_leaf(leaf).try_as_basis_value::<u8>().unwrap();
+        let mut out = Vec::with_capacity(len);
+        let mut branch = tree.clone();
+        let bits = data[at..]
+            .iter()
+            .flat_map(|byte| (0..8).rev().map(move |i| (byte >> i) & 1 == 1));
+        for bit in bits {
+            branch = if bit {
+                right_branch(&branch)
+            } else {
+                left_branch(&branch)
+            };
+            if is_leaf(&branch) {
+                out.push(byte_of(&branch));
+                if out.len() == len {
+                    return Some(out);
+                }
+                branch = tree.clone();
+            }
+        }
+        None
+    }
+
+    fn byte_tree(present: &[u8], counts: &[usize; 256]) -> List {
+        let pairs: List = present
+            .iter()
+            .map(|&b| list![b, counts[b as usize] as u64])
+            .collect();
+        generate_huffman_tree(&pairs)
+    }
+}
+
+/// A simplifier for the expressions of 2.3.2, with exercise 2.58's infix notation.
+///
+/// `simplify` puts prefix expressions in a canonical form: nested sums and products are
+/// flattened into n-ary ones, numbers are combined exactly, products and positive integer
+/// powers of sums are expanded, like terms are collected by coefficient and like factors by
+/// exponent, and the terms and factors are sorted. `-` and `/` are rewritten as `(* -1 b)` and
+/// `(** b -1)`, and `sin`, `cos`, `exp` and `log` fold at known points.
+/// `deriv` differentiates with rules for quotients and these functions, and simplifies.
+/// 符号代数化简：展平 n 元和与积、展开乘积、合并同类项与同底的幂并排序；`deriv` 求导后化简。
+/// 常数按精确有理数计算，浮点数会被转换为与之相等的有理数。
+pub mod simplify {
+    use std::collections::BTreeMap;
+    use std::iter;
+
+    use num::{BigRational, One, ToPrimitive, Zero};
+
+    use super::{is_number, is_variable};
+    use crate::list_impl::panic_with_location;
+    use crate::prelude::*;
+
+    /// 化简过程中的规范形式；变体的声明顺序决定了项与因子的排列顺序
+    #[derive(Clone, PartialEq, Eq, PartialOrd, Ord)]
+    enum Expr {
+        Num(BigRational),
+        Var(Symbol),
+        /// 至少两项，数值项在最后
+        Sum(Vec<Expr>),
+        /// 至少两个因子，数值系数在最前
+        Product(Vec<Expr>),
+        Pow(Box<Expr>, Box<Expr>),
+        Call(Symbol, Vec<Expr>),
+    }
+
+    fn num(n: i32) -> Expr {
+        Expr::Num(BigRational::from_integer(n.into()))
+    }
+
+    fn add(terms: Vec<Expr>) -> Expr {
+        fn collect(
+            terms: Vec<Expr>,
+            constant: &mut BigRational,
+            like: &mut BTreeMap<Expr, BigRational>,
+        ) {
+            for term in terms {
+                match term {
+                    Expr::Num(n) => *constant += n,
+                    Expr::Sum(ts) => collect(ts, constant, like),
+                    other => {
+                        let (coeff, monomial) = split_coeff(other);
+                        *like.entry(monomial).or_insert_with(BigRational::zero) += coeff;
+                    }
+                }
+            }
+        }
+        let mut constant = BigRational::zero();
+        let mut like = BTreeMap::new();
+        collect(terms, &mut constant, &mut like);
+        let mut result: Vec<Expr> = like
+            .into_iter()
+            .filter(|(_, coeff)| !coeff.is_zero())
+            .map(|(monomial, coeff)| with_coeff(coeff, monomial))
+            .collect();
+        if !constant.is_zero() || result.is_empty() {
+            result.push(Expr::Num(constant));
+        }
+        if result.len() == 1 {
+            result.pop().unwrap()
+        } else {
+            Expr::Sum(result)
+        }
+    }
+
+    fn split_coeff(term: Expr) -> (BigRational, Expr) {
+        match term {
+            Expr::Product(mut factors) => match &factors[0] {
+                Expr::Num(c) => {
+                    let c = c.clone();
+                    factors.remove(0);
+                    let rest = if factors.len() == 1 {
+                        factors.pop().unwrap()
+                    } else {
+                        Expr::Product(factors)
+                    };
+                    (c, rest)
+                }
+                _ => (BigRational::one(), Expr::Product(factors)),
+            },
+            other => (BigRational::one(), other),
+        }
+    }
+
+    fn with_coeff(coeff: BigRational, monomial: Expr) -> Expr {
+        if coeff.is_one() {
+            return monomial;
+        }
+        let factors = match monomial {
+            Expr::Product(factors) => factors,
+            other => vec![other],
+        };
+        Expr::Product(iter::once(Expr::Num(coeff)).chain(factors).collect())
+    }
+
+    fn mul(factors: Vec<Expr>) -> Expr {
+        fn collect(
+            factors: Vec<Expr>,
+            coeff: &mut BigRational,
+            powers: &mut BTreeMap<Expr, Vec<Expr>>,
+        ) {
+            for factor in factors {
+                match factor {
+                    Expr::Num(n) => *coeff *= n,
+                    Expr::Product(fs) => collect(fs, coeff, powers),
+                    Expr::Pow(base, exponent) => powers.entry(*base).or_default().push(*exponent),
+                    other => powers.entry(other).or_default().push(num(1)),
+                }
+            }
+        }
+        let mut coeff = BigRational::one();
+        let mut powers = BTreeMap::new();
+        collect(factors, &mut coeff, &mut powers);
+        let mut result = vec![];
+        for (base, exponents) in powers {
+            match pow(base, add(exponents)) {
+                Expr::Num(n) => coeff *= n,
+                other => result.push(other),
+            }
+        }
+        if coeff.is_zero() {
+            return num(0);
+        }
+        // 乘积中的和式按分配律展开
+        if let Some(i) = result.iter().position(|f| matches!(f, Expr::Sum(_))) {
+            let Expr::Sum(terms) = result.remove(i) else {
+                unreachable!()
+            };
+            return add(terms
+                .into_iter()
+                .map(|term| {
+                    mul(iter::once(Expr::Num(coeff.clone()))
+                        .chain(result.iter().cloned())
+                        .chain(iter::once(term))
+                        .collect())
+                })
+                .collect());
+        }
+        if result.is_empty() {
+            Expr::Num(coeff)
+        } else if result.len() == 1 && coeff.is_one() {
+            result.pop().unwrap()
+        } else {
+            if !coeff.is_one() {
+                result.insert(0, Expr::Num(coeff));
+            }
+            Expr::Product(result)
+        }
+    }
+
+    /// 数值幂结果的位数上限；超过时保留为 `Expr::Pow`，不计算巨大的整数
+    const MAX_POW_BITS: u64 = 4096;
+
+    fn pow_fits(base: &BigRational, exponent: i32) -> bool {
+        let bits = base.numer().bits().max(base.denom().bits());
+        bits.saturating_mul(exponent.unsigned_abs().into()) <= MAX_POW_BITS
+    }
+
+    fn pow(base: Expr, exponent: Expr) -> Expr {
+        let integer_exponent = match &exponent {
+            Expr::Num(e) if e.is_integer() => e.to_integer().to_i32(),
+            _ => None,
+        };
+        match (base, exponent) {
+            (_, Expr::Num(e)) if e.is_zero() => num(1),
+            (base, Expr::Num(e)) if e.is_one() => base,
+            (Expr::Num(b), _) if b.is_one() => num(1),
+            (Expr::Num(b), Expr::Num(e)) if b.is_zero() && e < BigRational::zero() => {
+                let exp = list![sym!("**"), 0, List::from_rational(e)];
+                panic_with_location("division by zero -- SIMPLIFY", &exp)
+            }
+            (Expr::Num(b), exponent) => match integer_exponent {
+                Some(e) if pow_fits(&b, e) => Expr::Num(b.pow(e)),
+                _ => Expr::Pow(Box::new(Expr::Num(b)), Box::new(exponent)),
+            },
+            // 仅当外层指数为整数时 (b^e)^n = b^(e*n) 才总是成立
+            (Expr::Pow(b, e), exponent) if integer_exponent.is_some() => {
+                pow(*b, mul(vec![*e, exponent]))
+            }
+            (Expr::Product(factors), exponent) if integer_exponent.is_some() => mul(factors
+                .into_iter()
+                .map(|f| pow(f, exponent.clone()))
+                .collect()),
+            (Expr::Sum(terms), _) if integer_exponent.is_some_and(|e| e > 1) => {
+                // 逐次乘以和式的各项；直接调用 mul 会将相同的和式重新合并为幂
+                (1..integer_exponent.unwrap()).fold(Expr::Sum(terms.clone()), |product, _| {
+                    add(terms
+                        .iter()
+                        .map(|term| mul(vec![product.clone(), term.clone()]))
+                        .collect())
+                })
+            }
+            (base, exponent) => Expr::Pow(Box::new(base), Box::new(exponent)),
+        }
+    }
+
+    fn call(f: Symbol, args: Vec<Expr>) -> Expr {
+        let zero = num(0);
+        match (f.as_str(), args.as_slice()) {
+            ("sin", [x]) if *x == zero => num(0),
+            ("cos", [x]) if *x == zero => num(1),
+            ("exp", [x]) if *x == zero => num(1),
+            ("log", [x]) if *x == num(1) => num(0),
+            ("log", [Expr::Call(g, inner)]) if g.as_str() == "exp" && inner.len() == 1 => {
+                inner[0].clone()
+            }
+            _ => Expr::Call(f, args),
+        }
+    }
+
+    fn from_list(exp: &List) -> Expr {
+        if is_number(exp) {
+            match exp.get_basis_value().as_ref().to_big_rational() {
+                Some(n) => Expr::Num(n),
+                None => panic_with_location("number has no exact value -- SIMPLIFY", exp),
+            }
+        } else if is_variable(exp) {
+            Expr::Var(*exp.try_as_basis_value::<Symbol>().unwrap())
+        } else if exp.is_pair() && exp.head().is_symbol() {
+            let op = *exp.head().try_as_basis_value::<Symbol>().unwrap();
+            let mut args: Vec<Expr> = exp.tail().iter().map(|arg| from_list(&arg)).collect();
+            match (op.as_str(), args.len()) {
+                ("+", _) => add(args),
+                ("*", _) => mul(args),
+                ("-", 1) => mul(vec![num(-1), args.pop().unwrap()]),
+                ("-", n) if n > 1 => {
+                    let first = args.remove(0);
+                    add(iter::once(first)
+                        .chain(args.into_iter().map(|a| mul(vec![num(-1), a])))
+                        .collect())
+                }
+                ("/", 1) => pow(args.pop().unwrap(), num(-1)),
+                ("/", n) if n > 1 => {
+                    let first = args.remove(0);
+                    mul(iter::once(first)
+                        .chain(args.into_iter().map(|a| pow(a, num(-1))))
+                        .collect())
+                }
+                ("**", 2) => {
+                    let exponent = args.pop().unwrap();
+                    pow(args.pop().unwrap(), exponent)
+                }
+                ("-" | "/" | "**", _) => {
+                    panic_with_location("wrong number of operands -- SIMPLIFY", exp)
+                }
+                _ => call(op, args),
+            }
+        } else {
+            panic_with_location("unknown expression type -- SIMPLIFY", exp)
+        }
+    }
+
+    fn to_list(exp: &Expr) -> List {
+        fn form(op: Symbol, args: &[Expr]) -> List {
+            iter::once(op.to_listv())
+                .chain(args.iter().map(to_list))
+                .collect()
+        }
+        match exp {
+            Expr::Num(n) => List::from_rational(n.clone()),
+            Expr::Var(v) => (*v).to_listv(),
+            Expr::Sum(terms) => form(sym!("+"), terms),
+            Expr::Product(factors) => form(sym!("*"), factors),
+            Expr::Pow(base, exponent) => list![sym!("**"), to_list(base), to_list(exponent)],
+            Expr::Call(f, args) => form(*f, args),
+        }
+    }
+
+    /// Puts a prefix expression in canonical form.
+    /// 将前缀表达式化简为规范形式。
+    ///
+    /// Numeric powers are evaluated only while the result stays below 4096 bits; larger ones,
+    /// such as `(** 2 100000)`, are kept as they are.
+    /// 数值幂仅在结果不超过 4096 位时求值，更大的幂保持原样。
+    ///
+    /// # Examples
+    /// ```rust
+    /// use sicp_rs::ch2::ch2_3::simplify::simplify;
+    /// use sicp_rs::prelude::*;
+    ///
+    /// let exp = List::parse("(+ (* x 0) (* 1 y) (* 2 x y) (* y x) x (- x))").unwrap();
+    /// assert_eq!(simplify(&exp), List::parse("(+ y (* 3 x y))").unwrap());
+    /// ```
+    pub fn simplify(exp: &List) -> List {
+        to_list(&from_list(exp))
+    }
+
+    fn depends_on(exp: &List, var: &List) -> bool {
+        if exp.is_pair() {
+            exp.iter().any(|e| depends_on(&e, var))
+        } else {
+            exp == var
+        }
+    }
+
+    fn d(exp: &List, var: &List) -> Expr {
+        if is_number(exp) {
+            return num(0);
+        }
+        if is_variable(exp) {
+            return if exp == var { num(1) } else { num(0) };
+        }
+        let args: Vec<List> = if exp.is_pair() {
+            exp.tail().iter().collect()
+        } else {
+            vec![]
+        };
+        let e = |x: &List| from_list(x);
+        let op = if exp.is_pair() && exp.head().is_symbol() {
+            exp.head().try_as_basis_value::<Symbol>().unwrap().as_str()
+        } else {
+            ""
+        };
+        match (op, args.as_slice()) {
+            ("+", _) => add(args.iter().map(|a| d(a, var)).collect()),
+            ("-", [u]) => mul(vec![num(-1), d(u, var)]),
+            ("-", [u, rest @ ..]) if !rest.is_empty() => add(iter::once(d(u, var))
+                .chain(rest.iter().map(|a| mul(vec![num(-1), d(a, var)])))
+                .collect()),
+            // 乘积法则：依次对每个因子求导
+            ("*", _) => add((0..args.len())
+                .map(|i| {
+                    mul(args
+                        .iter()
+                        .enumerate()
+                        .map(|(j, a)| if i == j { d(a, var) } else { e(a) })
+                        .collect())
+                })
+                .collect()),
+            // 商法则：(u/v)' = (v u' - u v') / v^2
+            ("/", [v]) => d(&list![sym!("/"), 1, v.clone()], var),
+            ("/", [u, rest @ ..]) if !rest.is_empty() => {
+                let v: List = if rest.len() == 1 {
+                    rest[0].clone()
+                } else {
+                    iter::once(sym!("*").to_listv())
+                        .chain(rest.iter().cloned())
+                        .collect()
+                };
+                mul(vec![
+                    add(vec![
+                        mul(vec![e(&v), d(u, var)]),
+                        mul(vec![num(-1), e(u), d(&v, var)]),
+                    ]),
+                    pow(e(&v), num(-2)),
+                ])
+            }
+            ("**", [u, n]) => {
+                if !depends_on(n, var) {
+                    // (u^n)' = n u^(n-1) u'
+                    mul(vec![e(n), pow(e(u), add(vec![e(n), num(-1)])), d(u, var)])
+                } else if !depends_on(u, var) {
+                    // (a^v)' = a^v log(a) v'
+                    mul(vec![e(exp), call(sym!(log), vec![e(u)]), d(n, var)])
+                } else {
+                    // (u^v)' = u^v (v' log(u) + v u' / u)
+                    mul(vec![
+                        e(exp),
+                        add(vec![
+                            mul(vec![d(n, var), call(sym!(log), vec![e(u)])]),
+                            mul(vec![e(n), d(u, var), pow(e(u), num(-1))]),
+                        ]),
+                    ])
+                }
+            }
+            ("sin", [u]) => mul(vec![call(sym!(cos), vec![e(u)]), d(u, var)]),
+            ("cos", [u]) => mul(vec![num(-1), call(sym!(sin), vec![e(u)]), d(u, var)]),
+            ("exp", [u]) => mul(vec![e(exp), d(u, var)]),
+            ("log", [u]) => mul(vec![pow(e(u), num(-1)), d(u, var)]),
+            _ => panic_with_location("unknown expression type -- DERIV", exp),
+        }
+    }
+
+    /// Differentiates a prefix expression with respect to `var` and simplifies the result.
+    /// Besides `+` and `*` it handles `-`, `/` (the quotient rule), `**` with any exponent,
+    /// `sin`, `cos`, `exp` and `log` (natural logarithm).
+    /// 对前缀表达式关于 `var` 求导并化简结果。
+    ///
+    /// # Examples
+    /// ```rust
+    /// use sicp_rs::ch2::ch2_3::simplify::deriv;
+    /// use sicp_rs::prelude::*;
+    ///
+    /// let exp = List::parse("(* (* x y) (+ x 3))").unwrap();
+    /// assert_eq!(
+    ///     deriv(&exp, &sym!(x).to_listv()),
+    ///     List::parse("(+ (* 3 y) (* 2 x y))").unwrap()
+    /// );
+    /// let exp = List::parse("(/ (sin x) x)").unwrap();
+    /// assert_eq!(
+    ///     deriv(&exp, &sym!(x).to_listv()),
+    ///     List::parse("(+ (* -1 (** x -2) (sin x)) (* (** x -1) (cos x)))").unwrap()
+    /// );
+    /// ```
+    pub fn deriv(exp: &List, var: &List) -> List {
+        to_list(&d(exp, var))
+    }
+
+    const FUNCTIONS: [&str; 4] = ["sin", "cos", "exp", "log"];
+
+    fn is_operator(x: &List, ops: &[&str]) -> bool {
+        x.is_symbol() && ops.contains(&x.try_as_basis_value::<Symbol>().unwrap().as_str())
+    }
+
+    /// Converts an infix expression (exercise 2.58), such as `(x + 3 * (x + y + 2))`, to
+    /// prefix notation. `**` binds tightest and to the right, then unary `-`, then `*` and
+    /// `/`, then `+` and `-`; `sin`, `cos`, `exp` and `log` apply to the operand that follows.
+    /// 将中缀表达式（练习 2.58）转换为前缀形式，按通常的优先级与结合性处理运算符。
+    pub fn infix_to_prefix(exp: &List) -> List {
+        fn sum(items: &[List], pos: &mut usize) -> List {
+            let mut result = product(items, pos);
+            while *pos < items.len() && is_operator(&items[*pos], &["+", "-"]) {
+                let op = items[*pos].clone();
+                *pos += 1;
+                result = list![op, result, product(items, pos)];
+            }
+            result
+        }
+        fn product(items: &[List], pos: &mut usize) -> List {
+            let mut result = unary(items, pos);
+            while *pos < items.len() && is_operator(&items[*pos], &["*", "/"]) {
+                let op = items[*pos].clone();
+                *pos += 1;
+                result = list![op, result, unary(items, pos)];
+            }
+            result
+        }
+        fn unary(items: &[List], pos: &mut usize) -> List {
+            if *pos < items.len() && is_operator(&items[*pos], &["-"]) {
+                *pos += 1;
+                list![sym!("-"), unary(items, pos)]
+            } else {
+                power(items, pos)
+            }
+        }
+        fn power(items: &[List], pos: &mut usize) -> List {
+            let base = primary(items, pos);
+            if *pos < items.len() && is_operator(&items[*pos], &["**"]) {
+                *pos += 1;
+                list![sym!("**"), base, unary(items, pos)]
+            } else {
+                base
+            }
+        }
+        fn primary(items: &[List], pos: &mut usize) -> List {
+            let Some(item) = items.get(*pos) else {
+                panic_with_location("missing operand -- INFIX", &List::from_slice(items))
+            };
+            *pos += 1;
+            if is_operator(item, &FUNCTIONS) {
+                list![item.clone(), power(items, pos)]
+            } else if is_operator(item, &["+", "-", "*", "/", "**"]) {
+                panic_with_location("unexpected operator -- INFIX", &List::from_slice(items))
+            } else {
+                infix_to_prefix(item)
+            }
+        }
+        if !exp.is_pair() {
+            return exp.clone();
+        }
+        let items: Vec<List> = exp.iter().collect();
+        let mut pos = 0;
+        let result = sum(&items, &mut pos);
+        if pos < items.len() {
+            panic_with_location("unexpected operand -- INFIX", exp);
+        }
+        result
+    }
+
+    /// Converts a prefix expression to infix notation, with parentheses only where the
+    /// precedence of `infix_to_prefix` requires them.
+    /// 将前缀表达式转换为中缀形式，只在优先级需要时加括号。
+    ///
+    /// # Examples
+    /// ```rust
+    /// use sicp_rs::ch2::ch2_3::simplify::{infix_to_prefix, prefix_to_infix, simplify};
+    /// use sicp_rs::prelude::*;
+    ///
+    /// let exp = List::parse("(x + 3 * (x + y + 2))").unwrap();
+    /// let simplified = simplify(&infix_to_prefix(&exp));
+    /// assert_eq!(prefix_to_infix(&simplified), List::parse("(4 * x + 3 * y + 6)").unwrap());
+    /// ```
+    pub fn prefix_to_infix(exp: &List) -> List {
+        // 返回记号序列及其优先级：1 为加减，2 为乘除，3 为一元负号，4 为乘方，5 为不可再分
+        fn tokens(exp: &List) -> (Vec<List>, u8) {
+            if !exp.is_pair() || !exp.head().is_symbol() {
+                return (vec![exp.clone()], 5);
+            }
+            let op = exp.head();
+            let args: Vec<List> = exp.tail().iter().collect();
+            let join = |prec: u8, right_prec: u8| {
+                let mut result = operand(&args[0], prec);
+                for arg in &args[1..] {
+                    result.push(op.clone());
+                    result.extend(operand(arg, right_prec));
+                }
+                (result, prec)
+            };
+            match (
+                op.try_as_basis_value::<Symbol>().unwrap().as_str(),
+                args.len(),
+            ) {
+                ("+", n) if n >= 2 => join(1, 1),
+                ("-", n) if n >= 2 => join(1, 2),
+                ("*", n) if n >= 2 => join(2, 2),
+                ("/", n) if n >= 2 => join(2, 3),
+                ("**", 2) => {
+                    let mut result = operand(&args[0], 5);
+                    result.push(op);
+                    result.extend(operand(&args[1], 3));
+                    (result, 4)
+                }
+                ("-", 1) => {
+                    let mut result = vec![op];
+                    result.extend(operand(&args[0], 3));
+                    (result, 3)
+                }
+                (f, 1) if FUNCTIONS.contains(&f) => {
+                    let mut result = vec![op];
+                    result.extend(operand(&args[0], 4));
+                    (result, 4)
+                }
+                _ => panic_with_location("unknown expression type -- INFIX", exp),
+            }
+        }
+        fn operand(exp: &List, min_prec: u8) -> Vec<List> {
+            let (items, prec) = tokens(exp);
+            if prec >= min_prec {
+                items
+            } else {
+                vec![List::from_slice(&items)]
+            }
+        }
+        let (items, prec) = tokens(exp);
+        if prec == 5 {
+            items[0].clone()
+        } else {
+            List::from_slice(&items)
+        }
+    }
+}
+
+/// Evaluation of the expressions of 2.3.2 under bindings given as an association list of
+/// variables, such as `((x . 2) (y . 0.5))`.
+/// 在以关联表给出的变量绑定下对表达式求值。
+pub mod evaluate {
+    use num::ToPrimitive;
+
+    use super::simplify::simplify;
+    use super::{is_number, is_variable};
+    use crate::ch2::ch2_5::{
+        ArithmeticContext, make_bigrational, make_float, make_integer, make_rational,
+    };
+    use crate::list_impl::panic_with_location;
+    use crate::prelude::*;
+
+    fn operator(exp: &List) -> &'static str {
+        if exp.is_pair() && exp.head().is_symbol() {
+            exp.head().try_as_basis_value::<Symbol>().unwrap().as_str()
+        } else {
+            ""
+        }
+    }
+
+    fn lookup(var: &List, bindings: &List) -> List {
+        match bindings.assoc(var) {
+            Some(binding) => binding.tail(),
+            None => panic_with_location("unbound variable -- EVAL", var),
+        }
+    }
+
+    fn to_f64(x: &List) -> f64 {
+        if let Ok(f) = x.try_as_basis_value::<f64>() {
+            return *f;
+        }
+        // NaN 与无穷大没有精确值
+        match x.get_basis_value().as_ref().to_big_rational() {
+            Some(r) => r.to_f64().unwrap(),
+            None => x.get_basis_value().as_string().parse().unwrap(),
+        }
+    }
+
+    /// Evaluates an expression to a float. Variables must be bound to numbers; the operators
+    /// are those of `simplify::deriv`, with `log` the natural logarithm.
+    /// 将表达式求值为浮点数；变量须绑定到数值，支持的运算符与 `simplify::deriv` 相同。
+    ///
+    /// # Examples
+    /// ```rust
+    /// use sicp_rs::ch2::ch2_3::evaluate::eval_expr;
+    /// use sicp_rs::prelude::*;
+    ///
+    /// let exp = List::parse("(+ (* 3 x) (** y 2) (sin 0))").unwrap();
+    /// let bindings = list![pair![sym!(x), 2], pair![sym!(y), 0.5]];
+    /// assert_eq!(eval_expr(&exp, &bindings), 6.25);
+    /// ```
+    pub fn eval_expr(exp: &List, bindings: &List) -> f64 {
+        if is_number(exp) {
+            return to_f64(exp);
+        }
+        if is_variable(exp) {
+            let value = lookup(exp, bindings);
+            if !is_number(&value) {
+                panic_with_location("variable is not bound to a number -- EVAL", exp);
+            }
+            return to_f64(&value);
+        }
+        let args: Vec<f64> = if exp.is_pair() {
+            exp.tail().iter().map(|a| eval_expr(&a, bindings)).collect()
+        } else {
+            vec![]
+        };
+        match (operator(exp), args.as_slice()) {
+            ("+", _) => args.iter().sum(),
+            ("*", _) => args.iter().product(),
+            ("-", [a]) => -a,
+            ("-", [a, rest @ ..]) if !rest.is_empty() => rest.iter().fold(*a, |x, y| x - y),
+            ("/", [a]) => 1.0 / a,
+            ("/", [a, rest @ ..]) if !rest.is_empty() => rest.iter().fold(*a, |x, y| x / y),
+            ("**", [a, b]) => a.powf(*b),
+            ("sin", [a]) => a.sin(),
+            ("cos", [a]) => a.cos(),
+            ("exp", [a]) => a.exp(),
+            ("log", [a]) => a.ln(),
+            _ => panic_with_location("unknown expression type -- EVAL", exp),
+        }
+    }
+
+    /// 精确数值常量转为算术系统中的整数或有理数；浮点数返回 `None`
+    fn exact_number(exp: &List, arith: &ArithmeticContext) -> Option<List> {
+        if exp.try_as_basis_value::<f64>().is_ok() {
+            return None;
+        }
+        let r = exp.get_basis_value().as_ref().to_big_rational()?;
+        if r.is_integer() {
+            return Some(make_integer(r.to_integer(), arith));
+        }
+        Some(match (r.numer().to_i32(), r.denom().to_i32()) {
+            (Some(n), Some(d)) => make_rational(n.to_listv(), d.to_listv(), arith),
+            _ => make_bigrational(r, arith),
+        })
+    }
+
+    /// Evaluates an expression with the generic operations of an `ArithmeticContext` (2.5),
+    /// with variables bound to its values. Exact constants become integers or rationals of the
+    /// context and `f64` constants become floats; `+`, `-`, `*`, `/` and `**` are supported.
+    /// 使用 `ArithmeticContext` 的通用运算求值，变量绑定到该算术系统中的值。
+    ///
+    /// # Examples
+    /// ```rust
+    /// use sicp_rs::ch2::ch2_3::evaluate::eval_expr_in;
+    /// use sicp_rs::ch2::ch2_5::{ArithmeticContext, install_arithmetic_package, make_rational};
+    /// use sicp_rs::prelude::*;
+    ///
+    /// let arith = ArithmeticContext::new();
+    /// install_arithmetic_package(&arith);
+    /// let half = make_rational(1.to_listv(), 2.to_listv(), &arith);
+    /// let exp = List::parse("(+ x (* 2 x))").unwrap();
+    /// let result = eval_expr_in(&exp, &list![pair![sym!(x), half]], &arith);
+    /// assert_eq!(result, make_rational(3.to_listv(), 2.to_listv(), &arith));
+    /// ```
+    pub fn eval_expr_in(exp: &List, bindings: &List, arith: &ArithmeticContext) -> List {
+        if is_number(exp) {
+            return exact_number(exp, arith).unwrap_or_else(|| make_float(to_f64(exp), arith));
+        }
+        if is_variable(exp) {
+            return lookup(exp, bindings);
+        }
+        let args: Vec<List> = if exp.is_pair() {
+            exp.tail()
+                .iter()
+                .map(|a| eval_expr_in(&a, bindings, arith))
+                .collect()
+        } else {
+            vec![]
+        };
+        let fold =
+            |first: &List, rest: &[List], op: fn(&ArithmeticContext, &List, &List) -> List| {
+                rest.iter().fold(first.clone(), |x, y| op(arith, &x, y))
+            };
+        match (operator(exp), args.as_slice()) {
+            ("+", []) => make_integer(0, arith),
+            ("+", [a, rest @ ..]) => fold(a, rest, ArithmeticContext::add),
+            ("*", []) => make_integer(1, arith),
+            ("*", [a, rest @ ..]) => fold(a, rest, ArithmeticContext::mul),
+            ("-", [a]) => arith.negative(a),
+            ("-", [a, rest @ ..]) => fold(a, rest, ArithmeticContext::sub),
+            ("/", [a]) => arith.div(&make_integer(1, arith), a),
+            ("/", [a, rest @ ..]) => fold(a, rest, ArithmeticContext::div),
+            ("**", [a, b]) => arith.pow(a, b),
+            _ => panic_with_location("unknown expression type -- EVAL", exp),
+        }
+    }
+
+    /// Substitutes the bound variables, which may be bound to numbers or to expressions, and
+    /// simplifies the result; unbound variables are left symbolic.
+    /// 代入已绑定的变量（值可以是数值或表达式）并化简，未绑定的变量保持符号形式。
+    ///
+    /// # Examples
+    /// ```rust
+    /// use sicp_rs::ch2::ch2_3::evaluate::partial_eval;
+    /// use sicp_rs::prelude::*;
+    ///
+    /// let exp = List::parse("(+ (* x y) (* 2 x) z)").unwrap();
+    /// let bindings = list![pair![sym!(x), 3], pair![sym!(z), list![sym!("-"), sym!(y)]]];
+    /// assert_eq!(partial_eval(&exp, &bindings), List::parse("(+ (* 2 y) 6)").unwrap());
+    /// ```
+    pub fn partial_eval(exp: &List, bindings: &List) -> List {
+        fn substitute(exp: &List, bindings: &List) -> List {
+            if is_variable(exp) {
+                bindings
+                    .assoc(exp)
+                    .map_or_else(|| exp.clone(), |binding| binding.tail())
+            } else if exp.is_pair() {
+                // 运算符本身不代入
+                List::pair(
+                    exp.head(),
+                    exp.tail()
+                        .iter()
+                        .map(|a| substitute(&a, bindings))
+                        .collect(),
+                )
+            } else {
+                exp.clone()
+            }
+        }
+        simplify(&substitute(exp, bindings))
     }
 }
@@ -29,13 +29,13 @@ pub fn apply_generic(op: &List, args: &List, arith: &ArithmeticContext) -> Optio
     } else {
         args.clone()
     };
-    let type_tags = args.map(|x| type_tag(x));
+    let type_tags = args.map(type_tag);
     if let Some(func) = arith.get(list![op.clone(), type_tags]) {
-        func.call(&args.map(|x| contents(x)))
+        func.call(&args.map(contents))
     } else {
         panic!(
             "apply_generic no method for these types op:{}, args:{}",
             op, args
         )
     }
-}
\ No newline at end of file
+}
@@ -1,10 +1,10 @@
-use std::cell::RefCell;
-use std::rc::Rc;
-use std::{fmt, i32};
+use std::collections::{HashMap, VecDeque};
+use std::fmt;
+use std::hash::Hash;
 
-use num::{Integer, Num};
+use num::{BigInt, BigRational, Integer, Num, Signed, ToPrimitive, Zero};
 
-use crate::ch3::ch3_3::make_table_2d;
+use crate::list_impl::{InnerCell, InnerCellExt, Shared};
 use crate::prelude::*;
 
 pub fn attach_tag(tag: &str, contents: &List) -> List {
@@ -19,15 +19,20 @@ pub fn attach_tag(tag: &str, contents: &List) -> List {
 }
 
 pub fn type_tag(datum: &List) -> List {
+    try_type_tag(datum).unwrap_or_else(|| panic!("bad tagged datum -- TYPE-TAG"))
+}
+/// Like `type_tag`, but returns `None` for a datum that carries no type tag.
+/// 与 `type_tag` 相同，但数据没有类型标签时返回 `None`。
+pub fn try_type_tag(datum: &List) -> Option<List> {
     // Only Support f64&i32
     if datum.is_value() && datum.try_as_basis_value::<f64>().is_ok() {
-        "float".to_listv()
+        Some("float".to_listv())
     } else if datum.is_value() && datum.try_as_basis_value::<i32>().is_ok() {
-        "integer".to_listv()
+        Some("integer".to_listv())
     } else if datum.is_pair() {
-        datum.head()
+        Some(datum.head())
     } else {
-        panic!("bad tagged datum -- TYPE-TAG")
+        None
     }
 }
 
@@ -43,259 +48,672 @@ pub fn contents(datum: &List) -> List {
         panic!("bad tagged datum -- CONTENTS")
     }
 }
+/// Reason a generic operation could not be dispatched or carried out; `op` and `types` give the
+/// operation name and the type tags of its arguments.
+/// 通用操作无法分派或执行的原因，`op` 与 `types` 为操作名及参数的类型标签。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DispatchError {
+    /// No procedure is installed for the signature, and no coercion between the types applies.
+    /// 没有对应签名的过程，也无法通过类型转换找到。
+    NoMethod { op: String, types: Vec<String> },
+    /// A coercion procedure from `from` to `to` failed to convert its argument.
+    /// 从 `from` 到 `to` 的类型转换过程失败。
+    CoercionFailed {
+        op: String,
+        types: Vec<String>,
+        from: String,
+        to: String,
+    },
+    /// Procedures are installed for `op`, but none of them takes this many arguments.
+    /// 已为 `op` 安装了过程，但没有一个接受该数量的参数。
+    ArityMismatch { op: String, types: Vec<String> },
+    /// The procedure was found but failed, e.g. division by zero.
+    /// 找到了过程，但执行失败，例如除以零。
+    OperationFailed {
+        op: String,
+        types: Vec<String>,
+        reason: String,
+    },
+    /// An argument carries no type tag, so no procedure can be looked up for it.
+    /// 参数没有类型标签，无法据此查找过程。
+    BadTaggedDatum { op: String, datum: String },
+}
+
+impl DispatchError {
+    /// Builds the value an installed procedure returns to report that it failed.
+    /// 构造已安装的过程在执行失败时返回的值。
+    pub fn operation_failed(op: &str, types: &[&str], reason: &str) -> List {
+        DispatchError::OperationFailed {
+            op: op.to_string(),
+            types: types.iter().map(|t| t.to_string()).collect(),
+            reason: reason.to_string(),
+        }
+        .to_listv()
+    }
+}
+
+// 已安装过程内部调用 try_* 版本，嵌套调用的失败转换为本过程的 OperationFailed 值
+fn fallible_proc(
+    op: &'static str,
+    types: &'static [&'static str],
+    body: impl Fn(&List) -> Result<List, DispatchError> + MaybeSendSync + 'static,
+) -> ClosureWrapper {
+    ClosureWrapper::new(move |args| {
+        Some(
+            body(args)
+                .unwrap_or_else(|e| DispatchError::operation_failed(op, types, &e.to_string())),
+        )
+    })
+}
+
+impl fmt::Display for DispatchError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let signature = |op: &str, types: &[String]| format!("{} ({})", op, types.join(" "));
+        match self {
+            DispatchError::NoMethod { op, types } => {
+                write!(f, "No method for these types -- {}", signature(op, types))
+            }
+            DispatchError::CoercionFailed {
+                op,
+                types,
+                from,
+                to,
+            } => write!(
+                f,
+                "{} to {} coercion failed -- {}",
+                from,
+                to,
+                signature(op, types)
+            ),
+            DispatchError::ArityMismatch { op, types } => write!(
+                f,
+                "No method takes {} args -- {}",
+                types.len(),
+                signature(op, types)
+            ),
+            DispatchError::OperationFailed { op, types, reason } => {
+                write!(f, "{} -- {}", reason, signature(op, types))
+            }
+            DispatchError::BadTaggedDatum { op, datum } => {
+                write!(f, "Bad tagged datum {} -- {}", datum, op)
+            }
+        }
+    }
+}
+
+impl std::error::Error for DispatchError {}
+
+/// Panicking form of `try_apply_generic`: returns `Some(result)`, or panics with the
+/// `DispatchError` message.
+/// `try_apply_generic` 的 panic 版本。
 pub fn apply_generic(op: &List, args: &List, arith: &ArithmeticContext) -> Option<List> {
-    let args = if args.head().is_pair() && args.head().head().is_pair() {
+    Some(try_apply_generic(op, args, arith).unwrap_or_else(|e| panic!("{}", e)))
+}
+
+/// Applies the procedure installed for `op` and the type tags of `args`. When no procedure
+/// matches directly, arguments that are all arithmetic types are raised to their least common
+/// supertype in the tower; otherwise all arguments are coerced to each argument's type in turn,
+/// for any number of arguments.
+/// 按 `op` 与参数的类型标签分派过程；找不到时，若参数均为算术类型，则提升到类型塔中的最低公共
+/// 超类型，否则依次尝试将所有参数转换为每个参数的类型。
+pub fn try_apply_generic(
+    op: &List,
+    args: &List,
+    arith: &ArithmeticContext,
+) -> Result<List, DispatchError> {
+    let args = if args.is_pair() && args.head().is_pair() && args.head().head().is_pair() {
         // 处理可能由于apply_generic导致的嵌套列表
         args.flatmap(|x| x.clone())
     } else {
         args.clone()
     };
 
-    let type_tags = args.map(|x| type_tag(x));
+    if let Some(arg) = args.iter().find(|arg| try_type_tag(arg).is_none()) {
+        return Err(DispatchError::BadTaggedDatum {
+            op: op.to_string(),
+            datum: arg.to_string(),
+        });
+    }
+    let type_tags = args.map(type_tag);
+    let types = || -> Vec<String> { type_tags.iter().map(|t| t.to_string()).collect() };
     let func = arith.get(list![op.clone(), type_tags.clone()]);
     if let Some(func) = func {
-        // 找到对应函数签名，直接调用
-        func.call(&args.map(|x| contents(x)))
-    } else {
-        if args.length() != 2 {
-            panic!(
-                "apply_generic expects 2 args, got {} len {} with op {}, may not found method",
-                args,
-                args.length(),
-                op
-            );
+        // 找到对应函数签名，直接调用；过程以 DispatchError 值报告失败
+        return match func.call(&args.map(contents)) {
+            Some(result) => match result.try_as_basis_value::<DispatchError>() {
+                Ok(e) => Err(e.clone()),
+                Err(_) => Ok(result),
+            },
+            None => Err(DispatchError::OperationFailed {
+                op: op.to_string(),
+                types: types(),
+                reason: "procedure returned no result".to_string(),
+            }),
+        };
+    }
+    let no_method = || {
+        let arity = args.length();
+        let signatures = arith.signatures(&op.to_string());
+        if !signatures.is_empty() && signatures.iter().all(|s| s.head().length() != arity) {
+            DispatchError::ArityMismatch {
+                op: op.to_string(),
+                types: types(),
+            }
+        } else {
+            DispatchError::NoMethod {
+                op: op.to_string(),
+                types: types(),
+            }
         }
-        let type1 = type_tags.head();
-        let type2 = type_tags.tail().head();
-        assert_ne!(type1, type2, "no method found for op:{}, args:{}", op, args);
-        let a1 = args.head();
-        let a2 = args.tail().head();
-
-        //可提升的算术类型
-        if find_arithmetic_type_index(&type1.to_string()) != -1
-            && find_arithmetic_type_index(&type2.to_string()) != -1
-        {
-            let (a1, a2) = unify_arithmetic_types(a1, a2, arith);
-            return apply_generic(op, &list![a1, a2], arith);
+    };
+    let mut targets: Vec<List> = Vec::new();
+    for t in type_tags.iter() {
+        if !targets.contains(&t) {
+            targets.push(t);
         }
-        // 类型强制
-        let try_coerce_and_apply = |t1: &List, t2: &List, a1: &List, a2: &List, direction: i32| {
-            if let Some(t1_to_t2) = arith.get_coercion(t1, t2) {
-                let coerce = |x: &List| {
-                    t1_to_t2
-                        .call(&list![x.clone()])
-                        .expect(&format!("{} to {} coercion failed", t1, t2))
-                };
-                let (a1, a2) = match direction {
-                    1 => (coerce(a1), a2.clone()),
-                    2 => (a1.clone(), coerce(a2)),
-                    _ => unreachable!("coerce direction only support 1:t1_to_t2 and 2:t2_to_t1"),
-                };
-                apply_generic(op, &list![a1, a2], arith)
-            } else {
-                eprintln!("coercion from {} to {} not found", t1, t2);
-                None
+    }
+    if targets.len() < 2 {
+        return Err(no_method());
+    }
+
+    // 参数均为已注册的类型时，提升到类型格中的最低公共超类型
+    if let Some(target) = arith.least_common_supertype(&type_tags) {
+        let mut raised = Vec::new();
+        for arg in args.iter() {
+            match arith.raise_to(&arg, &target) {
+                Some(x) => raised.push(x),
+                None => {
+                    return Err(DispatchError::OperationFailed {
+                        op: op.to_string(),
+                        types: types(),
+                        reason: format!("cannot raise {} to {}", type_tag(&arg), target),
+                    });
+                }
             }
-        };
-        if let Some(result) = try_coerce_and_apply(&type1, &type2, &a1, &a2, 1)
-            .or_else(|| try_coerce_and_apply(&type2, &type1, &a1, &a2, 2))
-        {
-            Some(result)
-        } else {
-            panic!("No method for these types op:{}, args:{}", op, args);
+        }
+        match try_apply_generic(op, &List::from_slice(&raised), arith) {
+            Err(DispatchError::NoMethod { .. } | DispatchError::ArityMismatch { .. }) => {}
+            result => return result,
+        }
+    }
+    // 类型强制（练习 2.82）：依次尝试将所有参数转换为每个参数的类型，
+    // 没有类型转换过程时沿类型格提升
+    for target in &targets {
+        let mut coerced = Vec::new();
+        for (arg, t) in args.iter().zip(type_tags.iter()) {
+            if t == *target {
+                coerced.push(arg);
+                continue;
+            }
+            let Some(coerce) = arith.get_coercion(&t, target) else {
+                match arith.raise_to(&arg, target) {
+                    Some(x) => {
+                        coerced.push(x);
+                        continue;
+                    }
+                    None => break,
+                }
+            };
+            match coerce.call(&list![arg]) {
+                Some(x) => coerced.push(x),
+                None => {
+                    return Err(DispatchError::CoercionFailed {
+                        op: op.to_string(),
+                        types: types(),
+                        from: t.to_string(),
+                        to: target.to_string(),
+                    });
+                }
+            }
+        }
+        if coerced.len() != args.length() {
+            continue;
+        }
+        match try_apply_generic(op, &List::from_slice(&coerced), arith) {
+            // 转换后仍无对应过程，继续尝试下一个类型
+            Err(DispatchError::NoMethod { .. } | DispatchError::ArityMismatch { .. }) => {}
+            result => return result,
         }
     }
+    Err(no_method())
 }
 
 pub fn install_arithmetic_package(arith: &ArithmeticContext) -> Option<List> {
-    install_integer_package(&arith);
-    install_float_package(&arith);
-    install_rational_package(&arith);
-    install_polar_package(&arith);
-    install_rectangular_package(&arith);
-    install_complex_package(&arith);
+    install_integer_package(arith);
+    install_float_package(arith);
+    install_rational_package(arith);
+    install_bigint_package(arith);
+    install_bigrational_package(arith);
+    install_polar_package(arith);
+    install_rectangular_package(arith);
+    install_complex_package(arith);
     Some("done".to_string().to_listv())
 }
 
-const ARITHMETIC_TYPES: [&str; 4] = ["integer", "rational", "float", "complex"];
+// 内置的数值类型
+const BASIS_ARITHMETIC_TYPES: [&str; 6] = [
+    "integer",
+    "bigint",
+    "rational",
+    "bigrational",
+    "float",
+    "complex",
+];
 
+/// Position of `type_tag` in the original tower `integer < rational < float < complex`, or
+/// `-1` for any other type.
+/// 类型在原有类型塔 `integer < rational < float < complex` 中的位置，其他类型返回 `-1`。
+#[deprecated(
+    since = "0.1.0",
+    note = "types are ranked by the registrable lattice; use `ArithmeticContext::type_rank` or \
+            `ArithmeticContext::least_common_supertype`"
+)]
 pub fn find_arithmetic_type_index(type_tag: &str) -> i32 {
-    for (i, t) in ARITHMETIC_TYPES.iter().enumerate() {
-        if type_tag == *t {
-            return i as i32;
-        }
-    }
-    -1
+    ["integer", "rational", "float", "complex"]
+        .iter()
+        .position(|t| *t == type_tag)
+        .map_or(-1, |i| i as i32)
 }
+/// Whether `x` is one of the built-in numeric types: integer, bigint, rational, bigrational,
+/// float or complex.
+/// 是否为内置的数值类型。
 pub fn is_basis_arithmetic_type(x: &List) -> bool {
-    find_arithmetic_type_index(&type_tag(x).to_string()) >= 0
+    BASIS_ARITHMETIC_TYPES.contains(&type_tag(x).to_string().as_str())
 }
 // 将两个值的类型提升到统一的类型。
 pub fn unify_arithmetic_types(a1: List, a2: List, arith: &ArithmeticContext) -> (List, List) {
-    let a1_index = find_arithmetic_type_index(&type_tag(&a1).to_string());
-    let a2_index = find_arithmetic_type_index(&type_tag(&a2).to_string());
-    fn type_raise(x: &List, index_diff: i32, arith: &ArithmeticContext) -> List {
-        if index_diff <= 0 {
-            x.clone()
-        } else {
-            type_raise(&arith.raise(x), index_diff - 1, arith)
+    match raise_to_common_type(&list![a1.clone(), a2.clone()], arith) {
+        Some(raised) => (raised.head(), raised.tail().head()),
+        None => (a1, a2),
+    }
+}
+/// Raises every argument to the least common supertype of their types in the type lattice, or
+/// returns `None` if some type is not registered, the types have no common supertype or an
+/// argument cannot be raised, e.g. a rational of polynomials to `float`.
+/// 将所有参数提升到它们在类型格中的最低公共超类型；若有未注册的类型、没有公共超类型或某个参数
+/// 无法提升，返回 `None`。
+pub fn raise_to_common_type(args: &List, arith: &ArithmeticContext) -> Option<List> {
+    let target = arith.least_common_supertype(&args.map(type_tag))?;
+    args.iter().map(|x| arith.raise_to(&x, &target)).collect()
+}
+
+/// A type registered in the lattice of an `ArithmeticContext`.
+/// 类型格中已注册的类型。
+#[derive(Clone)]
+struct TypeInfo {
+    rank: i32,
+    // 直接超类型及提升到该类型的过程，第一个为 raise 的默认目标
+    supertypes: Vec<(Symbol, ClosureWrapper)>,
+    project: Option<ClosureWrapper>,
+}
+
+// 操作表的键：操作名与参数类型标签，均为驻留后的 Symbol
+type OpKey = (Symbol, Vec<Symbol>);
+
+/// A hash table of procedures that also remembers the order in which keys were first inserted.
+/// 记录键首次插入顺序的过程哈希表。
+struct ProcTable<K> {
+    procs: HashMap<K, ClosureWrapper>,
+    order: Vec<K>,
+}
+
+impl<K: Hash + Eq + Clone> ProcTable<K> {
+    fn new() -> Self {
+        ProcTable {
+            procs: HashMap::new(),
+            order: Vec::new(),
         }
     }
-    let a1 = if a1_index < a2_index {
-        type_raise(&a1, a2_index - a1_index, arith)
-    } else {
-        a1
-    };
-    let a2 = if a1_index > a2_index {
-        type_raise(&a2, a1_index - a2_index, arith)
-    } else {
-        a2
-    };
-    (a1, a2)
+    fn get(&self, key: &K) -> Option<ClosureWrapper> {
+        self.procs.get(key).cloned()
+    }
+    fn insert(&mut self, key: K, proc: ClosureWrapper) {
+        if self.procs.insert(key.clone(), proc).is_none() {
+            self.order.push(key);
+        }
+    }
+    // 与原先的关联表一致，最近加入的表项在前
+    fn entries(&self) -> impl Iterator<Item = (&K, &ClosureWrapper)> {
+        self.order.iter().rev().map(|key| (key, &self.procs[key]))
+    }
+}
+
+// 类型标签统一驻留为 Symbol，同名的字符串与符号视为同一标签
+fn intern_tag(tag: &List) -> Symbol {
+    match tag {
+        List::V(v) if v.as_ref().is_symbol() => *v.as_ref().as_any().downcast_ref().unwrap(),
+        List::V(v) if v.as_ref().is_string() => Symbol::new(&v.as_ref().as_string()),
+        _ => Symbol::new(&tag.to_string()),
+    }
+}
+fn intern_tags(tags: &List) -> Vec<Symbol> {
+    tags.iter().map(|tag| intern_tag(&tag)).collect()
+}
+fn tags_to_list(tags: &[Symbol]) -> List {
+    tags.iter().map(|tag| tag.as_str().to_listv()).collect()
 }
 
+/// The tables of data-directed generic arithmetic: operations, coercions and the type lattice.
+/// Clones share the same tables.
+///
+/// The tables are private. Code that used the former public fields should migrate as follows:
+/// - `optable` (a `Table2d`): use `put`/`get` to install and look up procedures, and
+///   `signatures(op)` to list what is installed for an operation.
+/// - `coercion` (a `Shared<InnerCell<List>>` alist): use `put_coercion`/`get_coercion`, and
+///   `coercions()` to read the whole table as `((type1 type2 proc) ...)`.
+///
+/// 通用算术的数据导向表格：操作表、类型转换表与类型格，克隆后共享同一组表格。
+/// 原先公开的 `optable` 字段改用 `put`/`get`/`signatures`，`coercion` 字段改用
+/// `put_coercion`/`get_coercion`/`coercions`。
 #[derive(Clone)]
 pub struct ArithmeticContext {
-    // 这是所有的操作函数的表格
-    pub optable: Rc<dyn Fn(&str) -> ClosureWrapper>,
-    // 这是类型转换的表格
-    pub coercion: Rc<RefCell<List>>,
+    // 这是所有的操作函数的表格，以 (操作名, 类型标签) 为键
+    optable: Shared<InnerCell<ProcTable<OpKey>>>,
+    // 这是类型转换的表格，以 (源类型, 目标类型) 为键
+    coercion: Shared<InnerCell<ProcTable<(Symbol, Symbol)>>>,
+    // 这是类型格，记录每个类型的层级、超类型以及 raise/project 过程
+    types: Shared<InnerCell<HashMap<Symbol, TypeInfo>>>,
 }
 macro_rules! define_methods {
-    ($($fn_name:ident, $op_name:expr, 2);* $(;)?) => {
+    ($($fn_name:ident, $try_name:ident, $op_name:expr, 2);* $(;)?) => {
         $(
             pub fn $fn_name(&self, x: &List, y: &List) -> List {
-                self.apply_generic($op_name, &list![x.clone(), y.clone()]).unwrap()
+                self.$try_name(x, y).unwrap_or_else(|e| panic!("{}", e))
+            }
+            pub fn $try_name(&self, x: &List, y: &List) -> Result<List, DispatchError> {
+                self.try_apply_generic($op_name, &list![x.clone(), y.clone()])
             }
         )*
     };
-    ($($fn_name:ident, $op_name:expr, 1);* $(;)?) => {
+    ($($fn_name:ident, $try_name:ident, $op_name:expr, 1);* $(;)?) => {
         $(
             pub fn $fn_name(&self, x: &List) -> List {
-                self.apply_generic($op_name, &list![x.clone()]).unwrap()
+                self.$try_name(x).unwrap_or_else(|e| panic!("{}", e))
+            }
+            pub fn $try_name(&self, x: &List) -> Result<List, DispatchError> {
+                self.try_apply_generic($op_name, &list![x.clone()])
             }
         )*
     };
 }
+impl Default for ArithmeticContext {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl ArithmeticContext {
     pub fn new() -> Self {
         ArithmeticContext {
-            optable: make_table_2d(),
-            coercion: Rc::new(RefCell::new(List::Nil)),
+            optable: Shared::new(InnerCell::new(ProcTable::new())),
+            coercion: Shared::new(InnerCell::new(ProcTable::new())),
+            types: Shared::new(InnerCell::new(HashMap::new())),
         }
     }
+    /// Looks up the procedure for `keys`, a list `(op (type ...))`.
+    /// 查找 `(op (type ...))` 对应的过程。
     pub fn get(&self, keys: List) -> Option<ClosureWrapper> {
-        let lookup = (self.optable)("lookup");
-        if let Some(closure) = lookup.call(&keys) {
-            if let Ok(closure) = closure.try_as_basis_value::<ClosureWrapper>() {
-                Some(closure.clone())
-            } else {
-                eprintln!(
-                    "ArithmeticContext get failed! try_into_Closure failed for keys:{}",
-                    keys
-                );
-                None
-            }
-        } else {
-            None
-        }
+        let key = (intern_tag(&keys.head()), intern_tags(&keys.tail().head()));
+        self.optable.read_cell().get(&key)
     }
     pub fn put(&self, key1: &'static str, key2: List, closure: ClosureWrapper) {
-        let insert = (self.optable)("insert");
-        if insert
-            .call(&list![key1.to_listv(), key2.clone(), closure])
-            .is_none()
-        {
-            panic!(
-                "ArithmeticContext put failed! insert closure failed for keys:{}",
-                list![key1, key2]
-            )
-        }
+        let key = (Symbol::new(key1), intern_tags(&key2));
+        self.optable.write_cell().insert(key, closure);
+    }
+    /// Returns every signature installed for `op` as `(((type ...) . proc) ...)`, most recently
+    /// installed first; the type tags are returned as strings.
+    /// 返回 `op` 的所有函数签名，最近加入的在前，类型标签以字符串返回。
+    pub fn signatures(&self, op: &str) -> List {
+        let op = Symbol::new(op);
+        self.optable
+            .read_cell()
+            .entries()
+            .filter(|((name, _), _)| *name == op)
+            .map(|((_, tags), proc)| List::pair(tags_to_list(tags), proc.clone().to_listv()))
+            .collect()
     }
 
+    // 每个操作都有 panic 版本与返回 Result 的 try_ 版本
     define_methods! {
-        add, "add", 2;
-        sub, "sub", 2;
-        mul, "mul", 2;
-        div, "div", 2;
-        is_equal, "is_equal", 2;
+        add, try_add, "add", 2;
+        sub, try_sub, "sub", 2;
+        mul, try_mul, "mul", 2;
+        div, try_div, "div", 2;
+        is_equal, try_is_equal, "is_equal", 2;
+        gcd, try_gcd, "gcd", 2;
+        reduce, try_reduce, "reduce", 2;
+        pow, try_pow, "pow", 2;
     }
 
     define_methods! {
-        is_equal_to_zero, "is_equal_to_zero", 1;
-        negative, "negative", 1;
-        abs, "abs", 1;
-        numer, "numer", 1;
-        denom, "denom", 1;
-        real_part, "real_part", 1;
-        imag_part, "imag_part", 1;
-        magnitude, "magnitude", 1;
-        angle, "angle", 1;
-    }
+        is_equal_to_zero, try_is_equal_to_zero, "is_equal_to_zero", 1;
+        negative, try_negative, "negative", 1;
+        abs, try_abs, "abs", 1;
+        numer, try_numer, "numer", 1;
+        denom, try_denom, "denom", 1;
+        real_part, try_real_part, "real_part", 1;
+        imag_part, try_imag_part, "imag_part", 1;
+        magnitude, try_magnitude, "magnitude", 1;
+        angle, try_angle, "angle", 1;
+        lower_bound, try_lower_bound, "lower_bound", 1;
+        upper_bound, try_upper_bound, "upper_bound", 1;
+        center, try_center, "center", 1;
+        width, try_width, "width", 1;
+        percent, try_percent, "percent", 1;
+    }
+    /// Raises `x` to the first supertype of its type; `x` is returned unchanged if its type is
+    /// not registered or has no supertype.
+    /// 将 `x` 提升到其类型的第一个超类型；未注册的类型或最高层的类型原样返回。
     pub fn raise(&self, x: &List) -> List {
-        // only raise for basis arith type and if x is not a complex number
-        if !is_basis_arithmetic_type(x) || type_tag(x) == "complex".to_listv() {
-            x.clone()
-        } else {
-            self.apply_generic(&"raise", &list![x.clone()]).unwrap()
+        match self
+            .type_info(x)
+            .and_then(|info| info.supertypes.first().cloned())
+        {
+            Some((supertype, raise)) => raise
+                .call(&list![contents(x)])
+                .unwrap_or_else(|| panic!("raise {} to {} failed", x, supertype.as_str())),
+            None => x.clone(),
         }
     }
+    /// Projects `x` one level down the lattice; `x` is returned unchanged if its type has no
+    /// `project` procedure or the procedure fails.
+    /// 将 `x` 投影到下一层类型；没有 project 过程或投影失败时原样返回。
     pub fn project(&self, x: &List) -> List {
-        // only project for basis arith type and if x is not an integer
-        if !is_basis_arithmetic_type(x) || type_tag(x) == "integer".to_listv() {
-            x.clone()
-        } else {
-            self.apply_generic(&"project", &list![x.clone()]).unwrap()
-        }
+        self.type_info(x)
+            .and_then(|info| info.project)
+            .and_then(|project| project.call(&list![contents(x)]))
+            .unwrap_or_else(|| x.clone())
     }
     pub fn sqrt(&self, x: &List) -> List {
         assert!(
             is_basis_arithmetic_type(x) && type_tag(x) != "complex".to_listv(),
             "sqrt only for (integer, rational, float)"
         );
-        self.apply_generic(&"sqrt", &list![x.clone()]).unwrap()
-    }
-    pub fn gcd(&self, x: &List, y: &List) -> List {
-        self.apply_generic(&"gcd", &list![x.clone(), y.clone()])
-            .unwrap()
-    }
-    pub fn reduce(&self, x: &List, y: &List) -> List {
-        self.apply_generic(&"reduce", &list![x.clone(), y.clone()])
-            .unwrap()
+        self.try_sqrt(x).unwrap_or_else(|e| panic!("{}", e))
     }
-    pub fn pow(&self, x: &List, y: &List) -> List {
-        self.apply_generic(&"pow", &list![x.clone(), y.clone()])
-            .unwrap()
+    /// `sqrt` returning the `DispatchError` instead of panicking, e.g. for a complex number or
+    /// a rational of polynomials.
+    /// `sqrt` 的 try_ 版本，失败时返回 `DispatchError`。
+    pub fn try_sqrt(&self, x: &List) -> Result<List, DispatchError> {
+        self.try_apply_generic("sqrt", &list![x.clone()])
     }
     /// term_list support
     /// always return sparse term_list, as [sparse, [term]], not [sparse, term], use contents(head(tl)) to get first term
     pub fn first_term(&self, t: &List) -> List {
-        self.apply_generic(&"first_term", &list![t.clone()])
-            .unwrap()
+        self.apply_generic("first_term", &list![t.clone()]).unwrap()
     }
     pub fn rest_terms(&self, t: &List) -> List {
-        self.apply_generic(&"rest_terms", &list![t.clone()])
-            .unwrap()
+        self.apply_generic("rest_terms", &list![t.clone()]).unwrap()
     }
     pub fn adjoin_term(&self, t: &List, term_list: &List) -> List {
-        self.apply_generic(&"adjoin_term", &list![t.clone(), term_list.clone()])
+        self.apply_generic("adjoin_term", &list![t.clone(), term_list.clone()])
             .unwrap()
     }
+    /// Lowers `x` down the lattice as far as projecting and raising back gives an equal value.
+    /// 沿类型格尽可能地降低 `x` 的类型，直到投影后再提升的值与原值不相等。
     pub fn drop(&self, x: &List) -> List {
-        // integer类型已无法继续drop
-        self.drop_to_type(x, "integer".to_string())
+        self.drop_until(x, None)
     }
     pub fn drop_to_type(&self, x: &List, target_type: String) -> List {
-        if type_tag(x) == target_type.clone().to_listv() || type_tag(x) == "integer".to_listv() {
+        self.drop_until(x, Some(Symbol::new(&target_type)))
+    }
+    fn drop_until(&self, x: &List, target: Option<Symbol>) -> List {
+        let tag = type_tag(x);
+        if Some(intern_tag(&tag)) == target {
+            return x.clone();
+        }
+        // 没有 project 过程的类型（如 integer）或投影失败时已无法继续drop
+        let Some(new_x) = self
+            .type_info(x)
+            .and_then(|info| info.project)
+            .and_then(|project| project.call(&list![contents(x)]))
+        else {
             return x.clone();
         };
-        let new_x = self.project(x);
-        if self.is_equal(&self.raise(&new_x), &x) == true.to_listv() {
-            self.drop_to_type(&new_x, target_type)
-        } else {
+        match self.raise_to(&new_x, &tag) {
+            Some(back) if self.is_equal(&back, x) == true.to_listv() => {
+                self.drop_until(&new_x, target)
+            }
             // 可能不是target_type，已无法继续drop
-            x.clone()
+            _ => x.clone(),
+        }
+    }
+    // type lattice support
+    /// Registers `name` in the type lattice with its `rank` (higher ranks are further up), its
+    /// direct supertypes with the procedures raising a value to each of them, and the procedure
+    /// projecting a value one level down. Like other installed procedures they receive the
+    /// contents of the value. The first supertype and `project` are also installed as the
+    /// `raise`/`project` operations.
+    /// 在类型格中注册 `name`：层级 `rank`（越大越高）、各直接超类型及提升过程、向下投影的过程；
+    /// 第一个超类型的提升过程与 `project` 同时作为 `raise`/`project` 操作安装。
+    pub fn register_type(
+        &self,
+        name: &str,
+        rank: i32,
+        supertypes: &[(&str, ClosureWrapper)],
+        project: Option<ClosureWrapper>,
+    ) {
+        if let Some((_, raise)) = supertypes.first() {
+            self.put("raise", list![name.to_string()], raise.clone());
+        }
+        if let Some(project) = &project {
+            self.put("project", list![name.to_string()], project.clone());
+        }
+        let info = TypeInfo {
+            rank,
+            supertypes: supertypes
+                .iter()
+                .map(|(supertype, raise)| (Symbol::new(supertype), raise.clone()))
+                .collect(),
+            project,
+        };
+        self.types.write_cell().insert(Symbol::new(name), info);
+    }
+    /// Adds `supertype` as a further direct supertype of the registered type `name`, reached by
+    /// `raise`; the `raise` operation of `name` is left unchanged.
+    /// 为已注册的类型 `name` 增加一个直接超类型，`raise` 操作保持不变。
+    pub fn register_supertype(&self, name: &str, supertype: &str, raise: ClosureWrapper) {
+        self.types
+            .write_cell()
+            .get_mut(&Symbol::new(name))
+            .unwrap_or_else(|| panic!("register_supertype: type {} is not registered", name))
+            .supertypes
+            .push((Symbol::new(supertype), raise));
+    }
+    fn type_info(&self, x: &List) -> Option<TypeInfo> {
+        self.types
+            .read_cell()
+            .get(&intern_tag(&type_tag(x)))
+            .cloned()
+    }
+    /// Returns the rank of a registered type tag.
+    /// 返回已注册类型的层级。
+    pub fn type_rank(&self, tag: &List) -> Option<i32> {
+        self.types
+            .read_cell()
+            .get(&intern_tag(tag))
+            .map(|info| info.rank)
+    }
+    // 类型自身及其所有超类型，按广度优先的顺序
+    fn ancestors(&self, tag: Symbol) -> Vec<Symbol> {
+        let types = self.types.read_cell();
+        let mut found = vec![tag];
+        let mut i = 0;
+        while i < found.len() {
+            if let Some(info) = types.get(&found[i]) {
+                for (supertype, _) in &info.supertypes {
+                    if !found.contains(supertype) {
+                        found.push(*supertype);
+                    }
+                }
+            }
+            i += 1;
+        }
+        found
+    }
+    /// Whether `supertype` can be reached from `tag` by raising; every type is its own supertype.
+    /// `supertype` 是否可由 `tag` 提升得到；每个类型都是自身的超类型。
+    pub fn is_subtype(&self, tag: &List, supertype: &List) -> bool {
+        self.ancestors(intern_tag(tag))
+            .contains(&intern_tag(supertype))
+    }
+    /// Returns the registered common supertype of `tags` with the lowest rank, or `None` if some
+    /// tag is not registered or they have no common supertype.
+    /// 返回 `tags` 中各类型层级最低的公共超类型。
+    pub fn least_common_supertype(&self, tags: &List) -> Option<List> {
+        let tags: Vec<Symbol> = tags.iter().map(|tag| intern_tag(&tag)).collect();
+        let (first, rest) = tags.split_first()?;
+        if !tags
+            .iter()
+            .all(|tag| self.types.read_cell().contains_key(tag))
+        {
+            return None;
+        }
+        let mut common = self.ancestors(*first);
+        for tag in rest {
+            let ancestors = self.ancestors(*tag);
+            common.retain(|t| ancestors.contains(t));
         }
+        let types = self.types.read_cell();
+        common
+            .into_iter()
+            .filter_map(|t| types.get(&t).map(|info| (t, info.rank)))
+            .reduce(|best, t| if t.1 < best.1 { t } else { best })
+            .map(|(t, _)| t.as_str().to_listv())
+    }
+    /// Raises `x` along the shortest path of the lattice to the type `target`, or returns `None`
+    /// if `target` is not a supertype of the type of `x` or a raise procedure on the way fails.
+    /// 沿类型格中最短的路径将 `x` 提升到 `target` 类型；不可达或提升过程失败时返回 `None`。
+    pub fn raise_to(&self, x: &List, target: &List) -> Option<List> {
+        let (from, to) = (intern_tag(&type_tag(x)), intern_tag(target));
+        let path = {
+            let types = self.types.read_cell();
+            let mut parents: HashMap<Symbol, (Symbol, ClosureWrapper)> = HashMap::new();
+            let mut queue = VecDeque::from([from]);
+            while let Some(t) = queue.pop_front() {
+                if t == to {
+                    break;
+                }
+                for (supertype, raise) in types.get(&t).map_or(&[][..], |info| &info.supertypes) {
+                    if *supertype != from && !parents.contains_key(supertype) {
+                        parents.insert(*supertype, (t, raise.clone()));
+                        queue.push_back(*supertype);
+                    }
+                }
+            }
+            let mut path = Vec::new();
+            let mut t = to;
+            while t != from {
+                let (parent, raise) = parents.get(&t)?.clone();
+                path.push(raise);
+                t = parent;
+            }
+            path.reverse();
+            path
+        };
+        // 调用过程时不持有类型格的锁，过程中可能再次访问 ArithmeticContext
+        path.into_iter()
+            .try_fold(x.clone(), |x, raise| raise.call(&list![contents(&x)]))
     }
     // coercion support
     pub fn put_coercion(
@@ -304,52 +722,83 @@ impl ArithmeticContext {
         type2: &List,
         proc: ClosureWrapper,
     ) -> Option<List> {
-        if self.get_coercion(type1, type2).is_none() {
-            let old_coercion = self.coercion.borrow().clone();
-            let mut coercion = self.coercion.borrow_mut();
-            *coercion = pair![list![type1.clone(), type2.clone(), proc], old_coercion]
+        // 已存在的类型转换不会被覆盖
+        let key = (intern_tag(type1), intern_tag(type2));
+        let mut coercion = self.coercion.write_cell();
+        if coercion.get(&key).is_none() {
+            coercion.insert(key, proc);
         }
         Some("done".to_listv())
     }
     pub fn get_coercion(&self, type1: &List, type2: &List) -> Option<ClosureWrapper> {
-        fn get_type1(list_item: &List) -> List {
-            list_item.head()
-        }
-        fn get_type2(list_item: &List) -> List {
-            list_item.tail().head()
-        }
-        fn get_proc(list_item: &List) -> List {
-            list_item.tail().tail().head()
-        }
-        fn get_coercion_iter(type1: &List, type2: &List, items: &List) -> Option<ClosureWrapper> {
-            if items.is_empty() {
-                None
-            } else {
-                let top = items.head();
-
-                if get_type1(&top) == *type1 && get_type2(&top) == *type2 {
-                    if let Ok(proc) = get_proc(&top).try_as_basis_value::<ClosureWrapper>() {
-                        Some(proc.clone())
-                    } else {
-                        eprintln!(
-                            "get_coercion_iter failed! try_into_Closure failed for keys:{}",
-                            top
-                        );
-                        None
-                    }
-                } else {
-                    get_coercion_iter(type1, type2, &items.tail())
-                }
-            }
-        }
-        get_coercion_iter(type1, type2, &self.coercion.borrow().clone())
+        self.coercion
+            .read_cell()
+            .get(&(intern_tag(type1), intern_tag(type2)))
+    }
+    /// Returns the coercion table as `((type1 type2 proc) ...)`, most recently installed first;
+    /// the type tags are returned as strings.
+    /// 以 `((type1 type2 proc) ...)` 的形式返回类型转换表，最近加入的在前。
+    pub fn coercions(&self) -> List {
+        self.coercion
+            .read_cell()
+            .entries()
+            .map(|((t1, t2), proc)| {
+                list![
+                    t1.as_str().to_listv(),
+                    t2.as_str().to_listv(),
+                    proc.clone().to_listv()
+                ]
+            })
+            .collect()
     }
     pub fn apply_generic(&self, op: &'static str, args: &List) -> Option<List> {
         apply_generic(&op.to_listv(), args, self)
     }
+    pub fn try_apply_generic(&self, op: &'static str, args: &List) -> Result<List, DispatchError> {
+        try_apply_generic(&op.to_listv(), args, self)
+    }
+}
+
+/// Largest exponent `pow` accepts for `integer`, `bigint` and `bigrational` values once the
+/// result no longer fits in `i32` or the base is not 0 or ±1; larger exponents fail with
+/// `DispatchError::OperationFailed` instead of building an enormous `BigInt`.
+/// 结果超出 `i32` 时 `pow` 接受的最大指数，更大的指数返回 `OperationFailed`。
+pub const MAX_POW_EXPONENT: u32 = 1 << 16;
+
+/// Integer values accepted by `make_integer`: values that fit in `i32` make an `integer`,
+/// larger ones a `bigint`.
+/// `make_integer` 接受的整数：可放入 `i32` 的构造为 `integer`，否则构造为 `bigint`。
+pub trait IntegerLiteral {
+    fn into_i32(self) -> Result<i32, BigInt>;
+}
+impl IntegerLiteral for i32 {
+    fn into_i32(self) -> Result<i32, BigInt> {
+        Ok(self)
+    }
+}
+macro_rules! impl_integer_literal {
+    ($($t:ty),*) => {
+        $(
+            impl IntegerLiteral for $t {
+                fn into_i32(self) -> Result<i32, BigInt> {
+                    i32::try_from(self).map_err(|_| BigInt::from(self))
+                }
+            }
+        )*
+    };
+}
+impl_integer_literal!(i64, i128, u32, u64, u128);
+impl IntegerLiteral for BigInt {
+    fn into_i32(self) -> Result<i32, BigInt> {
+        i32::try_from(&self).map_err(|_| self)
+    }
 }
 
-pub fn make_integer(x: i32, arith: &ArithmeticContext) -> List {
+pub fn make_integer(x: impl IntegerLiteral, arith: &ArithmeticContext) -> List {
+    let x = match x.into_i32() {
+        Ok(x) => x,
+        Err(x) => return make_bigint(x, arith),
+    };
     if let Some(integer) = arith
         .get(list!["make", list!["integer"]])
         .expect("make_integer: arith.get(list![\"make\", list![\"integer\"]]) failed])")
@@ -360,6 +809,28 @@ pub fn make_integer(x: i32, arith: &ArithmeticContext) -> List {
         panic!("make_integer failed for x:{}", x)
     }
 }
+pub fn make_bigint(x: BigInt, arith: &ArithmeticContext) -> List {
+    if let Some(bigint) = arith
+        .get(list!["make", list!["bigint"]])
+        .expect("make_bigint: arith.get(list![\"make\", list![\"bigint\"]]) failed])")
+        .call(&list![x.clone()])
+    {
+        bigint
+    } else {
+        panic!("make_bigint failed for x:{}", x)
+    }
+}
+pub fn make_bigrational(x: BigRational, arith: &ArithmeticContext) -> List {
+    if let Some(bigrational) = arith
+        .get(list!["make", list!["bigrational"]])
+        .expect("make_bigrational: arith.get(list![\"make\", list![\"bigrational\"]]) failed])")
+        .call(&list![x.clone()])
+    {
+        bigrational
+    } else {
+        panic!("make_bigrational failed for x:{}", x)
+    }
+}
 pub fn make_float(x: f64, arith: &ArithmeticContext) -> List {
     if let Some(float) = arith
         .get(list!["make", list!["float"]])
@@ -403,6 +874,28 @@ pub fn make_complex_from_mag_ang(r: List, a: List, arith: &ArithmeticContext) ->
         panic!("make_complex_from_mag_ang failed for r:{}, a:{}", r, a)
     }
 }
+pub fn make_interval(lower: f64, upper: f64, arith: &ArithmeticContext) -> List {
+    if let Some(interval) = arith
+        .get(list!["make", list!["interval"]])
+        .expect("make_interval: arith.get(list![\"make\", list![\"interval\"]]) failed])")
+        .call(&list![lower, upper])
+    {
+        interval
+    } else {
+        panic!("make_interval failed for lower:{}, upper:{}", lower, upper)
+    }
+}
+pub fn make_center_percent(center: f64, percent: f64, arith: &ArithmeticContext) -> List {
+    if let Some(interval) = arith
+        .get(list!["make_center_percent", list!["interval"]])
+        .expect("make_center_percent: arith.get(list![\"make_center_percent\", list![\"interval\"]]) failed])")
+        .call(&list![center, percent])
+    {
+        interval
+    } else {
+        panic!("make_center_percent failed for center:{}, percent:{}", center, percent)
+    }
+}
 pub fn make_terms_from_sparse(term_list: &List, arith: &ArithmeticContext) -> List {
     if let Some(terms) = arith
        .get(list!["make_terms_from_sparse", list!["sparse"]])
@@ -455,20 +948,22 @@ pub fn make_polynomial_from_dense(
         panic!("make_polynomial_from_dense failed for variable:{}, term_list:{}", variable,term_list)
     }
 }
-fn install_binary_op<T: fmt::Debug + Clone + 'static>(
+fn install_binary_op<T: fmt::Debug + Clone + MaybeSendSync + 'static>(
     op_name: &'static str,
     tag_name: &'static str,
-    op: impl Fn(T, T) -> List + 'static,
+    op: impl Fn(T, T) -> List + MaybeSendSync + 'static,
     arith: &ArithmeticContext,
 ) {
     let get_value = move |x: &List| {
         x.try_as_basis_value::<T>()
-            .expect(&format!(
-                "{} only supports {}, please construct {} with the correct type",
-                tag_name,
-                std::any::type_name::<T>(),
-                tag_name
-            ))
+            .unwrap_or_else(|_| {
+                panic!(
+                    "{} only supports {}, please construct {} with the correct type",
+                    tag_name,
+                    std::any::type_name::<T>(),
+                    tag_name
+                )
+            })
             .clone()
     };
 
@@ -481,20 +976,22 @@ fn install_binary_op<T: fmt::Debug + Clone + 'static>(
         }),
     );
 }
-fn install_unary_op<T: fmt::Debug + Clone + 'static>(
+fn install_unary_op<T: fmt::Debug + Clone + MaybeSendSync + 'static>(
     op_name: &'static str,
     tag_name: &'static str,
-    op: impl Fn(T) -> List + 'static,
+    op: impl Fn(T) -> List + MaybeSendSync + 'static,
     arith: &ArithmeticContext,
 ) {
     let get_value = move |x: &List| {
         x.try_as_basis_value::<T>()
-            .expect(&format!(
-                "{} only supports {}, please construct {} with the correct type",
-                tag_name,
-                std::any::type_name::<T>(),
-                tag_name
-            ))
+            .unwrap_or_else(|_| {
+                panic!(
+                    "{} only supports {}, please construct {} with the correct type",
+                    tag_name,
+                    std::any::type_name::<T>(),
+                    tag_name
+                )
+            })
             .clone()
     };
     arith.put(
@@ -506,9 +1003,11 @@ fn install_unary_op<T: fmt::Debug + Clone + 'static>(
         }),
     )
 }
-pub fn install_basic_numeric_type<T: fmt::Debug + Copy + Num + PartialOrd + 'static>(
+pub fn install_basic_numeric_type<
+    T: fmt::Debug + Clone + Num + PartialOrd + MaybeSendSync + 'static,
+>(
     tag_name: &'static str,
-    make_value: impl Fn(T) -> List + Clone + 'static,
+    make_value: impl Fn(T) -> List + Clone + MaybeSendSync + 'static,
     arith: &ArithmeticContext,
 ) -> Option<List> {
     let tag = |x| attach_tag(tag_name, &x);
@@ -547,7 +1046,7 @@ pub fn install_basic_numeric_type<T: fmt::Debug + Copy + Num + PartialOrd + 'sta
             let make_value_ = make_value.clone();
             move |x: T, y: T| {
                 if y == T::zero() {
-                    panic!("{} divide by zero", tag_name)
+                    DispatchError::operation_failed("div", &[tag_name, tag_name], "divide by zero")
                 } else {
                     make_value_(x / y)
                 }
@@ -607,10 +1106,69 @@ pub fn install_integer_package(arith: &ArithmeticContext) -> Option<List> {
         },
         arith,
     );
-    arith.put("raise", list!["integer"], {
-        let arith = arith.clone();
-        ClosureWrapper::new(move |args| Some(make_rational(args.head(), 1.to_listv(), &arith)))
-    });
+    // 溢出时提升为 bigint
+    let install_checked_op = |op_name: &'static str,
+                              checked: fn(i32, i32) -> Option<i32>,
+                              big: fn(BigInt, BigInt) -> BigInt| {
+        let arith_ = arith.clone();
+        install_binary_op::<i32>(
+            op_name,
+            "integer",
+            move |x, y| match checked(x, y) {
+                Some(z) => z.to_listv(),
+                None => make_bigint(big(x.into(), y.into()), &arith_),
+            },
+            arith,
+        );
+    };
+    install_checked_op("add", i32::checked_add, |x, y| x + y);
+    install_checked_op("sub", i32::checked_sub, |x, y| x - y);
+    install_checked_op("mul", i32::checked_mul, |x, y| x * y);
+    // div integer：i32::MIN / -1 溢出时同样提升为 bigint
+    install_binary_op::<i32>(
+        "div",
+        "integer",
+        {
+            let arith = arith.clone();
+            move |x, y| match (y, x.checked_div(y)) {
+                (0, _) => DispatchError::operation_failed(
+                    "div",
+                    &["integer", "integer"],
+                    "divide by zero",
+                ),
+                (_, Some(z)) => z.to_listv(),
+                (_, None) => make_integer(i64::from(x) / i64::from(y), &arith),
+            }
+        },
+        arith,
+    );
+    install_unary_op::<i32>(
+        "negative",
+        "integer",
+        {
+            let arith = arith.clone();
+            move |x| make_integer(-i64::from(x), &arith)
+        },
+        arith,
+    );
+    install_unary_op::<i32>(
+        "abs",
+        "integer",
+        {
+            let arith = arith.clone();
+            move |x| make_integer(i64::from(x).abs(), &arith)
+        },
+        arith,
+    );
+    arith.register_type(
+        "integer",
+        0,
+        &[("rational", {
+            let arith = arith.clone();
+            ClosureWrapper::new(move |args| Some(make_rational(args.head(), 1.to_listv(), &arith)))
+        })],
+        None,
+    );
     // sqrt integer
     arith.put("sqrt", list!["integer"], {
         let arith = arith.clone();
@@ -627,20 +1185,59 @@ pub fn install_integer_package(arith: &ArithmeticContext) -> Option<List> {
             Some(x)
         })
     });
-    // gcd integer
-    install_binary_op::<i32>("gcd", "integer", move |a, b| a.gcd(&b).to_listv(), arith);
+    // gcd integer：在 i64 中计算，gcd(i32::MIN, 0) 为 2^31，提升为 bigint
+    install_binary_op::<i32>(
+        "gcd",
+        "integer",
+        {
+            let arith = arith.clone();
+            move |a, b| make_integer(i64::from(a).gcd(&i64::from(b)), &arith)
+        },
+        arith,
+    );
     // pow integer
     install_binary_op::<i32>(
         "pow",
         "integer",
-        move |a, b| a.pow(b as u32).to_listv(),
+        {
+            let arith = arith.clone();
+            move |a, b| match u32::try_from(b) {
+                Ok(b) => match a.checked_pow(b) {
+                    Some(x) => x.to_listv(),
+                    None if b > MAX_POW_EXPONENT => DispatchError::operation_failed(
+                        "pow",
+                        &["integer", "integer"],
+                        "exponent too large",
+                    ),
+                    None => make_bigint(BigInt::from(a).pow(b), &arith),
+                },
+                Err(_) => DispatchError::operation_failed(
+                    "pow",
+                    &["integer", "integer"],
+                    "negative exponent",
+                ),
+            }
+        },
         arith,
     );
     // reduce integer
     install_binary_op::<i32>(
         "reduce",
         "integer",
-        move |a, b| list![a / (a.gcd(&b)), b / (a.gcd(&b))],
+        {
+            let arith = arith.clone();
+            move |a, b| {
+                let (a, b) = (i64::from(a), i64::from(b));
+                match a.gcd(&b) {
+                    0 => DispatchError::operation_failed(
+                        "reduce",
+                        &["integer", "integer"],
+                        "divide by zero",
+                    ),
+                    g => list![make_integer(a / g, &arith), make_integer(b / g, &arith)],
+                }
+            }
+        },
         arith,
     );
     Some("done".to_string().to_listv())
@@ -654,7 +1251,7 @@ pub fn install_float_package(arith: &ArithmeticContext) -> Option<List> {
         },
         arith,
     );
-    arith.put("raise", list!["float"], {
+    let raise = {
         let arith = arith.clone();
         ClosureWrapper::new(move |args| {
             Some(make_complex_from_real_imag(
@@ -663,9 +1260,9 @@ pub fn install_float_package(arith: &ArithmeticContext) -> Option<List> {
                 &arith,
             ))
         })
-    });
+    };
     // project real to rational
-    arith.put("project", list!["float"], {
+    let project = {
         let arith = arith.clone();
         ClosureWrapper::new(move |args| {
             let real = args.head();
@@ -673,7 +1270,8 @@ pub fn install_float_package(arith: &ArithmeticContext) -> Option<List> {
             let (numer, denom) = float_to_fraction(*real, i32::MAX);
             Some(make_rational(numer.to_listv(), denom.to_listv(), &arith))
         })
-    });
+    };
+    arith.register_type("float", 4, &[("complex", raise)], Some(project));
     // sqrt float
     arith.put("sqrt", list!["float"], {
         let arith = arith.clone();
@@ -687,14 +1285,35 @@ pub fn install_float_package(arith: &ArithmeticContext) -> Option<List> {
     });
     // pow float
     install_binary_op::<f64>("pow", "float", move |a, b| a.powf(b).to_listv(), arith);
+    // is_equal float 容许舍入误差
+    install_binary_op::<f64>(
+        "is_equal",
+        "float",
+        move |a, b| is_close_float(a, b).to_listv(),
+        arith,
+    );
     Some("done".to_string().to_listv())
 }
+// 在舍入误差范围内比较浮点数（绝对或相对误差小于 f64::EPSILON），使 0.1 + 0.2 等于 0.3。
+// List 的 `==` 对浮点数做精确比较，以保持传递性并与 Hash 一致，因此通用算术的 is_equal 单独保留此容差。
+fn is_close_float(a: f64, b: f64) -> bool {
+    let dynamic_epsilon = f64::EPSILON * a.abs().max(b.abs());
+    (a - b).abs() < f64::EPSILON || (a - b).abs() < dynamic_epsilon
+}
 
+// 有理数 x 的分子分母尽量降为 integer；不是整数（如多项式）时返回 None
+fn integer_parts(x: &List, arith: &ArithmeticContext) -> Option<(i32, i32)> {
+    let n = arith.drop(&arith.try_numer(x).ok()?);
+    let d = arith.drop(&arith.try_denom(x).ok()?);
+    Some((
+        *n.try_as_basis_value::<i32>().ok()?,
+        *d.try_as_basis_value::<i32>().ok()?,
+    ))
+}
 pub fn install_rational_package(arith: &ArithmeticContext) -> Option<List> {
     let tag = |x| attach_tag("rational", &x);
     arith.put("make", list!["rational"], {
         let arith = arith.clone();
-        let tag = tag.clone();
         ClosureWrapper::new(move |args| {
             let (n, d) = (args.head(), args.tail().head());
             assert!(
@@ -712,6 +1331,14 @@ pub fn install_rational_package(arith: &ArithmeticContext) -> Option<List> {
                     let (n, d) = (res.head(), res.tail().head());
                     Some(tag(pair!(n, d)))
                 }
+                // 含 bigint 时按 bigrational 约分，能放入 i32 的仍为 rational
+                ("integer" | "bigint", "integer" | "bigint") => {
+                    let x = BigRational::new(to_bigint(&n)?, to_bigint(&d)?);
+                    match (i32::try_from(x.numer()), i32::try_from(x.denom())) {
+                        (Ok(n), Ok(d)) => Some(tag(pair!(n, d))),
+                        _ => Some(make_bigrational(x, &arith)),
+                    }
+                }
                 // complex
                 _ => Some(tag(pair!(n.clone(), d.clone()))),
             }
@@ -729,153 +1356,329 @@ pub fn install_rational_package(arith: &ArithmeticContext) -> Option<List> {
     );
 
     let extract_xy_numer_denom = {
-        let (arith, tag) = (arith.clone(), tag.clone());
+        let (arith, tag) = (arith.clone(), tag);
         move |args: &List| {
             // 使用 tag 函数重新附加数据类型标签：
             // apply_generic 在处理参数时会移除类型标签，
             // 这里通过 tag 函数重新为参数附加类型标签，以便后续操作能够识别数据类型。
             let (x, y) = (tag(args.head()), tag(args.tail().head()));
-            let (numer_x, denom_x) = (arith.numer(&x), arith.denom(&x));
-            let (numer_y, denom_y) = (arith.numer(&y), arith.denom(&y));
-            (numer_x, denom_x, numer_y, denom_y)
+            let (numer_x, denom_x) = (arith.try_numer(&x)?, arith.try_denom(&x)?);
+            let (numer_y, denom_y) = (arith.try_numer(&y)?, arith.try_denom(&y)?);
+            Ok::<_, DispatchError>((numer_x, denom_x, numer_y, denom_y))
         }
     };
+    // 嵌套的通用操作使用 try_* 版本，失败时由 fallible_proc 报告为本操作的失败
+    const RATIONAL2: &[&str] = &["rational", "rational"];
     arith.put("add", list!["rational", "rational"], {
         let (arith, extract_xy_) = (arith.clone(), extract_xy_numer_denom.clone());
-        ClosureWrapper::new(move |args| {
-            let (n_x, d_x, n_y, d_y) = extract_xy_(args);
-            let n = arith.add(&arith.mul(&n_x, &d_y), &arith.mul(&n_y, &d_x));
-            let d = arith.mul(&d_x, &d_y);
-            Some(make_rational(n, d, &arith))
+        fallible_proc("add", RATIONAL2, move |args| {
+            let (n_x, d_x, n_y, d_y) = extract_xy_(args)?;
+            let n = arith.try_add(&arith.try_mul(&n_x, &d_y)?, &arith.try_mul(&n_y, &d_x)?)?;
+            let d = arith.try_mul(&d_x, &d_y)?;
+            Ok(make_rational(n, d, &arith))
         })
     });
     arith.put("sub", list!["rational", "rational"], {
         let (arith, extract_xy_) = (arith.clone(), extract_xy_numer_denom.clone());
-        ClosureWrapper::new(move |args| {
-            let (n_x, d_x, n_y, d_y) = extract_xy_(args);
-            let n = arith.sub(&arith.mul(&n_x, &d_y), &arith.mul(&n_y, &d_x));
-            let d = arith.mul(&d_x, &d_y);
-            Some(make_rational(n, d, &arith))
+        fallible_proc("sub", RATIONAL2, move |args| {
+            let (n_x, d_x, n_y, d_y) = extract_xy_(args)?;
+            let n = arith.try_sub(&arith.try_mul(&n_x, &d_y)?, &arith.try_mul(&n_y, &d_x)?)?;
+            let d = arith.try_mul(&d_x, &d_y)?;
+            Ok(make_rational(n, d, &arith))
         })
     });
     arith.put("mul", list!["rational", "rational"], {
         let (arith, extract_xy_) = (arith.clone(), extract_xy_numer_denom.clone());
-        ClosureWrapper::new(move |args| {
-            let (n_x, d_x, n_y, d_y) = extract_xy_(args);
-            let n = arith.mul(&n_x, &n_y);
-            let d = arith.mul(&d_x, &d_y);
-            Some(make_rational(n, d, &arith))
+        fallible_proc("mul", RATIONAL2, move |args| {
+            let (n_x, d_x, n_y, d_y) = extract_xy_(args)?;
+            let n = arith.try_mul(&n_x, &n_y)?;
+            let d = arith.try_mul(&d_x, &d_y)?;
+            Ok(make_rational(n, d, &arith))
         })
     });
     arith.put("div", list!["rational", "rational"], {
         let (arith, extract_xy_) = (arith.clone(), extract_xy_numer_denom.clone());
-        ClosureWrapper::new(move |args| {
-            let (n_x, d_x, n_y, d_y) = extract_xy_(args);
-            let n = arith.mul(&n_x, &d_y);
-            let d = arith.mul(&d_x, &n_y);
-            Some(make_rational(n, d, &arith))
+        fallible_proc("div", RATIONAL2, move |args| {
+            let (n_x, d_x, n_y, d_y) = extract_xy_(args)?;
+            if arith.try_is_equal_to_zero(&n_y)? == true.to_listv() {
+                return Ok(DispatchError::operation_failed(
+                    "div",
+                    RATIONAL2,
+                    "divide by zero",
+                ));
+            }
+            let n = arith.try_mul(&n_x, &d_y)?;
+            let d = arith.try_mul(&d_x, &n_y)?;
+            Ok(make_rational(n, d, &arith))
         })
     });
     arith.put("is_equal", list!["rational", "rational"], {
         let (arith, extract_xy_) = (arith.clone(), extract_xy_numer_denom.clone());
-        ClosureWrapper::new(move |args| {
-            let (n_x, d_x, n_y, d_y) = extract_xy_(args);
-            Some(
-                (arith.is_equal(&n_x, &n_y) == true.to_listv()
-                    && arith.is_equal(&d_x, &d_y) == true.to_listv())
-                .to_listv(),
-            )
+        fallible_proc("is_equal", RATIONAL2, move |args| {
+            let (n_x, d_x, n_y, d_y) = extract_xy_(args)?;
+            Ok((arith.try_is_equal(&n_x, &n_y)? == true.to_listv()
+                && arith.try_is_equal(&d_x, &d_y)? == true.to_listv())
+            .to_listv())
         })
     });
     arith.put("is_equal_to_zero", list!["rational"], {
-        let (arith, tag) = (arith.clone(), tag.clone());
-        ClosureWrapper::new(move |args| {
+        let (arith, tag) = (arith.clone(), tag);
+        fallible_proc("is_equal_to_zero", &["rational"], move |args| {
             // 调用链中有apply_generic的调用，需要使用 tag 函数重新附加数据类型标签
-            let n = arith.numer(&tag(args.head()));
-            Some((arith.is_equal_to_zero(&n) == true.to_listv()).to_listv())
+            let n = arith.try_numer(&tag(args.head()))?;
+            Ok((arith.try_is_equal_to_zero(&n)? == true.to_listv()).to_listv())
         })
     });
     arith.put("negative", list!["rational"], {
-        let (arith, tag) = (arith.clone(), tag.clone());
-        ClosureWrapper::new(move |args| {
+        let (arith, tag) = (arith.clone(), tag);
+        fallible_proc("negative", &["rational"], move |args| {
             // 调用链中有apply_generic的调用，需要使用 tag 函数重新附加数据类型标签
-            let n = arith.numer(&tag(args.head()));
-            let d = arith.denom(&tag(args.head()));
-            Some(make_rational(arith.negative(&n), d, &arith))
+            let n = arith.try_numer(&tag(args.head()))?;
+            let d = arith.try_denom(&tag(args.head()))?;
+            Ok(make_rational(arith.try_negative(&n)?, d, &arith))
         })
     });
-    arith.put("raise", list!["rational"], {
+    let raise = {
         let arith = arith.clone();
         ClosureWrapper::new(move |args| {
-            // 调用链中有apply_generic的调用，需要使用 tag 函数重新附加数据类型标签
-            let n = arith.numer(&tag(args.head()));
-            let d = arith.denom(&tag(args.head()));
-            // try drop to integer
-            let (n, d) = (arith.drop(&n), arith.drop(&d));
-            if type_tag(&n) == "integer".to_listv() && type_tag(&d) == "integer".to_listv() {
-                let n = n
-                    .try_as_basis_value::<i32>()
-                    .expect("raise rational with integer error");
-                let d = d
-                    .try_as_basis_value::<i32>()
-                    .expect("raise rational with integer error");
-                let f = (*n as f64) / (*d as f64);
-                Some(make_float(f, &arith))
-            } else {
-                panic!(
-                    "raise: rational to float error, not support {} to raise",
-                    args
-                );
-            }
+            // 分子分母不是整数（如多项式）时无法提升为 float
+            let (n, d) = integer_parts(&tag(args.head()), &arith)?;
+            Some(make_float((n as f64) / (d as f64), &arith))
         })
-    });
+    };
     // project rational to integer
-    arith.put("project", list!["rational"], {
+    let project = {
         let arith = arith.clone();
         ClosureWrapper::new(move |args| {
-            // 调用链中有apply_generic的调用，需要使用 tag 函数重新附加数据类型标签
-            let n = arith.numer(&tag(args.head()));
-            let d = arith.denom(&tag(args.head()));
-            // try drop to integer
-            let (n, d) = (arith.drop(&n), arith.drop(&d));
-            if type_tag(&n) == "integer".to_listv() && type_tag(&d) == "integer".to_listv() {
-                let numer = *n.try_as_basis_value::<i32>().unwrap() as f64;
-                let denom = *d.try_as_basis_value::<i32>().unwrap() as f64;
-                let i = (numer / denom).floor() as i32;
-                Some(make_integer(i, &arith))
-            } else {
-                eprintln!(
-                    "project: rational to integer error: numer&denom only support integer found {}",
-                    args
+            let (n, d) = integer_parts(&tag(args.head()), &arith)?;
+            let i = ((n as f64) / (d as f64)).floor() as i32;
+            Some(make_integer(i, &arith))
+        })
+    };
+    arith.register_type("rational", 2, &[("float", raise)], Some(project));
+    // sqrt rational
+    arith.put("sqrt", list!["rational"], {
+        let arith = arith.clone();
+        ClosureWrapper::new(move |args| {
+            let Some((n, d)) = integer_parts(&tag(args.head()), &arith) else {
+                return Some(DispatchError::operation_failed(
+                    "sqrt",
+                    &["rational"],
+                    "numerator and denominator must be integers",
+                ));
+            };
+            let f = make_float(((n as f64) / (d as f64)).sqrt(), &arith);
+            // 返回值可能不是rational
+            Some(arith.drop_to_type(&f, "rational".to_string()))
+        })
+    });
+    Some("done".to_string().to_listv())
+}
+// integer 或 bigint 的值转换为 BigInt
+fn to_bigint(x: &List) -> Option<BigInt> {
+    if let Ok(x) = x.try_as_basis_value::<i32>() {
+        Some(BigInt::from(*x))
+    } else if type_tag(x) == "bigint".to_listv() {
+        contents(x).try_as_basis_value::<BigInt>().ok().cloned()
+    } else {
+        None
+    }
+}
+pub fn install_bigint_package(arith: &ArithmeticContext) -> Option<List> {
+    let tag = |x: BigInt| attach_tag("bigint", &x.to_listv());
+    install_basic_numeric_type::<BigInt>("bigint", tag, arith);
+    let raise = {
+        let arith = arith.clone();
+        ClosureWrapper::new(move |args| {
+            let x = args.head().try_as_basis_value::<BigInt>().ok()?.clone();
+            Some(make_bigrational(BigRational::from_integer(x), &arith))
+        })
+    };
+    // project bigint to integer, 超出 i32 时无法投影
+    let project = {
+        let arith = arith.clone();
+        ClosureWrapper::new(move |args| {
+            let x = args.head().try_as_basis_value::<BigInt>().ok()?.clone();
+            i32::try_from(&x).ok().map(|x| make_integer(x, &arith))
+        })
+    };
+    arith.register_type("bigint", 1, &[("bigrational", raise)], Some(project));
+    arith.register_supertype("integer", "bigint", {
+        let arith = arith.clone();
+        ClosureWrapper::new(move |args| Some(make_bigint(to_bigint(&args.head())?, &arith)))
+    });
+    // sqrt bigint, 完全平方数时结果精确
+    arith.put("sqrt", list!["bigint"], {
+        let arith = arith.clone();
+        ClosureWrapper::new(move |args| {
+            let x = args
+                .head()
+                .try_as_basis_value::<BigInt>()
+                .expect("sqrt bigint: bigint must be BigInt")
+                .clone();
+            if !x.is_negative() {
+                let root = x.sqrt();
+                if &root * &root == x {
+                    return Some(make_integer(root, &arith));
+                }
+            }
+            Some(make_float(x.to_f64()?.sqrt(), &arith))
+        })
+    });
+    // gcd bigint
+    install_binary_op::<BigInt>("gcd", "bigint", move |a, b| tag(a.gcd(&b)), arith);
+    // reduce bigint
+    install_binary_op::<BigInt>(
+        "reduce",
+        "bigint",
+        move |a, b| {
+            let g = a.gcd(&b);
+            if g.is_zero() {
+                return DispatchError::operation_failed(
+                    "reduce",
+                    &["bigint", "bigint"],
+                    "divide by zero",
                 );
-                None
             }
+            list![tag(a / &g), tag(b / &g)]
+        },
+        arith,
+    );
+    // pow bigint
+    install_binary_op::<BigInt>(
+        "pow",
+        "bigint",
+        move |a, b| match b.to_u32() {
+            // 底数为 0 或 ±1 时结果不会增大
+            Some(e) if e <= MAX_POW_EXPONENT || a.bits() <= 1 => tag(a.pow(e)),
+            _ if b.is_negative() => DispatchError::operation_failed(
+                "pow",
+                &["bigint", "bigint"],
+                "exponent must be a non-negative u32",
+            ),
+            _ => {
+                DispatchError::operation_failed("pow", &["bigint", "bigint"], "exponent too large")
+            }
+        },
+        arith,
+    );
+    Some("done".to_string().to_listv())
+}
+pub fn install_bigrational_package(arith: &ArithmeticContext) -> Option<List> {
+    let tag = |x: BigRational| attach_tag("bigrational", &x.to_listv());
+    install_basic_numeric_type::<BigRational>("bigrational", tag, arith);
+    install_unary_op::<BigRational>(
+        "numer",
+        "bigrational",
+        {
+            let arith = arith.clone();
+            move |x| make_integer(x.numer().clone(), &arith)
+        },
+        arith,
+    );
+    install_unary_op::<BigRational>(
+        "denom",
+        "bigrational",
+        {
+            let arith = arith.clone();
+            move |x| make_integer(x.denom().clone(), &arith)
+        },
+        arith,
+    );
+    let raise = {
+        let arith = arith.clone();
+        ClosureWrapper::new(move |args| {
+            let x = args
+                .head()
+                .try_as_basis_value::<BigRational>()
+                .ok()?
+                .clone();
+            Some(make_float(x.to_f64()?, &arith))
+        })
+    };
+    // project bigrational to integer/bigint (整数值) 或 rational (分子分母可放入 i32)
+    let project = {
+        let arith = arith.clone();
+        ClosureWrapper::new(move |args| {
+            let x = args
+                .head()
+                .try_as_basis_value::<BigRational>()
+                .ok()?
+                .clone();
+            if x.is_integer() {
+                return Some(make_integer(x.to_integer(), &arith));
+            }
+            let n = i32::try_from(x.numer()).ok()?;
+            let d = i32::try_from(x.denom()).ok()?;
+            Some(make_rational(n.to_listv(), d.to_listv(), &arith))
+        })
+    };
+    arith.register_type("bigrational", 3, &[("float", raise)], Some(project));
+    arith.register_supertype("bigint", "bigrational", {
+        let arith = arith.clone();
+        ClosureWrapper::new(move |args| {
+            let x = args.head().try_as_basis_value::<BigInt>().ok()?.clone();
+            Some(make_bigrational(BigRational::from_integer(x), &arith))
         })
     });
-    // sqrt rational
-    arith.put("sqrt", list!["rational"], {
+    arith.register_supertype("rational", "bigrational", {
         let arith = arith.clone();
         ClosureWrapper::new(move |args| {
-            // 调用链中有apply_generic的调用，需要使用 tag 函数重新附加数据类型标签
-            let n = arith.numer(&tag(args.head()));
-            let d = arith.denom(&tag(args.head()));
-            // try drop to integer
-            let (n, d) = (arith.drop(&n), arith.drop(&d));
-            if type_tag(&n) == "integer".to_listv() && type_tag(&d) == "integer".to_listv() {
-                let n = n
-                    .try_as_basis_value::<i32>()
-                    .expect("sqrt rational with integer error");
-                let d = d
-                    .try_as_basis_value::<i32>()
-                    .expect("sqrt rational with integer error");
-                let f = make_float(((*n as f64) / (*d as f64)).sqrt(), &arith);
-                // 返回值可能不是rational
-                Some(arith.drop_to_type(&f, "rational".to_string()))
-            } else {
-                panic!("sqrt rational error, not support {} to sqrt", args);
+            let (n, d) = (
+                to_bigint(&args.head().head())?,
+                to_bigint(&args.head().tail())?,
+            );
+            Some(make_bigrational(BigRational::new(n, d), &arith))
+        })
+    });
+    // sqrt bigrational, 分子分母均为完全平方数时结果精确
+    arith.put("sqrt", list!["bigrational"], {
+        let arith = arith.clone();
+        ClosureWrapper::new(move |args| {
+            let x = args
+                .head()
+                .try_as_basis_value::<BigRational>()
+                .expect("sqrt bigrational: bigrational must be BigRational")
+                .clone();
+            if !x.is_negative() {
+                let (n, d) = (x.numer().sqrt(), x.denom().sqrt());
+                if &n * &n == *x.numer() && &d * &d == *x.denom() {
+                    return Some(arith.drop(&make_bigrational(BigRational::new(n, d), &arith)));
+                }
             }
+            Some(make_float(x.to_f64()?.sqrt(), &arith))
         })
     });
+    // pow bigrational, 指数须为整数
+    install_binary_op::<BigRational>(
+        "pow",
+        "bigrational",
+        move |a, b| {
+            let exp = if b.is_integer() {
+                b.to_integer().to_i32()
+            } else {
+                None
+            };
+            // 分子分母均为 0 或 ±1 时结果不会增大
+            let small_base = a.numer().bits() <= 1 && a.denom().bits() <= 1;
+            match exp {
+                Some(exp) if exp.unsigned_abs() > MAX_POW_EXPONENT && !small_base => {
+                    DispatchError::operation_failed(
+                        "pow",
+                        &["bigrational", "bigrational"],
+                        "exponent too large",
+                    )
+                }
+                Some(exp) if !(a.is_zero() && exp < 0) => tag(a.pow(exp)),
+                _ => DispatchError::operation_failed(
+                    "pow",
+                    &["bigrational", "bigrational"],
+                    "exponent must be an i32 integer and the base non-zero when it is negative",
+                ),
+            }
+        },
+        arith,
+    );
     Some("done".to_string().to_listv())
 }
 // 将浮点数转换为分数（分子和分母）
@@ -925,7 +1728,7 @@ pub fn float_to_fraction(x: f64, max_denominator: i32) -> (i32, i32) {
         }
 
         // 更新小数部分
-        x = x - a as f64; // 计算小数部分
+        x -= a as f64; // 计算小数部分
         if x.abs().to_listv() == 0.0.to_listv() {
             // 如果小数部分接近 0，停止迭代
             break;
@@ -937,7 +1740,7 @@ pub fn float_to_fraction(x: f64, max_denominator: i32) -> (i32, i32) {
 
     // 如果是负数，调整符号
     if negative {
-        (numer1 * -1, denom1)
+        (-numer1, denom1)
     } else {
         (numer1, denom1)
     }
@@ -945,14 +1748,12 @@ pub fn float_to_fraction(x: f64, max_denominator: i32) -> (i32, i32) {
 pub fn install_rectangular_package(arith: &ArithmeticContext) -> Option<List> {
     let tag = |x: &List| attach_tag("rectangular", x);
     arith.put("make_from_real_imag", list!["rectangular"], {
-        let tag = tag.clone();
         ClosureWrapper::new(move |args| {
             let (x, y) = (args.head(), args.tail().head());
             Some(tag(&pair!(x, y)))
         })
     });
     arith.put("make_from_mag_ang", list!["rectangular"], {
-        let tag = tag.clone();
         ClosureWrapper::new(move |args| {
             let (r, a) = (args.head(), args.tail().head());
             if r.is_float_value() && a.is_float_value() {
@@ -983,15 +1784,14 @@ pub fn install_rectangular_package(arith: &ArithmeticContext) -> Option<List> {
             // apply_generic 在处理参数时会移除类型标签，
             // 这里通过 tag 函数重新为参数附加类型标签，以便后续操作能够识别数据类型。
             let args = tag(arg);
-            let (real_x, imag_x) = (arith.real_part(&args), arith.imag_part(&args));
-            (real_x, imag_x)
+            Ok((arith.try_real_part(&args)?, arith.try_imag_part(&args)?))
         }
     };
     arith.put("magnitude", list!["rectangular"], {
         let extract = extract_real_imag.clone();
         let arith = arith.clone();
-        ClosureWrapper::new(move |args| {
-            let (real, imag) = extract(&args.head());
+        fallible_proc("magnitude", &["rectangular"], move |args| {
+            let (real, imag) = extract(&args.head())?;
             if is_basis_arithmetic_type(&real)
                 && type_tag(&real) != "complex".to_listv()
                 && is_basis_arithmetic_type(&real)
@@ -999,19 +1799,23 @@ pub fn install_rectangular_package(arith: &ArithmeticContext) -> Option<List> {
             {
                 // sqrt only for (integer, rational, float)
                 // (real*real + imag*imag).sqrt()
-                let r2 = arith.mul(&real, &real);
-                let i2 = arith.mul(&imag, &imag);
-                let x = arith.add(&r2, &i2);
-                Some(arith.drop(&arith.sqrt(&x)))
+                let r2 = arith.try_mul(&real, &real)?;
+                let i2 = arith.try_mul(&imag, &imag)?;
+                let x = arith.try_add(&r2, &i2)?;
+                Ok(arith.drop(&arith.sqrt(&x)))
             } else {
-                panic!("complex magnitude not support for {}", args);
+                Ok(DispatchError::operation_failed(
+                    "magnitude",
+                    &["rectangular"],
+                    "complex magnitude only support basis arithmetic parts",
+                ))
             }
         })
     });
     arith.put("angle", list!["rectangular"], {
         let extract = extract_real_imag.clone();
-        ClosureWrapper::new(move |args| {
-            let (real, imag) = extract(&args.head());
+        fallible_proc("angle", &["rectangular"], move |args| {
+            let (real, imag) = extract(&args.head())?;
             if real.is_float_value() && imag.is_float_value() {
                 let r = real
                     .try_as_basis_value::<f64>()
@@ -1019,35 +1823,35 @@ pub fn install_rectangular_package(arith: &ArithmeticContext) -> Option<List> {
                 let i = imag
                     .try_as_basis_value::<f64>()
                     .expect("complex: float type only support f64");
-                Some((i.atan2(*r)).to_listv())
+                Ok((i.atan2(*r)).to_listv())
             } else {
-                todo!("complex angle Now only support f64")
+                Ok(DispatchError::operation_failed(
+                    "angle",
+                    &["rectangular"],
+                    "complex angle Now only support f64",
+                ))
             }
         })
     });
     arith.put("is_equal", list!["rectangular", "rectangular"], {
         let arith = arith.clone();
         let extract = extract_real_imag.clone();
-        ClosureWrapper::new(move |args| {
+        fallible_proc("is_equal", &["rectangular", "rectangular"], move |args| {
             let (x, y) = (args.head(), args.tail().head());
-            let ((r_x, i_x), (r_y, i_y)) = (extract(&x), extract(&y));
-            Some(
-                (arith.is_equal(&r_x, &r_y) == true.to_listv()
-                    && arith.is_equal(&i_x, &i_y) == true.to_listv())
-                .to_listv(),
-            )
+            let ((r_x, i_x), (r_y, i_y)) = (extract(&x)?, extract(&y)?);
+            Ok((arith.try_is_equal(&r_x, &r_y)? == true.to_listv()
+                && arith.try_is_equal(&i_x, &i_y)? == true.to_listv())
+            .to_listv())
         })
     });
     arith.put("is_equal_to_zero", list!["rectangular"], {
         let arith = arith.clone();
         let extract = extract_real_imag.clone();
-        ClosureWrapper::new(move |args| {
-            let (r, i) = extract(&args.head());
-            Some(
-                (arith.is_equal_to_zero(&r) == true.to_listv()
-                    && arith.is_equal_to_zero(&i) == true.to_listv())
-                .to_listv(),
-            )
+        fallible_proc("is_equal_to_zero", &["rectangular"], move |args| {
+            let (r, i) = extract(&args.head())?;
+            Ok((arith.try_is_equal_to_zero(&r)? == true.to_listv()
+                && arith.try_is_equal_to_zero(&i)? == true.to_listv())
+            .to_listv())
         })
     });
     Some("done".to_string().to_listv())
@@ -1056,25 +1860,21 @@ pub fn install_rectangular_package(arith: &ArithmeticContext) -> Option<List> {
 pub fn install_polar_package(arith: &ArithmeticContext) -> Option<List> {
     let tag = |x: &List| attach_tag("polar", x);
     arith.put("make_from_mag_ang", list!["polar"], {
-        let tag = tag.clone();
         ClosureWrapper::new(move |args| {
             let (x, y) = (args.head(), args.tail().head());
             Some(tag(&pair!(x, y)))
         })
     });
     arith.put("make_from_real_imag", list!["polar"], {
-        let tag = tag.clone();
         ClosureWrapper::new(move |args| {
             let (r, i) = (args.head(), args.tail().head());
             if r.is_float_value() && i.is_float_value() {
-                let r = r
+                let r = *r
                     .try_as_basis_value::<f64>()
-                    .expect("complex: float type only support f64")
-                    .clone();
-                let i = i
+                    .expect("complex: float type only support f64");
+                let i = *i
                     .try_as_basis_value::<f64>()
-                    .expect("complex: float type only support f64")
-                    .clone();
+                    .expect("complex: float type only support f64");
                 Some(tag(&pair![(r * r + i * i).sqrt(), i.atan2(r)]))
             } else {
                 todo!("complex make_from_real_imag polar Now only support f64")
@@ -1095,67 +1895,68 @@ pub fn install_polar_package(arith: &ArithmeticContext) -> Option<List> {
             // apply_generic 在处理参数时会移除类型标签，
             // 这里通过 tag 函数重新为参数附加类型标签，以便后续操作能够识别数据类型。
             let args = tag(arg);
-            let (mag, ang) = (arith.magnitude(&args), arith.angle(&args));
-            (mag, ang)
+            Ok((arith.try_magnitude(&args)?, arith.try_angle(&args)?))
         }
     };
     arith.put("real_part", list!["polar"], {
         let extract = extract_mag_ang.clone();
-        ClosureWrapper::new(move |args| {
-            let (mag, ang) = extract(&args.head());
+        fallible_proc("real_part", &["polar"], move |args| {
+            let (mag, ang) = extract(&args.head())?;
             if mag.is_float_value() && ang.is_float_value() {
-                let m = mag
+                let m = *mag
                     .try_as_basis_value::<f64>()
-                    .expect("complex: float type only support f64")
-                    .clone();
-                let a = ang
+                    .expect("complex: float type only support f64");
+                let a = *ang
                     .try_as_basis_value::<f64>()
-                    .expect("complex: float type only support f64")
-                    .clone();
-                Some((m * a.cos()).to_listv())
+                    .expect("complex: float type only support f64");
+                Ok((m * a.cos()).to_listv())
             } else {
-                todo!("complex real_part Now only support f64")
+                Ok(DispatchError::operation_failed(
+                    "real_part",
+                    &["polar"],
+                    "complex real_part Now only support f64",
+                ))
             }
         })
     });
     arith.put("imag_part", list!["polar"], {
         let extract = extract_mag_ang.clone();
-        ClosureWrapper::new(move |args| {
-            let (mag, ang) = extract(&args.head());
+        fallible_proc("imag_part", &["polar"], move |args| {
+            let (mag, ang) = extract(&args.head())?;
             if mag.is_float_value() && ang.is_float_value() {
-                let m = mag
+                let m = *mag
                     .try_as_basis_value::<f64>()
-                    .expect("complex: float type only support f64")
-                    .clone();
-                let a = ang
+                    .expect("complex: float type only support f64");
+                let a = *ang
                     .try_as_basis_value::<f64>()
-                    .expect("complex: float type only support f64")
-                    .clone();
-                Some((m * a.sin()).to_listv())
+                    .expect("complex: float type only support f64");
+                Ok((m * a.sin()).to_listv())
             } else {
-                todo!("complex imag_part Now only support f64")
+                Ok(DispatchError::operation_failed(
+                    "imag_part",
+                    &["polar"],
+                    "complex imag_part Now only support f64",
+                ))
             }
         })
     });
     arith.put("is_equal", list!["polar", "polar"], {
         let arith = arith.clone();
         let extract = extract_mag_ang.clone();
-        ClosureWrapper::new(move |args| {
+        fallible_proc("is_equal", &["polar", "polar"], move |args| {
             let (x, y) = (args.head(), args.tail().head());
-            let ((m_x, a_x), (m_y, a_y)) = (extract(&x), extract(&y));
-            Some(
-                (arith.is_equal(&m_x, &m_y) == true.to_listv()
-                    && arith.is_equal(&a_x, &a_y) == true.to_listv())
-                .to_listv(),
-            )
+            let ((m_x, a_x), (m_y, a_y)) = (extract(&x)?, extract(&y)?);
+            Ok((arith.try_is_equal(&m_x, &m_y)? == true.to_listv()
+                && arith.try_is_equal(&a_x, &a_y)? == true.to_listv())
+            .to_listv())
         })
     });
     arith.put("is_equal_to_zero", list!["polar"], {
         let arith = arith.clone();
         let extract = extract_mag_ang.clone();
-        ClosureWrapper::new(move |args| {
-            let (m, _) = extract(&args.head());
-            Some((arith.is_equal_to_zero(&m) == true.to_listv()).to_listv())
+        fallible_proc("is_equal_to_zero", &["polar"], move |args| {
+            let (m, _) = extract(&args.head())?;
+            Ok((arith.try_is_equal_to_zero(&m)? == true.to_listv()).to_listv())
         })
     });
     Some("done".to_string().to_listv())
@@ -1164,7 +1965,6 @@ pub fn install_polar_package(arith: &ArithmeticContext) -> Option<List> {
 pub fn install_complex_package(arith: &ArithmeticContext) -> Option<List> {
     let tag = |x: &List| attach_tag("complex", x);
     arith.put("make_from_real_imag", list!["complex"], {
-        let tag = tag.clone();
         let arith = arith.clone();
         ClosureWrapper::new(move |args| {
             let (x, y) = (args.head(), args.tail().head());
@@ -1180,7 +1980,6 @@ pub fn install_complex_package(arith: &ArithmeticContext) -> Option<List> {
         })
     });
     arith.put("make_from_mag_ang", list!["complex"], {
-        let tag = tag.clone();
         let arith = arith.clone();
         ClosureWrapper::new(move |args| {
             let (x, y) = (args.head(), args.tail().head());
@@ -1195,91 +1994,118 @@ pub fn install_complex_package(arith: &ArithmeticContext) -> Option<List> {
             }
         })
     });
+    // 嵌套的通用操作使用 try_* 版本，失败时由 fallible_proc 报告为本操作的失败
+    const COMPLEX2: &[&str] = &["complex", "complex"];
     arith.put("add", list!["complex", "complex"], {
         let arith = arith.clone();
-        ClosureWrapper::new(move |args| {
-            let (r1, i1) = (arith.real_part(&args.head()), arith.imag_part(&args.head()));
+        fallible_proc("add", COMPLEX2, move |args| {
+            let (r1, i1) = (
+                arith.try_real_part(&args.head())?,
+                arith.try_imag_part(&args.head())?,
+            );
             let (r2, i2) = (
-                arith.real_part(&args.tail().head()),
-                arith.imag_part(&args.tail().head()),
+                arith.try_real_part(&args.tail().head())?,
+                arith.try_imag_part(&args.tail().head())?,
             );
-            let (r, i) = (arith.add(&r1, &r2), arith.add(&i1, &i2));
-            Some(make_complex_from_real_imag(r, i, &arith))
+            let (r, i) = (arith.try_add(&r1, &r2)?, arith.try_add(&i1, &i2)?);
+            Ok(make_complex_from_real_imag(r, i, &arith))
         })
     });
     arith.put("sub", list!["complex", "complex"], {
         let arith = arith.clone();
-        ClosureWrapper::new(move |args| {
-            let (r1, i1) = (arith.real_part(&args.head()), arith.imag_part(&args.head()));
+        fallible_proc("sub", COMPLEX2, move |args| {
+            let (r1, i1) = (
+                arith.try_real_part(&args.head())?,
+                arith.try_imag_part(&args.head())?,
+            );
             let (r2, i2) = (
-                arith.real_part(&args.tail().head()),
-                arith.imag_part(&args.tail().head()),
+                arith.try_real_part(&args.tail().head())?,
+                arith.try_imag_part(&args.tail().head())?,
             );
-            let (r, i) = (arith.sub(&r1, &r2), arith.sub(&i1, &i2));
-            Some(make_complex_from_real_imag(r, i, &arith))
+            let (r, i) = (arith.try_sub(&r1, &r2)?, arith.try_sub(&i1, &i2)?);
+            Ok(make_complex_from_real_imag(r, i, &arith))
         })
     });
     arith.put("mul", list!["complex", "complex"], {
         let arith = arith.clone();
-        ClosureWrapper::new(move |args| {
-            let (m1, a1) = (arith.magnitude(&args.head()), arith.angle(&args.head()));
+        fallible_proc("mul", COMPLEX2, move |args| {
+            let (m1, a1) = (
+                arith.try_magnitude(&args.head())?,
+                arith.try_angle(&args.head())?,
+            );
             let (m2, a2) = (
-                arith.magnitude(&args.tail().head()),
-                arith.angle(&args.tail().head()),
+                arith.try_magnitude(&args.tail().head())?,
+                arith.try_angle(&args.tail().head())?,
             );
-            let (m, a) = (arith.mul(&m1, &m2), arith.add(&a1, &a2));
-            Some(make_complex_from_real_imag(m, a, &arith))
+            let (m, a) = (arith.try_mul(&m1, &m2)?, arith.try_add(&a1, &a2)?);
+            Ok(make_complex_from_real_imag(m, a, &arith))
         })
     });
     arith.put("div", list!["complex", "complex"], {
         let arith = arith.clone();
-        ClosureWrapper::new(move |args| {
-            let (m1, a1) = (arith.magnitude(&args.head()), arith.angle(&args.head()));
+        fallible_proc("div", COMPLEX2, move |args| {
+            let (m1, a1) = (
+                arith.try_magnitude(&args.head())?,
+                arith.try_angle(&args.head())?,
+            );
             let (m2, a2) = (
-                arith.magnitude(&args.tail().head()),
-                arith.angle(&args.tail().head()),
+                arith.try_magnitude(&args.tail().head())?,
+                arith.try_angle(&args.tail().head())?,
             );
-            let (m, a) = (arith.div(&m1, &m2), arith.sub(&a1, &a2));
-            Some(make_complex_from_real_imag(m, a, &arith))
+            let (m, a) = (arith.try_div(&m1, &m2)?, arith.try_sub(&a1, &a2)?);
+            Ok(make_complex_from_real_imag(m, a, &arith))
         })
     });
     arith.put("negative", list!["complex"], {
         let arith = arith.clone();
-        ClosureWrapper::new(move |args| {
-            let (r, i) = (arith.real_part(&args.head()), arith.imag_part(&args.head()));
-            Some(make_complex_from_real_imag(
-                arith.negative(&r),
-                arith.negative(&i),
+        fallible_proc("negative", &["complex"], move |args| {
+            let (r, i) = (
+                arith.try_real_part(&args.head())?,
+                arith.try_imag_part(&args.head())?,
+            );
+            Ok(make_complex_from_real_imag(
+                arith.try_negative(&r)?,
+                arith.try_negative(&i)?,
                 &arith,
             ))
         })
     });
     arith.put("is_equal", list!["complex", "complex"], {
         let arith = arith.clone();
-        ClosureWrapper::new(move |args| Some(arith.is_equal(&args.head(), &args.tail().head())))
+        fallible_proc("is_equal", COMPLEX2, move |args| {
+            arith.try_is_equal(&args.head(), &args.tail().head())
+        })
     });
     arith.put("is_equal_to_zero", list!["complex"], {
         let arith = arith.clone();
-        ClosureWrapper::new(move |args| Some(arith.is_equal_to_zero(&args.head())))
+        fallible_proc("is_equal_to_zero", &["complex"], move |args| {
+            arith.try_is_equal_to_zero(&args.head())
+        })
     });
     arith.put("real_part", list!["complex"], {
         let arith = arith.clone();
-        ClosureWrapper::new(move |args| Some(arith.real_part(&args)))
+        fallible_proc("real_part", &["complex"], move |args| {
+            arith.try_real_part(args)
+        })
     });
     arith.put("imag_part", list!["complex"], {
         let arith = arith.clone();
-        ClosureWrapper::new(move |args| Some(arith.imag_part(&args)))
+        fallible_proc("imag_part", &["complex"], move |args| {
+            arith.try_imag_part(args)
+        })
     });
     arith.put("magnitude", list!["complex"], {
         let arith = arith.clone();
-        ClosureWrapper::new(move |args| Some(arith.magnitude(&args)))
+        fallible_proc("magnitude", &["complex"], move |args| {
+            arith.try_magnitude(args)
+        })
     });
     arith.put("angle", list!["complex"], {
         let arith = arith.clone();
-        ClosureWrapper::new(move |args| Some(arith.angle(&args)))
+        fallible_proc("angle", &["complex"], move |args| arith.try_angle(args))
     });
     // project complex to real
-    arith.put("project", list!["complex"], {
+    let project = {
         let arith = arith.clone();
         ClosureWrapper::new(move |args| {
             let real = arith.real_part(args);
@@ -1289,6 +2115,8 @@ pub fn install_complex_package(arith: &ArithmeticContext) -> Option<List> {
                 *real.try_as_basis_value::<f64>().unwrap()
             } else if type_tag(&real) == "rational".to_listv() {
                 *(arith.raise(&real).try_as_basis_value::<f64>().unwrap())
+            } else if let Some(real) = arith.raise_to(&real, &"float".to_listv()) {
+                *real.try_as_basis_value::<f64>().unwrap()
             } else {
                 eprintln!("project complex to real only support basis arithmetic type");
                 return None;
@@ -1296,16 +2124,122 @@ pub fn install_complex_package(arith: &ArithmeticContext) -> Option<List> {
 
             Some(make_float(real, &arith))
         })
+    };
+    arith.register_type("complex", 5, &[], Some(project));
+    Some("done".to_string().to_listv())
+}
+// interval arithmetic (2.1.4)
+// 区间 [lower, upper] 表示为 ("interval", (lower . upper))，上下界均为 f64
+fn interval(lower: f64, upper: f64) -> List {
+    attach_tag("interval", &pair!(lower, upper))
+}
+fn interval_bounds(x: &List) -> (f64, f64) {
+    let bound = |b: List| {
+        *b.try_as_basis_value::<f64>()
+            .expect("interval: bounds must be f64")
+    };
+    (bound(x.head()), bound(x.tail()))
+}
+fn mul_interval((l1, u1): (f64, f64), (l2, u2): (f64, f64)) -> List {
+    let (p1, p2, p3, p4) = (l1 * l2, l1 * u2, u1 * l2, u1 * u2);
+    interval(p1.min(p2).min(p3).min(p4), p1.max(p2).max(p3).max(p4))
+}
+pub fn install_interval_package(arith: &ArithmeticContext) -> Option<List> {
+    arith.put(
+        "make",
+        list!["interval"],
+        ClosureWrapper::new(move |args| {
+            let (a, b) = interval_bounds(&pair!(args.head(), args.tail().head()));
+            Some(interval(a.min(b), a.max(b)))
+        }),
+    );
+    // 以中心与百分比误差构造区间（练习 2.12）
+    arith.put(
+        "make_center_percent",
+        list!["interval"],
+        ClosureWrapper::new(move |args| {
+            let (c, p) = interval_bounds(&pair!(args.head(), args.tail().head()));
+            let w = (c * p / 100.0).abs();
+            Some(interval(c - w, c + w))
+        }),
+    );
+    let put_binary = |op: &'static str, f: fn((f64, f64), (f64, f64)) -> List| {
+        arith.put(
+            op,
+            list!["interval", "interval"],
+            ClosureWrapper::new(move |args| {
+                Some(f(
+                    interval_bounds(&args.head()),
+                    interval_bounds(&args.tail().head()),
+                ))
+            }),
+        );
+    };
+    put_binary("add", |(l1, u1), (l2, u2)| interval(l1 + l2, u1 + u2));
+    // 练习 2.8
+    put_binary("sub", |(l1, u1), (l2, u2)| interval(l1 - u2, u1 - l2));
+    put_binary("mul", mul_interval);
+    // 练习 2.10：除数区间跨越零时报错
+    put_binary("div", |x, (l2, u2)| {
+        if l2 <= 0.0 && u2 >= 0.0 {
+            DispatchError::operation_failed(
+                "div",
+                &["interval", "interval"],
+                "division by an interval that spans zero",
+            )
+        } else {
+            mul_interval(x, (1.0 / u2, 1.0 / l2))
+        }
+    });
+    put_binary("is_equal", |x, y| (x == y).to_listv());
+
+    let put_unary = |op: &'static str, f: fn((f64, f64)) -> List| {
+        arith.put(
+            op,
+            list!["interval"],
+            ClosureWrapper::new(move |args| Some(f(interval_bounds(&args.head())))),
+        );
+    };
+    put_unary("is_equal_to_zero", |(l, u)| {
+        (l == 0.0 && u == 0.0).to_listv()
     });
+    put_unary("negative", |(l, u)| interval(-u, -l));
+    put_unary("lower_bound", |(l, _)| l.to_listv());
+    put_unary("upper_bound", |(_, u)| u.to_listv());
+    put_unary("center", |(l, u)| ((l + u) / 2.0).to_listv());
+    put_unary("width", |(l, u)| ((u - l) / 2.0).to_listv());
+    put_unary("percent", |(l, u)| {
+        ((u - l) / 2.0 / ((l + u) / 2.0) * 100.0).to_listv()
+    });
+    Some("done".to_string().to_listv())
+}
+// 实数转换为上下界相同的区间
+pub fn install_interval_coercion(arith: &mut ArithmeticContext) -> Option<List> {
+    let mut put_helper = |type_x: &str| {
+        arith.put_coercion(&type_x.to_string().to_listv(), &"interval".to_listv(), {
+            let arith = arith.clone();
+            ClosureWrapper::new(move |args| {
+                let x = arith.raise_to(&args.head(), &"float".to_listv())?;
+                let x = *x.try_as_basis_value::<f64>().ok()?;
+                Some(make_interval(x, x, &arith))
+            })
+        })
+    };
+    put_helper("integer");
+    put_helper("bigint");
+    put_helper("rational");
+    put_helper("bigrational");
+    put_helper("float");
+
     Some("done".to_string().to_listv())
 }
 pub fn is_variable(x: &List) -> bool {
-    x.is_string_value()
+    x.is_symbol()
 }
 pub fn is_same_variable(v1: &List, v2: &List) -> bool {
     is_variable(v1)
         && is_variable(v2)
-        && (v1 == v2 || v1 == &"any".to_listv() || v2 == &"any".to_listv())
+        && (v1 == v2 || v1 == &sym!(any).to_listv() || v2 == &sym!(any).to_listv())
 }
 // representation of poly
 pub fn make_poly(variable: List, term_list: List) -> List {
@@ -1318,7 +2252,7 @@ pub fn variable(p: &List) -> List {
 /// may be integer + poly and integer with variable any
 /// integer -> (poly, (any, (0, integer))) as poly: any^0 * integer  
 pub fn variable_not_any(p1: &List, p2: &List) -> List {
-    if variable(p1) == "any".to_listv() {
+    if variable(p1) == sym!(any).to_listv() {
         variable(p2)
     } else {
         variable(p1)
@@ -1361,7 +2295,7 @@ pub fn pure_first_term(first_term: &List) -> List {
 pub fn pretty_polynomial(p: &List, arith: &ArithmeticContext) -> String {
     // (polynomial, x, sparse, (2, 4), (1, 3), (0, 7.0))
     fn iter(var_name: &List, term_list: &List, arith: &ArithmeticContext) -> String {
-        if is_empty_term_list(&term_list) {
+        if is_empty_term_list(term_list) {
             return "0".to_string();
         }
         let t1 = arith.first_term(term_list); // (sparse, (2, 4))
@@ -1384,14 +2318,14 @@ pub fn pretty_polynomial(p: &List, arith: &ArithmeticContext) -> String {
             )
         }
     }
-    let tl = term_list(&contents(&p)); // (sparse, (2, 4), (1, 3), (0, 7.0))
+    let tl = term_list(&contents(p)); // (sparse, (2, 4), (1, 3), (0, 7.0))
     if contents(&tl).is_empty() {
         format!("({}:{})", type_tag(p), contents(&tl))
     } else {
         format!(
             "({}:{})",
             type_tag(p),
-            iter(&variable(&contents(&p)), &tl, arith)
+            iter(&variable(&contents(p)), &tl, arith)
         )
     }
 }
@@ -1528,7 +2462,7 @@ pub fn install_polynomial_coercion(arith: &mut ArithmeticContext) -> Option<List
                 let x = args.head();
 
                 Some(make_polynomial_from_sparse(
-                    &"any".to_listv(),
+                    &sym!(any).to_listv(),
                     &list![make_term(0.to_listv(), x,)],
                     &arith,
                 ))
@@ -1549,15 +2483,15 @@ pub fn install_polynomial_package(arith: &ArithmeticContext) -> Option<List> {
         } else if is_empty_term_list(l2) {
             l1.clone()
         } else {
-            let t1 = arith.first_term(&l1);
+            let t1 = arith.first_term(l1);
             let (order1, coeff1) = (order(&pure_first_term(&t1)), coeff(&pure_first_term(&t1)));
-            let t2 = arith.first_term(&l2);
+            let t2 = arith.first_term(l2);
             let (order2, coeff2) = (order(&pure_first_term(&t2)), coeff(&pure_first_term(&t2)));
 
             if order1.get_basis_value() > order2.get_basis_value() {
-                arith.adjoin_term(&t1, &add_terms(&arith.rest_terms(&l1), l2, arith))
+                arith.adjoin_term(&t1, &add_terms(&arith.rest_terms(l1), l2, arith))
             } else if order1.get_basis_value() < order2.get_basis_value() {
-                arith.adjoin_term(&t2, &add_terms(l1, &arith.rest_terms(&l2), &arith))
+                arith.adjoin_term(&t2, &add_terms(l1, &arith.rest_terms(l2), arith))
             } else {
                 let first_term = make_terms_from_sparse(
                     &list![make_term(order1, arith.add(&coeff1, &coeff2))],
@@ -1566,7 +2500,7 @@ pub fn install_polynomial_package(arith: &ArithmeticContext) -> Option<List> {
 
                 arith.adjoin_term(
                     &first_term,
-                    &add_terms(&arith.rest_terms(l1), &arith.rest_terms(l2), &arith),
+                    &add_terms(&arith.rest_terms(l1), &arith.rest_terms(l2), arith),
                 )
             }
         }
@@ -1575,8 +2509,8 @@ pub fn install_polynomial_package(arith: &ArithmeticContext) -> Option<List> {
         if is_empty_term_list(l) {
             make_empty_term_list(arith) //[sparse, List::Nil]
         } else {
-            let (order1, coeff1) = (order(&pure_first_term(&t1)), coeff(&pure_first_term(&t1)));
-            let t2 = arith.first_term(&l);
+            let (order1, coeff1) = (order(&pure_first_term(t1)), coeff(&pure_first_term(t1)));
+            let t2 = arith.first_term(l);
             let (order2, coeff2) = (order(&pure_first_term(&t2)), coeff(&pure_first_term(&t2)));
             let first_term = make_terms_from_sparse(
                 &list![make_term(
@@ -1587,7 +2521,7 @@ pub fn install_polynomial_package(arith: &ArithmeticContext) -> Option<List> {
             );
             arith.adjoin_term(
                 &first_term,
-                &mul_term_by_all_terms(&t1, &arith.rest_terms(&l), &arith),
+                &mul_term_by_all_terms(t1, &arith.rest_terms(l), arith),
             )
         }
     }
@@ -1596,9 +2530,9 @@ pub fn install_polynomial_package(arith: &ArithmeticContext) -> Option<List> {
             make_empty_term_list(arith) //[sparse, List::Nil]
         } else {
             add_terms(
-                &mul_term_by_all_terms(&arith.first_term(l1), l2, &arith),
-                &mul_terms(&arith.rest_terms(l1), l2, &arith),
-                &arith,
+                &mul_term_by_all_terms(&arith.first_term(l1), l2, arith),
+                &mul_terms(&arith.rest_terms(l1), l2, arith),
+                arith,
             )
         }
     }
@@ -1606,9 +2540,9 @@ pub fn install_polynomial_package(arith: &ArithmeticContext) -> Option<List> {
         if is_empty_term_list(l1) {
             list![make_empty_term_list(arith), make_empty_term_list(arith)] //[sparse, List::Nil]
         } else {
-            let t1 = arith.first_term(&l1);
+            let t1 = arith.first_term(l1);
             let (order1, coeff1) = (order(&pure_first_term(&t1)), coeff(&pure_first_term(&t1)));
-            let t2 = arith.first_term(&l2);
+            let t2 = arith.first_term(l2);
             let (order2, coeff2) = (order(&pure_first_term(&t2)), coeff(&pure_first_term(&t2)));
             if order2.get_basis_value() > order1.get_basis_value() {
                 list![
@@ -1645,12 +2579,12 @@ pub fn install_polynomial_package(arith: &ArithmeticContext) -> Option<List> {
             let first_term =
                 make_terms_from_sparse(&list![make_term(order1, arith.negative(&coeff1))], arith);
 
-            arith.adjoin_term(&first_term, &negative_terms(&arith.rest_terms(l), &arith))
+            arith.adjoin_term(&first_term, &negative_terms(&arith.rest_terms(l), arith))
         }
     }
     fn integerizing_factor(t1: &List, t2: &List, arith: &ArithmeticContext) -> List {
-        let order1 = order(&pure_first_term(&t1));
-        let (order2, coeff2) = (order(&pure_first_term(&t2)), coeff(&pure_first_term(&t2)));
+        let order1 = order(&pure_first_term(t1));
+        let (order2, coeff2) = (order(&pure_first_term(t2)), coeff(&pure_first_term(t2)));
 
         let exp = arith.sub(&arith.add(&1.to_listv(), &order1), &order2);
         assert_eq!(
@@ -1673,10 +2607,10 @@ pub fn install_polynomial_package(arith: &ArithmeticContext) -> Option<List> {
         }
     }
     fn pseudoremainder_terms(p: &List, q: &List, arith: &ArithmeticContext) -> List {
-        let (t1, t2) = (arith.first_term(&p), arith.first_term(&q));
+        let (t1, t2) = (arith.first_term(p), arith.first_term(q));
         let factor = integerizing_factor(&t1, &t2, arith);
         // factor & p all are terms
-        div_terms(&mul_terms(&factor, &p, arith), q, arith)
+        div_terms(&mul_terms(&factor, p, arith), q, arith)
             .tail()
             .head()
     }
@@ -1702,12 +2636,12 @@ pub fn install_polynomial_package(arith: &ArithmeticContext) -> Option<List> {
             let final_gcd = mul_terms(&simplified_gcd, &coeff_gcd_term, arith);
 
             let final_first_coeff = coeff(&pure_first_term(&arith.first_term(&final_gcd)));
-            let final_gcd = if final_first_coeff < 0.to_listv() {
+
+            if final_first_coeff < 0.to_listv() {
                 negative_terms(&final_gcd, arith)
             } else {
                 final_gcd
-            };
-            final_gcd
+            }
         }
     }
     fn term_list_coeffs_gcd(term_list: &List, arith: &ArithmeticContext) -> List {
@@ -1725,7 +2659,7 @@ pub fn install_polynomial_package(arith: &ArithmeticContext) -> Option<List> {
             let gcd = if arith.is_equal_to_zero(&coeff2) == true.to_listv() {
                 first.clone()
             } else {
-                arith.gcd(&first, &coeff2)
+                arith.gcd(first, &coeff2)
             };
             iter(&gcd, &arith.rest_terms(term_list), arith)
         }
@@ -1746,8 +2680,8 @@ pub fn install_polynomial_package(arith: &ArithmeticContext) -> Option<List> {
     }
 
     fn simplify_terms_coeffs(term_list: &List, arith: &ArithmeticContext) -> List {
-        let gcd = term_list_coeffs_gcd(term_list, &arith);
-        normalize_terms_coeffs(term_list, gcd, &arith)
+        let gcd = term_list_coeffs_gcd(term_list, arith);
+        normalize_terms_coeffs(term_list, gcd, arith)
     }
     fn reduce_terms(n: &List, d: &List, arith: &ArithmeticContext) -> (List, List) {
         let g = gcd_terms(n, d, arith);
@@ -1860,19 +2794,19 @@ pub fn install_polynomial_package(arith: &ArithmeticContext) -> Option<List> {
             if arith.is_equal_to_zero(&coeff(&t)) == false.to_listv() {
                 false.to_listv()
             } else {
-                is_equal_to_zero(&arith.rest_terms(term_list), &arith)
+                is_equal_to_zero(&arith.rest_terms(term_list), arith)
             }
         }
     }
     fn is_equal_terms(p1: &List, p2: &List, arith: &ArithmeticContext) -> List {
-        match (is_empty_term_list(&p1), is_empty_term_list(&p2)) {
+        match (is_empty_term_list(p1), is_empty_term_list(p2)) {
             (true, true) => true.to_listv(),
             (true, false) => false.to_listv(),
             (false, true) => false.to_listv(),
             (false, false) => {
-                let t1 = arith.first_term(&p1);
+                let t1 = arith.first_term(p1);
                 let (order1, coeff1) = (order(&pure_first_term(&t1)), coeff(&pure_first_term(&t1)));
-                let t2 = arith.first_term(&p2);
+                let t2 = arith.first_term(p2);
                 let (order2, coeff2) = (order(&pure_first_term(&t2)), coeff(&pure_first_term(&t2)));
                 if arith.is_equal(&order1, &order2) == true.to_listv()
                     && arith.is_equal(&coeff1, &coeff2) == true.to_listv()
@@ -2007,3 +2941,258 @@ pub fn install_polynomial_package(arith: &ArithmeticContext) -> Option<List> {
     });
     Some("done".to_string().to_listv())
 }
+
+/// Values that can be turned into a tagged datum of the generic arithmetic system:
+/// `i32` (integer), `f64` (float), `(i32, i32)` (rational `n/d`) and `(f64, f64)`
+/// (complex, from real and imaginary parts).
+/// 可转换为通用算术系统中带标签数据的字面量。
+pub trait NumberLiteral {
+    fn make(self, arith: &ArithmeticContext) -> List;
+}
+impl NumberLiteral for i32 {
+    fn make(self, arith: &ArithmeticContext) -> List {
+        make_integer(self, arith)
+    }
+}
+impl NumberLiteral for i64 {
+    fn make(self, arith: &ArithmeticContext) -> List {
+        make_integer(self, arith)
+    }
+}
+impl NumberLiteral for BigInt {
+    fn make(self, arith: &ArithmeticContext) -> List {
+        make_integer(self, arith)
+    }
+}
+impl NumberLiteral for BigRational {
+    fn make(self, arith: &ArithmeticContext) -> List {
+        make_bigrational(self, arith)
+    }
+}
+impl NumberLiteral for f64 {
+    fn make(self, arith: &ArithmeticContext) -> List {
+        make_float(self, arith)
+    }
+}
+impl NumberLiteral for (i32, i32) {
+    fn make(self, arith: &ArithmeticContext) -> List {
+        make_rational(self.0.to_listv(), self.1.to_listv(), arith)
+    }
+}
+impl NumberLiteral for (f64, f64) {
+    fn make(self, arith: &ArithmeticContext) -> List {
+        make_complex_from_real_imag(self.0.to_listv(), self.1.to_listv(), arith)
+    }
+}
+
+/// A tagged datum together with the `ArithmeticContext` it belongs to, so that generic
+/// arithmetic can be written with Rust operators; every operator dispatches through
+/// `apply_generic`.
+/// 带标签数据与其所属 `ArithmeticContext` 的组合，运算符均经由 `apply_generic` 分派。
+///
+/// ```rust
+/// use sicp_rs::ch2::ch2_5::{ArithmeticContext, Number, install_arithmetic_package};
+///
+/// let arith = ArithmeticContext::new();
+/// install_arithmetic_package(&arith);
+/// let half = arith.number((1, 2));
+/// let x = (half.clone() + 1) * 2;
+/// assert_eq!(x, arith.number(3));
+/// assert!(half < arith.number(0.75));
+/// assert_eq!(format!("{}", -half), "-1/2");
+/// ```
+#[derive(Clone)]
+pub struct Number {
+    value: List,
+    arith: ArithmeticContext,
+}
+
+impl Number {
+    pub fn new(value: impl NumberLiteral, arith: &ArithmeticContext) -> Self {
+        Number::from_list(value.make(arith), arith)
+    }
+    /// Wraps an already tagged datum, e.g. the result of `arith.add`.
+    /// 包装一个已带标签的数据。
+    pub fn from_list(value: List, arith: &ArithmeticContext) -> Self {
+        Number {
+            value,
+            arith: arith.clone(),
+        }
+    }
+    pub fn value(&self) -> &List {
+        &self.value
+    }
+    pub fn into_list(self) -> List {
+        self.value
+    }
+    pub fn arith(&self) -> &ArithmeticContext {
+        &self.arith
+    }
+    pub fn type_tag(&self) -> String {
+        type_tag(&self.value).to_string()
+    }
+    fn wrap(&self, value: List) -> Number {
+        Number::from_list(value, &self.arith)
+    }
+    // 实数与零的比较，复数等其他类型返回 None
+    fn sign(&self) -> Option<std::cmp::Ordering> {
+        fn basis_sign(x: &List) -> Option<std::cmp::Ordering> {
+            if let Ok(i) = x.try_as_basis_value::<i32>() {
+                Some(i.cmp(&0))
+            } else if let Ok(f) = x.try_as_basis_value::<f64>() {
+                f.partial_cmp(&0.0)
+            } else if let Ok(i) = x.try_as_basis_value::<BigInt>() {
+                Some(i.cmp(&BigInt::zero()))
+            } else if let Ok(r) = x.try_as_basis_value::<BigRational>() {
+                Some(r.cmp(&BigRational::zero()))
+            } else {
+                None
+            }
+        }
+        match self.type_tag().as_str() {
+            "integer" | "float" => basis_sign(&self.value),
+            "bigint" | "bigrational" => basis_sign(&contents(&self.value)),
+            "rational" => {
+                let n = basis_sign(&self.arith.numer(&self.value))?;
+                let d = basis_sign(&self.arith.denom(&self.value))?;
+                Some(if d.is_lt() { n.reverse() } else { n })
+            }
+            _ => None,
+        }
+    }
+}
+
+impl ArithmeticContext {
+    /// Wraps a literal as a `Number` of this context.
+    /// 将字面量包装为本上下文中的 `Number`。
+    pub fn number(&self, value: impl NumberLiteral) -> Number {
+        Number::new(value, self)
+    }
+}
+
+macro_rules! impl_number_ops {
+    ($($trait:ident, $method:ident);* $(;)?) => {
+        $(
+            impl std::ops::$trait<&Number> for &Number {
+                type Output = Number;
+                fn $method(self, rhs: &Number) -> Number {
+                    self.wrap(self.arith.$method(&self.value, &rhs.value))
+                }
+            }
+            impl std::ops::$trait<Number> for Number {
+                type Output = Number;
+                fn $method(self, rhs: Number) -> Number {
+                    (&self).$method(&rhs)
+                }
+            }
+            impl std::ops::$trait<&Number> for Number {
+                type Output = Number;
+                fn $method(self, rhs: &Number) -> Number {
+                    (&self).$method(rhs)
+                }
+            }
+            impl std::ops::$trait<Number> for &Number {
+                type Output = Number;
+                fn $method(self, rhs: Number) -> Number {
+                    self.$method(&rhs)
+                }
+            }
+            impl<T: NumberLiteral> std::ops::$trait<T> for Number {
+                type Output = Number;
+                fn $method(self, rhs: T) -> Number {
+                    let rhs = Number::new(rhs, &self.arith);
+                    (&self).$method(&rhs)
+                }
+            }
+            impl<T: NumberLiteral> std::ops::$trait<T> for &Number {
+                type Output = Number;
+                fn $method(self, rhs: T) -> Number {
+                    self.$method(&Number::new(rhs, &self.arith))
+                }
+            }
+            impl std::ops::$trait<Number> for i32 {
+                type Output = Number;
+                fn $method(self, rhs: Number) -> Number {
+                    Number::new(self, &rhs.arith).$method(&rhs)
+                }
+            }
+            impl std::ops::$trait<Number> for f64 {
+                type Output = Number;
+                fn $method(self, rhs: Number) -> Number {
+                    Number::new(self, &rhs.arith).$method(&rhs)
+                }
+            }
+        )*
+    };
+}
+impl_number_ops! {
+    Add, add;
+    Sub, sub;
+    Mul, mul;
+    Div, div;
+}
+
+impl std::ops::Neg for &Number {
+    type Output = Number;
+    fn neg(self) -> Number {
+        self.wrap(self.arith.negative(&self.value))
+    }
+}
+impl std::ops::Neg for Number {
+    type Output = Number;
+    fn neg(self) -> Number {
+        -&self
+    }
+}
+
+impl PartialEq for Number {
+    fn eq(&self, other: &Self) -> bool {
+        self.arith.is_equal(&self.value, &other.value) == true.to_listv()
+    }
+}
+impl PartialOrd for Number {
+    /// Orders real numbers by the sign of their generic difference; complex numbers are
+    /// unordered.
+    /// 按通用减法结果的符号比较实数；复数之间不可比较。
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        if self.type_tag() == "complex" || other.type_tag() == "complex" {
+            return None;
+        }
+        if self == other {
+            return Some(std::cmp::Ordering::Equal);
+        }
+        (self - other).sign()
+    }
+}
+
+impl fmt::Display for Number {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.type_tag().as_str() {
+            "rational" => {
+                let (n, d) = (self.arith.numer(&self.value), self.arith.denom(&self.value));
+                // 负号总是显示在分子上
+                if self.wrap(d.clone()).sign() == Some(std::cmp::Ordering::Less) {
+                    write!(f, "{}/{}", self.arith.negative(&n), self.arith.negative(&d))
+                } else {
+                    write!(f, "{}/{}", n, d)
+                }
+            }
+            "complex" => {
+                let re = self.arith.real_part(&self.value);
+                let im = self.arith.imag_part(&self.value).to_string();
+                if im.starts_with('-') {
+                    write!(f, "{}{}i", re, im)
+                } else {
+                    write!(f, "{}+{}i", re, im)
+                }
+            }
+            "bigint" | "bigrational" => write!(f, "{}", contents(&self.value)),
+            _ => write!(f, "{}", self.value),
+        }
+    }
+}
+impl fmt::Debug for Number {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("Number").field(&self.value).finish()
+    }
+}
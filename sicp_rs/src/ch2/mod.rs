@@ -1,3 +1,3 @@
-pub mod ch2_4;
 pub mod ch2_3;
-pub mod ch2_5;
\ No newline at end of file
+pub mod ch2_4;
+pub mod ch2_5;
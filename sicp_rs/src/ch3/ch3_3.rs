@@ -1,6 +1,5 @@
-use crate::list_impl::panic_with_location;
+use crate::list_impl::{Shared, panic_with_location};
 use crate::prelude::*;
-use std::rc::Rc;
 
 /// 3.3.2 队列的表示
 pub fn make_queue() -> List {
@@ -51,28 +50,14 @@ pub fn front_queue(queue: &List) -> List {
     }
 }
 
-/// 3.3.3 表格的表示
+// 3.3.3 表格的表示
 
-fn assoc(key: &List, records: &List) -> Option<List> {
-    if records.is_empty() {
-        None
-    } else if *key == records.head().head() {
-        // List::V(_) == List::V(_) or List::Cons(_,_) == List::Cons(_,_)
-        Some(records.head().clone())
-    } else {
-        assoc(key, &records.tail())
-    }
-}
 pub fn lookup(key: &List, table: &List) -> Option<List> {
-    let record = assoc(key, &table.tail());
-    if let Some(record) = record {
-        Some(record.tail())
-    } else {
-        None
-    }
+    let record = table.tail().assoc(key);
+    record.map(|record| record.tail())
 }
 pub fn insert(key: &List, value: List, table: &List) -> Option<List> {
-    let record = assoc(&key, &table.tail());
+    let record = table.tail().assoc(key);
     if let Some(record) = record {
         record.set_tail(value);
     } else {
@@ -95,23 +80,19 @@ mod test_table_1d {
 }
 
 pub fn lookup_2d(key1: &List, key2: &List, local_table: &List) -> Option<List> {
-    let subtable = assoc(&key1, &local_table.tail());
+    let subtable = local_table.tail().assoc(key1);
 
     if let Some(subtable) = subtable {
-        let record = assoc(&key2, &subtable.tail());
-        if let Some(record) = record {
-            Some(record.tail())
-        } else {
-            None
-        }
+        let record = subtable.tail().assoc(key2);
+        record.map(|record| record.tail())
     } else {
         None
     }
 }
 pub fn insert_2d(key1: &List, key2: &List, value: List, local_table: List) -> Option<List> {
-    let subtable = assoc(&key1, &local_table.tail());
+    let subtable = local_table.tail().assoc(key1);
     if let Some(subtable) = subtable {
-        let record = assoc(&key2, &subtable.tail());
+        let record = subtable.tail().assoc(key2);
         if let Some(record) = record {
             record.set_tail(value);
         } else {
@@ -126,8 +107,15 @@ pub fn insert_2d(key1: &List, key2: &List, value: List, local_table: List) -> Op
     Some("ok".to_string().to_listv())
 }
 
-pub fn make_table_2d() -> Rc<dyn Fn(&str) -> ClosureWrapper> {
-    let local_table = Rc::new(list!["*table*"]);
+/// Message-passing table returned by `make_table_2d`.
+/// `make_table_2d` 返回的消息传递式表格；启用 `multi_threaded` 特性时可在线程间共享。
+#[cfg(not(feature = "multi_threaded"))]
+pub type Table2d = Shared<dyn Fn(&str) -> ClosureWrapper>;
+#[cfg(feature = "multi_threaded")]
+pub type Table2d = Shared<dyn Fn(&str) -> ClosureWrapper + Send + Sync>;
+
+pub fn make_table_2d() -> Table2d {
+    let local_table = Shared::new(list!["*table*"]);
     let local1 = local_table.clone();
     let local2 = local_table.clone();
     // 必须将闭包显式写在此处,以方便编译器推断生命周期;若这部分代码直接写在下面的闭包中,则编译器无法推断生命周期,编译失败.
@@ -146,7 +134,7 @@ pub fn make_table_2d() -> Rc<dyn Fn(&str) -> ClosureWrapper> {
 
         insert_2d(&a1, &a2, a3, (*lt).clone())
     };
-    Rc::new(move |m: &str| {
+    Shared::new(move |m: &str| {
         if m == "lookup" {
             ClosureWrapper::new(lookup.clone())
         } else if m == "insert" {
@@ -156,7 +144,7 @@ pub fn make_table_2d() -> Rc<dyn Fn(&str) -> ClosureWrapper> {
                 let lt = local_table.clone();
                 move |args| {
                     let key1 = args.head();
-                    assoc(&key1, &lt.tail())
+                    lt.tail().assoc(&key1)
                 }
             })
         } else {
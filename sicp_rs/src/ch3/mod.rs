@@ -1 +1 @@
-pub mod ch3_3;
\ No newline at end of file
+pub mod ch3_3;
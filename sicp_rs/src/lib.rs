@@ -1,10 +1,10 @@
-pub mod listv;
-pub mod list_impl;
-pub mod ch3;
 pub mod ch2;
+pub mod ch3;
+pub mod list_impl;
+pub mod listv;
 
 pub mod prelude {
+    pub use crate::list_impl::{ClosureWrapper, List, MaybeSendSync, Symbol};
     pub use crate::listv::ListV;
-    pub use crate::list_impl::{List,ClosureWrapper};
-    pub use crate::{list,pair,is_type};
-}
\ No newline at end of file
+    pub use crate::{is_type, list, match_list, pair, sym};
+}
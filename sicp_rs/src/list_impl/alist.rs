@@ -0,0 +1,211 @@
+// src/list_impl/alist.rs
+//! # Association Lists and Property Lists
+//!
+//! An association list (alist) is a list of `(key . value)` pairs, as used by the tables of
+//! SICP 3.3.3; a property list (plist) stores the same entries flat, as `(k1 v1 k2 v2 ...)`.
+//! Lookups come in three flavours by equality kind, following Scheme: `assoc` (`is_equal`),
+//! `assv` (`is_eqv`) and `assq` (`is_eq`). The other operations compare keys with `is_equal`.
+//!
+//! `assoc` returns the entry pair itself, so `set_tail` on it updates the table in place.
+//! `alist_insert`, `alist_remove`, `alist_update` and the plist operations build new lists and
+//! leave the original untouched. The plist operations panic on a plist of odd length.
+//!
+//! 关联表（alist）是由 `(key . value)` 序对组成的表，属性表（plist）将其平铺为 `(k1 v1 k2 v2 ...)`。
+//! 查找按相等谓词分为 `assoc`、`assv`、`assq`；其余操作均不修改原表。
+//!
+//! ## Examples
+//! ```rust
+//! use sicp_rs::prelude::*;
+//!
+//! let table = List::Nil
+//!     .alist_insert(sym!(a).to_listv(), 1.to_listv())
+//!     .alist_insert(sym!(b).to_listv(), 2.to_listv());
+//! assert_eq!(table.assoc(&sym!(a).to_listv()), Some(pair![sym!(a), 1]));
+//! assert_eq!(table.alist_keys(), list![sym!(b), sym!(a)]);
+//!
+//! let table = table.alist_update(&sym!(a).to_listv(), |v| {
+//!     (v.unwrap().try_as_basis_value::<i32>().unwrap() + 10).to_listv()
+//! });
+//! assert_eq!(table.assoc(&sym!(a).to_listv()).unwrap().tail(), 11.to_listv());
+//!
+//! let plist = list![sym!(color), "red", sym!(size), 3];
+//! assert_eq!(plist.plist_get(&sym!(size).to_listv()), Some(3.to_listv()));
+//! assert_eq!(plist.plist_to_alist(), list![pair![sym!(color), "red"], pair![sym!(size), 3]]);
+//! ```
+
+use std::collections::HashMap;
+
+use super::{List, panic_with_location};
+
+impl List {
+    fn assoc_by(&self, key: &List, same: impl Fn(&List, &List) -> bool) -> Option<List> {
+        self.iter().find(|entry| same(key, &entry.head()))
+    }
+
+    /// Returns the first entry whose key is `equal?` to `key`, like Scheme's `assoc`.
+    /// 返回第一个键与 `key` 结构相等（`equal?`）的表项。
+    pub fn assoc(&self, key: &List) -> Option<List> {
+        self.assoc_by(key, List::is_equal)
+    }
+
+    /// Returns the first entry whose key is `eqv?` to `key`, like Scheme's `assv`.
+    /// 返回第一个键与 `key` 满足 `eqv?` 的表项。
+    pub fn assv(&self, key: &List) -> Option<List> {
+        self.assoc_by(key, List::is_eqv)
+    }
+
+    /// Returns the first entry whose key is `eq?` to `key`, like Scheme's `assq`.
+    /// 返回第一个键与 `key` 为同一对象（`eq?`）的表项。
+    pub fn assq(&self, key: &List) -> Option<List> {
+        self.assoc_by(key, List::is_eq)
+    }
+
+    /// Returns an alist with `(key . value)` in front and any older entries for `key` removed.
+    /// 返回新的关联表：`(key . value)` 位于表头，并移除该键原有的表项。
+    pub fn alist_insert(&self, key: List, value: List) -> List {
+        let rest = self.alist_remove(&key);
+        List::pair(List::pair(key, value), rest)
+    }
+
+    /// Returns the alist without the entries for `key`.
+    /// 返回移除 `key` 所有表项后的关联表。
+    pub fn alist_remove(&self, key: &List) -> List {
+        self.iter()
+            .filter(|entry| !entry.head().is_equal(key))
+            .collect()
+    }
+
+    /// Returns the alist with the value for `key` replaced by `f(Some(old))`, keeping its
+    /// position, or with `(key . f(None))` in front if `key` is absent.
+    /// 将 `key` 的值替换为 `f(Some(旧值))` 并保持其位置；若不存在该键，则在表头加入 `(key . f(None))`。
+    pub fn alist_update(&self, key: &List, f: impl FnOnce(Option<List>) -> List) -> List {
+        match self.assoc(key) {
+            Some(found) => {
+                let mut new_value = Some(f(Some(found.tail())));
+                // 重复的键只保留第一个表项
+                self.iter()
+                    .filter_map(|entry| {
+                        if !entry.head().is_equal(key) {
+                            Some(entry)
+                        } else {
+                            new_value
+                                .take()
+                                .map(|value| List::pair(entry.head(), value))
+                        }
+                    })
+                    .collect()
+            }
+            None => List::pair(List::pair(key.clone(), f(None)), self.clone()),
+        }
+    }
+
+    /// Returns the keys of the alist, in order.
+    /// 按顺序返回关联表的所有键。
+    pub fn alist_keys(&self) -> List {
+        self.iter().map(|entry| entry.head()).collect()
+    }
+
+    /// Converts the alist to a `HashMap`; the first entry for a key wins, as with `assoc`.
+    /// 转换为 `HashMap`；与 `assoc` 一致，同一键以第一个表项为准。
+    pub fn alist_to_hash_map(&self) -> HashMap<List, List> {
+        let mut map = HashMap::new();
+        for entry in self.iter() {
+            map.entry(entry.head()).or_insert_with(|| entry.tail());
+        }
+        map
+    }
+
+    /// Builds an alist from a `HashMap`, in the map's iteration order.
+    /// 由 `HashMap` 构造关联表，表项顺序即 `HashMap` 的遍历顺序。
+    pub fn alist_from_hash_map(map: &HashMap<List, List>) -> List {
+        map.iter()
+            .map(|(key, value)| List::pair(key.clone(), value.clone()))
+            .collect()
+    }
+
+    fn plist_entries(&self) -> Vec<(List, List)> {
+        let mut entries = Vec::new();
+        let mut rest = self.clone();
+        while !rest.is_empty() {
+            let key = rest.head();
+            rest = rest.tail();
+            if rest.is_empty() {
+                panic_with_location("plist has a key without a value", self);
+            }
+            entries.push((key, rest.head()));
+            rest = rest.tail();
+        }
+        entries
+    }
+
+    fn plist_from_entries(entries: impl IntoIterator<Item = (List, List)>) -> List {
+        entries
+            .into_iter()
+            .flat_map(|(key, value)| [key, value])
+            .collect()
+    }
+
+    /// Returns the value after the first occurrence of `key` in the plist.
+    /// 返回属性表中第一个 `key` 之后的值。
+    ///
+    /// # Panics
+    /// Panics if the plist has odd length (a key without a value) or is not a proper list.
+    /// The same holds for every `plist_*` method and `plist_to_alist`.
+    /// 若属性表长度为奇数（某个键没有值）或不是以 `Nil` 结尾的链表，将会触发 panic；
+    /// 其他 `plist_*` 方法与 `plist_to_alist` 同理。
+    pub fn plist_get(&self, key: &List) -> Option<List> {
+        self.plist_entries()
+            .into_iter()
+            .find(|(k, _)| k.is_equal(key))
+            .map(|(_, value)| value)
+    }
+
+    /// Returns the plist with the value for `key` replaced in place, or with `key value`
+    /// appended if `key` is absent.
+    /// 返回替换 `key` 对应值后的属性表；若不存在该键，则在末尾加入 `key value`。
+    ///
+    /// # Panics
+    /// 与 `plist_get` 相同，属性表长度为奇数时触发 panic。
+    pub fn plist_put(&self, key: List, value: List) -> List {
+        let mut entries = self.plist_entries();
+        match entries.iter_mut().find(|(k, _)| k.is_equal(&key)) {
+            Some(entry) => entry.1 = value,
+            None => entries.push((key, value)),
+        }
+        List::plist_from_entries(entries)
+    }
+
+    /// Returns the plist without the entries for `key`.
+    /// 返回移除 `key` 所有表项后的属性表。
+    pub fn plist_remove(&self, key: &List) -> List {
+        List::plist_from_entries(
+            self.plist_entries()
+                .into_iter()
+                .filter(|(k, _)| !k.is_equal(key)),
+        )
+    }
+
+    /// Returns the keys of the plist, in order.
+    /// 按顺序返回属性表的所有键。
+    pub fn plist_keys(&self) -> List {
+        self.plist_entries()
+            .into_iter()
+            .map(|(key, _)| key)
+            .collect()
+    }
+
+    /// Converts `(k1 v1 k2 v2 ...)` to `((k1 . v1) (k2 . v2) ...)`.
+    /// 将属性表转换为关联表。
+    pub fn plist_to_alist(&self) -> List {
+        self.plist_entries()
+            .into_iter()
+            .map(|(key, value)| List::pair(key, value))
+            .collect()
+    }
+
+    /// Converts `((k1 . v1) (k2 . v2) ...)` to `(k1 v1 k2 v2 ...)`.
+    /// 将关联表转换为属性表。
+    pub fn alist_to_plist(&self) -> List {
+        List::plist_from_entries(self.iter().map(|entry| (entry.head(), entry.tail())))
+    }
+}
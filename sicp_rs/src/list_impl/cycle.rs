@@ -0,0 +1,174 @@
+// src/list_impl/cycle.rs
+//! # Shared and Cyclic Structure
+//!
+//! `set_head`/`set_tail` can make a cell reachable from itself (exercises 3.13, 3.18, 3.19).
+//! This module finds such cells, identified by the addresses of their head and tail cells, so
+//! that printing, comparison and length computations can stop instead of recursing forever.
+//! 检测共享与循环的序对，供打印、比较和求长度时使用。
+//!
+//! ## Examples
+//! ```rust
+//! use sicp_rs::prelude::*;
+//!
+//! let l = list![sym!(a), sym!(b)];
+//! l.last_pair().set_tail(l.clone());
+//! assert!(l.is_cyclic());
+//! assert_eq!(l.to_scheme_string(), "#0=(a b . #0#)");
+//! assert_eq!(l.to_string(), "#0=(a, (b, #0#))");
+//! ```
+
+use std::collections::{HashMap, HashSet};
+
+use super::List;
+use super::equality::Identity;
+
+/// Result of walking every cell reachable from a list.
+/// 遍历结果：`cyclic` 为构成环的序对，`shared` 为被多次引用（但不成环）的序对。
+struct CellScan {
+    cyclic: HashSet<Identity>,
+    shared: HashSet<Identity>,
+}
+
+/// Depth-first walk with an explicit stack; a cell met again while still on the current path
+/// closes a cycle, a cell met again after it has been finished is merely shared.
+/// 显式栈深度优先遍历，不依赖 Rust 递归。
+fn scan(l: &List) -> CellScan {
+    enum Frame {
+        Enter(List),
+        Exit(Identity),
+    }
+    // true: 仍在当前路径上; false: 已遍历完成
+    let mut on_path: HashMap<Identity, bool> = HashMap::new();
+    let mut result = CellScan {
+        cyclic: HashSet::new(),
+        shared: HashSet::new(),
+    };
+    let mut stack = vec![Frame::Enter(l.clone())];
+    while let Some(frame) = stack.pop() {
+        match frame {
+            Frame::Enter(cell @ List::Cons(_, _)) => {
+                let id = cell.identity();
+                match on_path.get(&id) {
+                    Some(true) => {
+                        result.cyclic.insert(id);
+                    }
+                    Some(false) => {
+                        result.shared.insert(id);
+                    }
+                    None => {
+                        on_path.insert(id, true);
+                        stack.push(Frame::Exit(id));
+                        stack.push(Frame::Enter(cell.tail()));
+                        stack.push(Frame::Enter(cell.head()));
+                    }
+                }
+            }
+            Frame::Enter(_) => (),
+            Frame::Exit(id) => {
+                on_path.insert(id, false);
+            }
+        }
+    }
+    result
+}
+
+/// What a printer should emit in front of a cell.
+/// 打印序对时需要输出的标签。
+pub(crate) enum Label {
+    None,
+    /// `#n=`: first occurrence of a labelled cell.
+    Define(usize),
+    /// `#n#`: later occurrence, print only the reference.
+    Ref(usize),
+}
+
+/// Datum label bookkeeping shared by the printers.
+/// 打印器共用的数据标签记录。
+#[derive(Clone)]
+pub(crate) struct Labels {
+    targets: HashSet<Identity>,
+    assigned: HashMap<Identity, usize>,
+}
+
+impl Labels {
+    /// Label only the cells that take part in a cycle, as Scheme's `write` does.
+    /// 只为成环的序对加标签。
+    pub(crate) fn cycles(l: &List) -> Self {
+        let targets = if l.is_pair() {
+            scan(l).cyclic
+        } else {
+            HashSet::new()
+        };
+        Labels {
+            targets,
+            assigned: HashMap::new(),
+        }
+    }
+
+    /// Label every cell referenced more than once, as Scheme's `write-shared` does.
+    /// 为所有被多次引用的序对加标签。
+    pub(crate) fn shared(l: &List) -> Self {
+        let scan = scan(l);
+        Labels {
+            targets: scan.cyclic.union(&scan.shared).copied().collect(),
+            assigned: HashMap::new(),
+        }
+    }
+
+    pub(crate) fn is_target(&self, l: &List) -> bool {
+        l.is_pair() && !self.targets.is_empty() && self.targets.contains(&l.identity())
+    }
+
+    /// Record that `l` is about to be printed.
+    /// 记录即将打印的序对，返回应输出的标签。
+    pub(crate) fn visit(&mut self, l: &List) -> Label {
+        if !self.is_target(l) {
+            return Label::None;
+        }
+        let id = l.identity();
+        if let Some(n) = self.assigned.get(&id) {
+            Label::Ref(*n)
+        } else {
+            let n = self.assigned.len();
+            self.assigned.insert(id, n);
+            Label::Define(n)
+        }
+    }
+}
+
+impl List {
+    /// Check whether any cell is reachable from itself, through heads or tails.
+    /// 检查结构中是否存在环（经由头部或尾部均可）。
+    pub fn is_cyclic(&self) -> bool {
+        self.is_pair() && !scan(self).cyclic.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_identity_distinguishes_cell_pairs() {
+        // 旧的 unique_id 为 head * 31 + tail，这两个序对在其下相同
+        let x = Identity::Pair(1, 31);
+        let y = Identity::Pair(2, 0);
+        assert!(x != y);
+        assert_eq!(HashSet::from([x, y]).len(), 2);
+    }
+
+    #[test]
+    fn test_scan_keys_on_identity() {
+        let y = pair![3, 4];
+        let x = pair![1, y.clone()];
+        let result = scan(&list![x.clone(), y.clone(), x.clone()]);
+        assert!(result.cyclic.is_empty());
+        assert!(result.shared == HashSet::from([x.identity(), y.identity()]));
+
+        y.set_tail(x.clone());
+        let result = scan(&x);
+        assert!(result.cyclic == HashSet::from([x.identity()]));
+        assert!(result.shared.is_empty());
+    }
+}
@@ -0,0 +1,95 @@
+// src/list_impl/equality.rs
+//! # Equality Predicates
+//!
+//! Scheme's three equality predicates on `List`, from strictest to loosest:
+//! - `is_eq` (`eq?`): the same object. Pairs are the same when they share their cells, so
+//!   mutating one through `set_head`/`set_tail` is visible through the other. Values are the
+//!   same when they share an allocation, except that symbols with the same name and booleans
+//!   with the same value are always the same, as are all `Nil`s.
+//! - `is_eqv` (`eqv?`): `is_eq`, or two numbers that are both exact (integers, `BigRational`)
+//!   or both floats and are numerically equal. `2` and `2_u64` are `eqv?`; `2` and `2.0` are not.
+//! - `is_equal` (`equal?`): the structural `==` of `List`, which also compares strings by text
+//!   and numbers by value across all numeric types.
+//!
+//! Scheme 的三种相等谓词：`is_eq` 判断是否同一对象，`is_eqv` 另外比较精确性相同的数值，
+//! `is_equal` 按结构比较。
+//!
+//! ## Examples
+//! ```rust
+//! use sicp_rs::prelude::*;
+//!
+//! let x = list![1, 2];
+//! let y = list![1, 2];
+//! assert!(x.is_eq(&x.clone()) && !x.is_eq(&y));
+//! assert!(x.is_equal(&y));
+//!
+//! assert!(sym!(a).to_listv().is_eq(&sym!(a).to_listv()));
+//! assert!(List::Nil.is_eq(&x.tail().tail()));
+//!
+//! assert!(2.to_listv().is_eqv(&2_u64.to_listv()));
+//! assert!(!2.to_listv().is_eqv(&2.0.to_listv()));
+//! assert!(2.to_listv().is_equal(&2.0.to_listv()));
+//! ```
+
+use super::{List, Shared, Symbol};
+use crate::prelude::ListV;
+
+/// What makes a list the same object as another, as used by `is_eq` and `ByIdentity`.
+/// 判断是否为同一对象的依据，供 `is_eq` 与 `ByIdentity` 使用。
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) enum Identity {
+    Pair(usize, usize),
+    Value(usize),
+    Symbol(Symbol),
+    Bool(bool),
+    Nil,
+}
+
+impl List {
+    pub(crate) fn identity(&self) -> Identity {
+        match self {
+            List::Cons(head, tail) => {
+                Identity::Pair(Shared::as_ptr(head) as usize, Shared::as_ptr(tail) as usize)
+            }
+            List::V(v) => {
+                let any = v.as_ref().as_any();
+                if let Some(s) = any.downcast_ref::<Symbol>() {
+                    Identity::Symbol(*s)
+                } else if let Some(b) = any.downcast_ref::<bool>() {
+                    Identity::Bool(*b)
+                } else {
+                    Identity::Value(Shared::as_ptr(v) as *const () as usize)
+                }
+            }
+            List::Nil => Identity::Nil,
+        }
+    }
+
+    /// Scheme's `eq?`: whether both are the same object.
+    /// 对应 `eq?`：是否为同一对象。
+    pub fn is_eq(&self, other: &List) -> bool {
+        self.identity() == other.identity()
+    }
+
+    /// Scheme's `eqv?`: `is_eq`, or numbers of the same exactness with equal values.
+    /// 对应 `eqv?`：同一对象，或精确性相同且数值相等的数。
+    pub fn is_eqv(&self, other: &List) -> bool {
+        if self.is_eq(other) {
+            return true;
+        }
+        match (self, other) {
+            (List::V(a), List::V(b)) => {
+                let (a, b) = (a.as_ref(), b.as_ref());
+                let exact = |v: &dyn ListV| v.is_integer() || v.is_rational();
+                ((exact(a) && exact(b)) || (a.is_float() && b.is_float())) && a == b
+            }
+            _ => false,
+        }
+    }
+
+    /// Scheme's `equal?`: structural equality, the same as `==`.
+    /// 对应 `equal?`：按结构比较，与 `==` 相同。
+    pub fn is_equal(&self, other: &List) -> bool {
+        self == other
+    }
+}
@@ -0,0 +1,71 @@
+// src/list_impl/error.rs
+//! # List Errors
+//!
+//! Error type returned by the fallible `try_*` accessors of `List`, as an alternative to the
+//! panicking `head`/`tail`/`set_head`/... when the input is not trusted.
+//! `List` 的 `try_*` 系列方法返回的错误类型，用于替代会 panic 的访问方法。
+//!
+//! ## Examples
+//! ```rust
+//! use sicp_rs::prelude::*;
+//! use sicp_rs::list_impl::ListError;
+//!
+//! assert_eq!(list![1, 2].try_head(), Ok(1.to_listv()));
+//! assert_eq!(
+//!     List::Nil.try_tail(),
+//!     Err(ListError::EmptyList { op: "tail" })
+//! );
+//! assert!(matches!(
+//!     1.to_listv().try_as_basis_value::<f64>(),
+//!     Err(ListError::TypeMismatch { .. })
+//! ));
+//! ```
+
+use std::error::Error;
+use std::fmt;
+
+/// Reason a `List` operation could not be carried out.
+/// `List` 操作失败的原因。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ListError {
+    /// The operation needs another variant, e.g. `head` on a value or `get_basis_value` on a pair.
+    /// 调用者的变体不符合要求，例如对值调用 `head`。
+    WrongVariant {
+        op: &'static str,
+        expected: &'static str,
+        found: String,
+    },
+    /// The value is not of the requested Rust type.
+    /// 值的类型与请求的 Rust 类型不一致。
+    TypeMismatch {
+        expected: &'static str,
+        found: String,
+    },
+    /// The operation needs a pair but was given `Nil`.
+    /// 需要序对，却得到了空表。
+    EmptyList { op: &'static str },
+    /// The list does not end with `Nil`, or its tail is cyclic and never ends.
+    /// 链表不以 `Nil` 结尾，或尾部成环。
+    ImproperList { op: &'static str, found: String },
+}
+
+impl fmt::Display for ListError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ListError::WrongVariant {
+                op,
+                expected,
+                found,
+            } => write!(f, "{}: expected {}, found {}", op, expected, found),
+            ListError::TypeMismatch { expected, found } => {
+                write!(f, "type mismatch: expected {}, found {}", expected, found)
+            }
+            ListError::EmptyList { op } => write!(f, "{}: empty list", op),
+            ListError::ImproperList { op, found } => {
+                write!(f, "{}: improper list, found {}", op, found)
+            }
+        }
+    }
+}
+
+impl Error for ListError {}
@@ -0,0 +1,93 @@
+// src/list_impl/hash.rs
+//! # Hashing
+//!
+//! Two ways to key a `HashMap`/`HashSet` with lists:
+//! - `List` itself hashes by structure, consistently with `PartialEq` (Scheme's `equal?`):
+//!   `list![1, 2]` and `list![1_u64, 2.0]` are the same key.
+//! - `ByIdentity(List)` hashes and compares by cell pointers (Scheme's `eq?`): only the same
+//!   pair is the same key, as needed to remember visited pairs in SICP 3.3.1.
+//!
+//! 两种哈希方式：`List` 按结构哈希（对应 `equal?`），`ByIdentity` 按序对的指针哈希（对应 `eq?`）。
+//!
+//! ## Examples
+//! ```rust
+//! use std::collections::{HashMap, HashSet};
+//! use sicp_rs::list_impl::ByIdentity;
+//! use sicp_rs::prelude::*;
+//!
+//! let mut memo = HashMap::new();
+//! memo.insert(list![1, 2], "found");
+//! assert_eq!(memo.get(&list![1_u64, 2.0]), Some(&"found"));
+//!
+//! let x = list![1, 2];
+//! let mut visited = HashSet::new();
+//! assert!(visited.insert(ByIdentity(x.clone())));
+//! assert!(!visited.insert(ByIdentity(x.clone())));
+//! assert!(visited.insert(ByIdentity(list![1, 2])));
+//! ```
+
+use std::hash::{Hash, Hasher};
+
+use super::List;
+
+/// Nodes hashed from a cyclic list, whose unfolding never ends.
+/// 循环链表展开后无限，只哈希前若干个节点。
+const CYCLIC_HASH_LIMIT: usize = 256;
+
+/// Hashes the tree of values in pre-order. A cyclic list unfolds to an infinite tree, so only
+/// its first `CYCLIC_HASH_LIMIT` nodes are hashed; lists equal to it unfold the same way.
+/// A list must not be mutated (`set_head`/`set_tail`) while it is a key.
+/// 按先序遍历哈希各节点；循环链表只哈希展开后的前若干个节点，与之相等的链表展开结果相同。
+/// 作为键使用期间不应再修改链表。
+impl Hash for List {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let mut budget = if self.is_cyclic() {
+            CYCLIC_HASH_LIMIT
+        } else {
+            usize::MAX
+        };
+        let mut stack = vec![self.clone()];
+        while let Some(l) = stack.pop() {
+            if budget == 0 {
+                break;
+            }
+            budget -= 1;
+            match l {
+                List::Nil => 0u8.hash(state),
+                List::V(ref v) => {
+                    1u8.hash(state);
+                    v.as_ref().hash(state);
+                }
+                List::Cons(_, _) => {
+                    2u8.hash(state);
+                    stack.push(l.tail());
+                    stack.push(l.head());
+                }
+            }
+        }
+    }
+}
+
+/// `List` equality is an equivalence relation except for NaN, which is not equal to itself,
+/// so a list containing NaN cannot be found again as a map key.
+/// 除 NaN 不等于自身外，`List` 的相等关系满足等价关系；含 NaN 的链表作为键时无法再被查到。
+impl Eq for List {}
+
+/// A list compared and hashed by identity, as `List::is_eq` (Scheme's `eq?`) defines it.
+/// 按同一性比较与哈希的链表，与 `List::is_eq` 一致。
+#[derive(Clone, Debug)]
+pub struct ByIdentity(pub List);
+
+impl PartialEq for ByIdentity {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.is_eq(&other.0)
+    }
+}
+
+impl Eq for ByIdentity {}
+
+impl Hash for ByIdentity {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.identity().hash(state);
+    }
+}
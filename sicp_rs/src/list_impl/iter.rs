@@ -0,0 +1,113 @@
+// src/list_impl/iter.rs
+//! # Iterators
+//!
+//! Walk a `List` with Rust's `Iterator` instead of calling `head()`/`tail()` by hand, so the
+//! std adapters (`zip`, `enumerate`, `take_while`, ...) work alongside the SICP-style combinators.
+//! 为 `List` 提供 Rust 原生迭代器，可与标准库的迭代器适配器配合使用。
+//!
+//! Iteration follows the tail chain and stops at the first tail that is not a pair, so the
+//! final value of a list not ending with `Nil` is not yielded. A cyclic list yields forever.
+//! 迭代沿尾部进行，遇到非序对的尾部即停止；循环链表的迭代不会结束。
+//!
+//! ## Examples
+//! ```rust
+//! use sicp_rs::prelude::*;
+//!
+//! let l = list![1, 2, 3];
+//! let doubled: List = l
+//!     .iter()
+//!     .map(|x| (x.try_as_basis_value::<i32>().unwrap() * 2).to_listv())
+//!     .collect();
+//! assert_eq!(doubled, list![2, 4, 6]);
+//!
+//! for (i, x) in l.iter().enumerate() {
+//!     assert_eq!(x, (i as i32 + 1).to_listv());
+//! }
+//!
+//! // 通过序对迭代器原地修改
+//! for cell in l.pairs() {
+//!     let x = *cell.head().try_as_basis_value::<i32>().unwrap();
+//!     cell.set_head((x * 10).to_listv());
+//! }
+//! assert_eq!(l, list![10, 20, 30]);
+//! ```
+
+use super::List;
+
+/// Iterator over the elements of a list, created by `List::iter`.
+/// 逐个返回链表元素的迭代器。
+#[derive(Clone, Debug)]
+pub struct Iter {
+    rest: List,
+}
+
+/// Iterator over the pairs (cells) of a list, created by `List::pairs`.
+/// Each item shares its cell with the list, so `set_head`/`set_tail` on it mutates the list.
+/// 逐个返回链表序对的迭代器，可通过 `set_head`/`set_tail` 修改原链表。
+#[derive(Clone, Debug)]
+pub struct Pairs {
+    rest: List,
+}
+
+impl List {
+    /// Iterate over the elements of the list.
+    /// 迭代链表的各元素。
+    pub fn iter(&self) -> Iter {
+        Iter { rest: self.clone() }
+    }
+
+    /// Iterate over the pairs of the list, starting with the list itself.
+    /// 迭代链表的各个序对（从链表自身开始）。
+    pub fn pairs(&self) -> Pairs {
+        Pairs { rest: self.clone() }
+    }
+}
+
+impl Iterator for Iter {
+    type Item = List;
+
+    fn next(&mut self) -> Option<List> {
+        if !self.rest.is_pair() {
+            return None;
+        }
+        let value = self.rest.head();
+        self.rest = self.rest.tail();
+        Some(value)
+    }
+}
+
+impl Iterator for Pairs {
+    type Item = List;
+
+    fn next(&mut self) -> Option<List> {
+        if !self.rest.is_pair() {
+            return None;
+        }
+        let next = self.rest.tail();
+        Some(std::mem::replace(&mut self.rest, next))
+    }
+}
+
+impl IntoIterator for List {
+    type Item = List;
+    type IntoIter = Iter;
+
+    fn into_iter(self) -> Iter {
+        Iter { rest: self }
+    }
+}
+
+impl IntoIterator for &List {
+    type Item = List;
+    type IntoIter = Iter;
+
+    fn into_iter(self) -> Iter {
+        self.iter()
+    }
+}
+
+impl FromIterator<List> for List {
+    fn from_iter<I: IntoIterator<Item = List>>(iter: I) -> Self {
+        List::from_slice(&iter.into_iter().collect::<Vec<_>>())
+    }
+}
@@ -0,0 +1,196 @@
+// src/list_impl/matching.rs
+//! # Structural Matching
+//!
+//! `match_list!` matches a `List` against list-shaped patterns and binds the parts, replacing
+//! chains such as `exp.tail().tail().head()`. Arms are tried in order; an arm whose shape does
+//! not fit falls through to the next one instead of panicking.
+//! 按结构匹配 `List` 并绑定其中的部分，取代 `tail().tail().head()` 之类的访问链；形状不符时尝试下一分支。
+//!
+//! ## Pattern Syntax
+//! - `(p1, p2, ...)` matches a proper list with exactly these elements.
+//! - `(p1, p2 . rest)` binds the remaining tail to `rest` (`. _` ignores it).
+//! - `name` binds the element as a `List`; `_` ignores it.
+//! - `name: T` requires a value of Rust type `T` and binds a clone of it (`n: i32`, `s: String`).
+//!   The type ends at the next `,` or `.`, so `(op, n: i32 . rest)` works; a type containing a
+//!   top-level comma (`HashMap<K, V>`) needs a type alias.
+//! - A literal (`"+"`, `0`, `true`) must be equal to the element; a string literal only matches
+//!   a `String`, so symbols such as those read by `List::parse` need `sym!(x)` or `sym!("+")`.
+//! - A nested `(...)` matches the element as a list.
+//! - A final `_ => expr` arm catches everything else; without it, a value matching no arm panics.
+//!
+//! ## Examples
+//! ```rust
+//! use sicp_rs::prelude::*;
+//!
+//! fn simplify(exp: &List) -> List {
+//!     match_list!(exp, {
+//!         ("+", 0, x) | ("+", x, 0) => x,
+//!         ("*", a: i32, b: i32) => (a * b).to_listv(),
+//!         (op . args) => List::pair(op, args.map(simplify)),
+//!         _ => exp.clone(),
+//!     })
+//! }
+//!
+//! let exp = list!["*", list!["+", 0, 3], list!["*", 2, 4]];
+//! assert_eq!(simplify(&exp), list!["*", 3, 8]);
+//! assert_eq!(simplify(&list!["*", 2, 4]), 8.to_listv());
+//! ```
+
+/// Match a `List` against list patterns, binding sub-lists and typed values.
+/// 按结构匹配 `List`，绑定子链表与指定类型的值。详见模块文档。
+#[macro_export]
+macro_rules! match_list {
+    ($value:expr, { $($arms:tt)* }) => {{
+        let __value: &$crate::list_impl::List = &$value;
+        $crate::match_list!(@arms __value; $($arms)*)
+    }};
+
+    // ---- arms: 展开为 if let ... else if let ... 链，分支体不处于带标签的块中 ----
+    (@arms $v:ident;) => {
+        $crate::list_impl::panic_with_location("match_list: no pattern matched", $v)
+    };
+    (@arms $v:ident; , $($rest:tt)*) => {
+        $crate::match_list!(@arms $v; $($rest)*)
+    };
+    (@arms $v:ident; _ => $body:expr $(, $($rest:tt)*)?) => {
+        $body
+    };
+    (@arms $v:ident; $(($($pat:tt)*))|+ => $body:block $($rest:tt)*) => {
+        $crate::match_list!(@alts $v; [$(($($pat)*))+] $body; $($rest)*)
+    };
+    (@arms $v:ident; $(($($pat:tt)*))|+ => $body:expr $(, $($rest:tt)*)?) => {
+        $crate::match_list!(@alts $v; [$(($($pat)*))+] $body; $($($rest)*)?)
+    };
+    // 备选模式依次尝试，各自复制一份分支体
+    (@alts $v:ident; [] $body:expr; $($rest:tt)*) => {
+        $crate::match_list!(@arms $v; $($rest)*)
+    };
+    (@alts $v:ident; [($($pat:tt)*) $($alt:tt)*] $body:expr; $($rest:tt)*) => {
+        if let Some($crate::match_list!(@binds [] $($pat)*)) = 'arm: {
+            let __list = $v.clone();
+            $crate::match_list!(@next 'arm, __list; , $($pat)*);
+            Some($crate::match_list!(@binds [] $($pat)*))
+        } {
+            $body
+        } else {
+            $crate::match_list!(@alts $v; [$($alt)*] $body; $($rest)*)
+        }
+    };
+
+    // ---- elements: 逐个匹配元素，失败时以 None 跳出 ----
+    // 一个元素之后是 `,`、`. rest` 或结束
+    (@next $a:lifetime, $c:ident; $(,)?) => {
+        if !$c.is_empty() {
+            break $a None;
+        }
+    };
+    (@next $a:lifetime, $c:ident; $(,)? . _) => {};
+    (@next $a:lifetime, $c:ident; $(,)? . $rest:ident) => {
+        let $rest = $c;
+    };
+    (@next $a:lifetime, $c:ident; , $($more:tt)+) => {
+        $crate::match_list!(@elem $a, $c; $($more)+);
+    };
+    (@elem $a:lifetime, $c:ident; _ $($more:tt)*) => {
+        if !$c.is_pair() {
+            break $a None;
+        }
+        let $c = $c.tail();
+        $crate::match_list!(@next $a, $c; $($more)*);
+    };
+    (@elem $a:lifetime, $c:ident; ($($sub:tt)*) $($more:tt)*) => {
+        let Ok(__sub) = $c.try_head() else {
+            break $a None;
+        };
+        $crate::match_list!(@next $a, __sub; , $($sub)*);
+        let $c = $c.tail();
+        $crate::match_list!(@next $a, $c; $($more)*);
+    };
+    (@elem $a:lifetime, $c:ident; sym!($($s:tt)+) $($more:tt)*) => {
+        match $c.try_head() {
+            Ok(head)
+                if head.try_as_basis_value::<$crate::list_impl::Symbol>()
+                    == Ok(&$crate::sym!($($s)+)) => {}
+            _ => break $a None,
+        }
+        let $c = $c.tail();
+        $crate::match_list!(@next $a, $c; $($more)*);
+    };
+    (@elem $a:lifetime, $c:ident; $lit:literal $($more:tt)*) => {
+        match $c.try_head() {
+            Ok(head) if head == $crate::listv::ListV::to_listv($lit) => (),
+            _ => break $a None,
+        }
+        let $c = $c.tail();
+        $crate::match_list!(@next $a, $c; $($more)*);
+    };
+    (@elem $a:lifetime, $c:ident; $name:ident : $($more:tt)+) => {
+        $crate::match_list!(@typed $a, $c; $name [] $($more)+);
+    };
+    // 类型一直延续到下一个 `,` 或 `.`，因此 `n: i32 . rest` 也能匹配
+    (@typed $a:lifetime, $c:ident; $name:ident [$($ty:tt)+]) => {
+        $crate::match_list!(@bind_typed $a, $c; $name [$($ty)+]);
+    };
+    (@typed $a:lifetime, $c:ident; $name:ident [$($ty:tt)+] , $($more:tt)*) => {
+        $crate::match_list!(@bind_typed $a, $c; $name [$($ty)+] , $($more)*);
+    };
+    (@typed $a:lifetime, $c:ident; $name:ident [$($ty:tt)+] . $($more:tt)*) => {
+        $crate::match_list!(@bind_typed $a, $c; $name [$($ty)+] . $($more)*);
+    };
+    (@typed $a:lifetime, $c:ident; $name:ident [$($ty:tt)*] $t:tt $($more:tt)*) => {
+        $crate::match_list!(@typed $a, $c; $name [$($ty)* $t] $($more)*);
+    };
+    (@bind_typed $a:lifetime, $c:ident; $name:ident [$($ty:tt)+] $($more:tt)*) => {
+        let Ok($name) = $c
+            .try_head()
+            .and_then(|head| head.try_as_basis_value::<$($ty)+>().cloned())
+        else {
+            break $a None;
+        };
+        let $c = $c.tail();
+        $crate::match_list!(@next $a, $c; $($more)*);
+    };
+    (@elem $a:lifetime, $c:ident; $name:ident $($more:tt)*) => {
+        let Ok($name) = $c.try_head() else {
+            break $a None;
+        };
+        let $c = $c.tail();
+        $crate::match_list!(@next $a, $c; $($more)*);
+    };
+
+    // ---- binds: 按出现顺序收集模式中绑定的名字，组成元组 ----
+    (@binds [$($acc:ident)*]) => {
+        ($($acc,)*)
+    };
+    (@binds [$($acc:ident)*] ($($sub:tt)*) $($rest:tt)*) => {
+        $crate::match_list!(@binds [$($acc)*] $($sub)* $($rest)*)
+    };
+    (@binds [$($acc:ident)*] sym!($($s:tt)+) $($rest:tt)*) => {
+        $crate::match_list!(@binds [$($acc)*] $($rest)*)
+    };
+    (@binds [$($acc:ident)*] $lit:literal $($rest:tt)*) => {
+        $crate::match_list!(@binds [$($acc)*] $($rest)*)
+    };
+    (@binds [$($acc:ident)*] $name:ident : $($rest:tt)*) => {
+        $crate::match_list!(@skip_type [$($acc)* $name] $($rest)*)
+    };
+    (@binds [$($acc:ident)*] $name:ident $($rest:tt)*) => {
+        $crate::match_list!(@binds [$($acc)* $name] $($rest)*)
+    };
+    (@binds [$($acc:ident)*] $other:tt $($rest:tt)*) => {
+        $crate::match_list!(@binds [$($acc)*] $($rest)*)
+    };
+    // 类型一直延续到下一个 `,` 或 `.`
+    (@skip_type [$($acc:ident)*]) => {
+        $crate::match_list!(@binds [$($acc)*])
+    };
+    (@skip_type [$($acc:ident)*] , $($rest:tt)*) => {
+        $crate::match_list!(@binds [$($acc)*] $($rest)*)
+    };
+    (@skip_type [$($acc:ident)*] . $($rest:tt)*) => {
+        $crate::match_list!(@binds [$($acc)*] $($rest)*)
+    };
+    (@skip_type [$($acc:ident)*] $t:tt $($rest:tt)*) => {
+        $crate::match_list!(@skip_type [$($acc)*] $($rest)*)
+    };
+}
@@ -11,6 +11,19 @@
 //! - Immutable value wrapping (`List::V`).
 //! - Utility methods for list manipulation, such as `map`, `filter`, `fold_left`, and more.
 //! - Support for comparing lists and values (`PartialEq` and `PartialOrd`).
+//! - Reading Scheme-style text into lists (`List::parse`) and printing them back in
+//!   Scheme notation (`List::to_scheme_string`, `List::pretty_scheme`).
+//! - Rust iterators over elements and pairs (`List::iter`, `List::pairs`), and `collect::<List>()`.
+//! - Fallible accessors (`try_head`, `try_set_tail`, `try_as_basis_value`, ...) returning
+//!   `ListError` instead of panicking.
+//! - `Rc`/`RefCell` by default, `Arc`/`RwLock` with the `multi_threaded` feature (see `Shared`).
+//! - Structural matching of list shapes with `match_list!`.
+//! - Interned `Symbol` values (`sym!(x)`), distinct from strings.
+//! - `Hash`/`Eq` by structure, and `ByIdentity` for keying collections by pair identity.
+//! - Scheme's equality predicates `is_eq`, `is_eqv` and `is_equal`.
+//! - Association lists (`assoc`, `alist_insert`, ...) and property lists (`plist_get`, ...).
+//! - Stable sorting (`sort`, `sort_by`) and `member`, `remove`, `delete_duplicates`.
+//! - `Serialize`/`Deserialize` with the `serde` feature, preserving shared and cyclic structure.
 //!
 //! ## Examples
 //!
@@ -52,26 +65,38 @@
 //! assert_eq!(sum, 15);
 //! ```
 
+mod alist;
+mod cycle;
+mod equality;
+mod error;
+mod hash;
+mod iter;
+mod matching;
+mod printer;
+mod reader;
+#[cfg(feature = "serde")]
+mod serde_impl;
+mod shared;
+mod sort;
+mod symbol;
+pub use error::ListError;
+pub use hash::ByIdentity;
+pub use iter::{Iter, Pairs};
+pub use reader::ParseError;
+pub use shared::{InnerCell, InnerCellExt, MaybeSendSync, Shared};
+pub use symbol::Symbol;
+
 use crate::prelude::ListV;
+use cycle::{Label, Labels};
+use num::BigRational;
 use std::any::TypeId;
-use std::cell::RefCell;
+use std::collections::HashSet;
 use std::fmt;
-use std::rc::Rc;
-// UnsafeCell
-// 预备以后的扩展,例如" 1. Sicp ch3.5中涉及并发, 可修改为 Rc->Arc; 2. 若因为借用检查导致panic RefCell->RwLock
-// #[cfg(feature = "single_threaded")]
-// pub type Shared<T> = Rc<T>;
-// #[cfg(feature = "multi_threaded")]
-// pub type Shared<T> = Arc<T>;
-// #[cfg(feature = "single_threaded")]
-// pub type InnerCell<T> = RefCell<T>;
-// #[cfg(feature = "multi_threaded")]
-// pub type InnerCell<T> = RwLock<T>;
 
 /// Shared reference to a `List` type.
-/// 使用 `Rc<RefCell<List>>` 封装的共享引用。
-/// 在单线程环境下使用 `Rc`，在多线程环境下可扩展为 `Arc`。
-type SharedList = Rc<RefCell<List>>;
+/// 使用 `Shared<InnerCell<List>>` 封装的共享引用。
+/// 在单线程环境下为 `Rc<RefCell<List>>`，启用 `multi_threaded` 特性时为 `Arc<RwLock<List>>`。
+type SharedList = Shared<InnerCell<List>>;
 
 /// Enum representing a recursive list structure.
 /// 定义递归链表结构的枚举。
@@ -79,8 +104,8 @@ type SharedList = Rc<RefCell<List>>;
 pub enum List {
     Cons(SharedList, SharedList),
     // The value is treated as a List type, enabling the construction of nested lists (e.g., list(1, list(1, 2))).
-    // Values of type Rc<dyn ListV> are immutable; instead of modifying them, we create new values and replace the old ones.
-    V(Rc<dyn ListV>),
+    // Values of type Shared<dyn ListV> are immutable; instead of modifying them, we create new values and replace the old ones.
+    V(Shared<dyn ListV>),
     Nil,
 }
 impl Clone for List {
@@ -92,6 +117,28 @@ impl Clone for List {
         }
     }
 }
+/// Dropping a long chain of cells would otherwise recurse once per cell and overflow the stack.
+/// Cells owned only by this list are detached onto an explicit stack before they are freed.
+/// 默认的析构会对每个序对递归一次，长链表会导致栈溢出；这里将独占的子结构移到显式栈上逐个释放。
+impl Drop for List {
+    fn drop(&mut self) {
+        fn detach(l: &mut List, stack: &mut Vec<List>) {
+            if let List::Cons(head, tail) = l {
+                for cell in [head, tail] {
+                    // 仅当该序对不再被其他地方引用时才接管，否则只减少引用计数
+                    if let Some(cell) = Shared::get_mut(cell) {
+                        stack.push(std::mem::replace(cell.get_cell_mut(), List::Nil));
+                    }
+                }
+            }
+        }
+        let mut stack = vec![];
+        detach(self, &mut stack);
+        while let Some(mut l) = stack.pop() {
+            detach(&mut l, &mut stack);
+        }
+    }
+}
 
 impl List {
     /// 获取 `List` 的唯一标识符。
@@ -100,37 +147,72 @@ impl List {
     /// 对于 `Cons` 和 `V` 变体，返回其内部共享指针的地址。
     /// For `Cons` and `V` variants, return the address of their internal shared pointer.
     ///
-    /// 对于 `Nil` 变体，直接返回其自身的地址。
-    /// For the `Nil` variant, directly return its own address.
+    /// 所有 `Nil` 都是同一个对象，标识符固定为 0。
+    /// All `Nil`s are the same object, with identifier 0.
     pub fn unique_id(&self) -> u64 {
         match self {
             // 提取 `SharedList` 的指针地址
             // Extract the pointer address of `SharedList`
             List::Cons(a, b) => {
-                let a = Rc::as_ptr(a) as u64;
-                let b = Rc::as_ptr(b) as u64;
+                let a = Shared::as_ptr(a) as u64;
+                let b = Shared::as_ptr(b) as u64;
                 a.wrapping_mul(31).wrapping_add(b)
             }
 
-            // 提取 `Rc<dyn ListV>` 的指针地址
-            // Extract the pointer address of `Rc<dyn ListV>`
-            List::V(v) => Rc::as_ptr(v) as *const () as u64,
+            // 提取 `Shared<dyn ListV>` 的指针地址
+            // Extract the pointer address of `Shared<dyn ListV>`
+            List::V(v) => Shared::as_ptr(v) as *const () as u64,
 
-            // 对于 `Nil`，直接返回其自身的地址
-            // For `Nil`, directly return its own address
-            List::Nil => self as *const _ as u64,
+            // `Nil` 没有分配，所有 `Nil` 共用同一个标识符
+            // `Nil` has no allocation; every `Nil` shares one identifier
+            List::Nil => 0,
         }
     }
     /// Extract an immutable reference from a `SharedList`.
     /// 从 `SharedList` 中提取不可变引用。
     pub fn extract_clone(sl: &SharedList) -> List {
-        (*sl.borrow()).clone()
+        (*sl.read_cell()).clone()
     }
 
     /// Modify the content of a `SharedList`.
     /// 修改 `SharedList` 的内容。
     fn replace_list(old: &SharedList, new_list: List) {
-        *old.borrow_mut() = new_list;
+        *old.write_cell() = new_list;
+    }
+
+    /// Walk the tail chain with a loop, returning the elements and the final tail
+    /// (`Nil` for a proper list, `V` for a list not ending with `Nil`).
+    /// 以循环沿尾部遍历，返回各元素及最终的尾部，供各组合子以常数栈空间实现。
+    ///
+    /// # Errors
+    /// 若尾部成环，返回 `ListError::ImproperList`，而不是无限分配内存。
+    fn try_spine(&self, op: &'static str) -> Result<(Vec<List>, List), ListError> {
+        let mut items = vec![];
+        let mut rest = self.clone();
+        // slow 每两步前进一步，若被 rest 追上说明尾部成环
+        let mut slow = self.clone();
+        while let List::Cons(value, next) = &rest {
+            items.push(List::extract_clone(value));
+            let next = List::extract_clone(next);
+            rest = next;
+            if items.len() % 2 == 0 {
+                slow = slow.tail();
+                if rest.is_pair() && slow.is_eq(&rest) {
+                    return Err(ListError::ImproperList {
+                        op,
+                        found: self.to_string(),
+                    });
+                }
+            }
+        }
+        Ok((items, rest))
+    }
+    /// Like `try_spine`, but panics on a cyclic tail.
+    /// 与 `try_spine` 相同，但尾部成环时触发 panic。
+    fn spine(&self, op: &'static str) -> (Vec<List>, List) {
+        self.try_spine(op).unwrap_or_else(|_| {
+            panic_with_location(&format!("{} called on a cyclic list", op), self)
+        })
     }
     /// Create a pair of two lists.
     /// 创建两个链表的配对。
@@ -142,7 +224,10 @@ impl List {
     /// assert!(pair.is_pair());
     /// ```
     pub fn pair(a: List, b: List) -> List {
-        List::Cons(Rc::new(RefCell::new(a)), Rc::new(RefCell::new(b)))
+        List::Cons(
+            Shared::new(InnerCell::new(a)),
+            Shared::new(InnerCell::new(b)),
+        )
     }
     /// Check if the list is a pair.
     /// 检查链表是否是配对结构。
@@ -168,10 +253,8 @@ impl List {
     /// # Panics
     /// 如果调用者不是链表结构，将会触发 `unreachable!`。
     pub fn head(&self) -> List {
-        match self {
-            List::Cons(current, _) => List::extract_clone(current),
-            _ => unreachable_with_location("Only list can call head", &self),
-        }
+        self.try_head()
+            .unwrap_or_else(|_| unreachable_with_location("Only list can call head", &self))
     }
 
     /// Get the tail of the list.
@@ -180,10 +263,8 @@ impl List {
     /// # Panics
     /// 如果调用者不是链表结构，将会触发 `unreachable!`。
     pub fn tail(&self) -> List {
-        match self {
-            List::Cons(_, next) => List::extract_clone(next),
-            _ => unreachable_with_location("Only list can call tail", &self),
-        }
+        self.try_tail()
+            .unwrap_or_else(|_| unreachable_with_location("Only list can call tail", &self))
     }
     /// Modify the head of the list.
     /// 修改链表的头部。
@@ -191,10 +272,8 @@ impl List {
     /// # Panics
     /// 如果调用者不是链表结构，将会触发 `unreachable!`。
     pub fn set_head(&self, new_head: List) {
-        match self {
-            List::Cons(current, _) => List::replace_list(current, new_head),
-            _ => unreachable_with_location("Only list can call set_head", &self),
-        }
+        self.try_set_head(new_head)
+            .unwrap_or_else(|_| unreachable_with_location("Only list can call set_head", &self))
     }
 
     /// Modify the tail of the list.
@@ -203,31 +282,125 @@ impl List {
     /// # Panics
     /// 如果调用者不是链表结构，将会触发 `unreachable!`。
     pub fn set_tail(&self, new_tail: List) {
+        self.try_set_tail(new_tail)
+            .unwrap_or_else(|_| unreachable_with_location("Only list can call set_tail", &self))
+    }
+
+    /// Name of the variant, used by errors on pairs so that building them does not format the
+    /// whole list.
+    /// 变体名称；对序对报错时只记录名称，避免格式化整个链表。
+    fn variant_name(&self) -> &'static str {
+        match self {
+            List::Cons(_, _) => "pair",
+            List::V(_) => "value",
+            List::Nil => "nil",
+        }
+    }
+
+    /// The error for an operation that needs a pair.
+    /// 需要序对的操作在非序对上调用时的错误。
+    fn not_a_pair(&self, op: &'static str) -> ListError {
+        match self {
+            List::Nil => ListError::EmptyList { op },
+            _ => ListError::WrongVariant {
+                op,
+                expected: "pair",
+                found: self.to_string(),
+            },
+        }
+    }
+
+    /// Get the head of the list, or an error if it is not a pair.
+    /// 获取链表的头部；若不是序对则返回错误。
+    pub fn try_head(&self) -> Result<List, ListError> {
         match self {
-            List::Cons(_, next) => List::replace_list(next, new_tail),
-            _ => unreachable_with_location("Only list can call set_tail", &self),
+            List::Cons(current, _) => Ok(List::extract_clone(current)),
+            _ => Err(self.not_a_pair("head")),
+        }
+    }
+
+    /// Get the tail of the list, or an error if it is not a pair.
+    /// 获取链表的尾部；若不是序对则返回错误。
+    pub fn try_tail(&self) -> Result<List, ListError> {
+        match self {
+            List::Cons(_, next) => Ok(List::extract_clone(next)),
+            _ => Err(self.not_a_pair("tail")),
+        }
+    }
+
+    /// Modify the head of the list, or return an error if it is not a pair.
+    /// 修改链表的头部；若不是序对则返回错误。
+    pub fn try_set_head(&self, new_head: List) -> Result<(), ListError> {
+        match self {
+            List::Cons(current, _) => {
+                List::replace_list(current, new_head);
+                Ok(())
+            }
+            _ => Err(self.not_a_pair("set_head")),
+        }
+    }
+
+    /// Modify the tail of the list, or return an error if it is not a pair.
+    /// 修改链表的尾部；若不是序对则返回错误。
+    pub fn try_set_tail(&self, new_tail: List) -> Result<(), ListError> {
+        match self {
+            List::Cons(_, next) => {
+                List::replace_list(next, new_tail);
+                Ok(())
+            }
+            _ => Err(self.not_a_pair("set_tail")),
         }
     }
     pub fn last_pair(&self) -> List {
-        if self.tail().is_empty() {
-            self.clone()
-        } else {
-            self.tail().last_pair()
+        let (items, _) = self.tail().spine("last_pair");
+        let mut current = self.clone();
+        for _ in 0..items.len() {
+            current = current.tail();
         }
+        current
     }
     /// Wrap a value as `List::V`.
     /// 将基础值封装为 `List::V`。
     pub fn wrap_as_list_value<T: ListV>(v: T) -> List {
-        List::V(Rc::new(v))
+        List::V(Shared::new(v))
+    }
+    /// Wrap an exact number, narrowing integers to `i32`, `i64`, `i128` or `BigInt` as the
+    /// reader does.
+    /// 封装精确数值；整数按读取器的规则收窄为 `i32`、`i64`、`i128` 或 `BigInt`。
+    pub fn from_rational(r: BigRational) -> List {
+        if !r.is_integer() {
+            return r.to_listv();
+        }
+        let i = r.to_integer();
+        if let Ok(i) = i32::try_from(&i) {
+            i.to_listv()
+        } else if let Ok(i) = i64::try_from(&i) {
+            i.to_listv()
+        } else if let Ok(i) = i128::try_from(&i) {
+            i.to_listv()
+        } else {
+            i.to_listv()
+        }
     }
     /// 非基础值无需封装，直接返回自身
     pub fn to_listv(self) -> List {
         self
     }
-    pub fn get_basis_value(&self) -> Rc<dyn ListV> {
+    pub fn get_basis_value(&self) -> Shared<dyn ListV> {
+        self.try_get_basis_value().unwrap_or_else(|_| {
+            unreachable_with_location("Only List::V(_) can call get_basis_value", &self)
+        })
+    }
+    /// Get the wrapped value, or an error if the list is not a `List::V`.
+    /// 获取封装的基础值；若不是 `List::V` 则返回错误。
+    pub fn try_get_basis_value(&self) -> Result<Shared<dyn ListV>, ListError> {
         match self {
-            List::V(v) => v.clone(),
-            _ => unreachable_with_location("Only List::V(_) can call get_basis_value", &self),
+            List::V(v) => Ok(v.clone()),
+            _ => Err(ListError::WrongVariant {
+                op: "get_basis_value",
+                expected: "value",
+                found: self.variant_name().to_string(),
+            }),
         }
     }
     pub fn is_number_value(&self) -> bool {
@@ -236,6 +409,9 @@ impl List {
     pub fn is_string_value(&self) -> bool {
         self.is_value() && self.get_basis_value().as_ref().is_string()
     }
+    pub fn is_symbol(&self) -> bool {
+        self.is_value() && self.get_basis_value().as_ref().is_symbol()
+    }
     pub fn is_float_value(&self) -> bool {
         self.is_value() && self.get_basis_value().as_ref().is_float()
     }
@@ -244,20 +420,26 @@ impl List {
     }
     pub fn try_as_basis_value<T: Clone + std::fmt::Debug + 'static>(
         &self,
-    ) -> Result<&T, &'static str> {
+    ) -> Result<&T, ListError> {
         match self {
             List::V(v) => {
-                // 必须首先as_ref()从Rc中解出dyn ListV,才是正确的Any类型,从而正确解出类型T的值
+                // 必须首先as_ref()从Shared中解出dyn ListV,才是正确的Any类型,从而正确解出类型T的值
                 let any = v.as_ref().as_any();
 
                 if let Some(value) = any.downcast_ref::<T>() {
                     Ok(value)
                 } else {
-                    Err("Type mismatch")
+                    Err(ListError::TypeMismatch {
+                        expected: std::any::type_name::<T>(),
+                        found: v.as_string(),
+                    })
                 }
             }
-            List::Nil => Err("Cannot call try_as_basis_value on List::Nil"),
-            List::Cons(_, _) => Err("Cannot call try_as_basis_value on List::Cons"),
+            _ => Err(ListError::WrongVariant {
+                op: "try_as_basis_value",
+                expected: "value",
+                found: self.variant_name().to_string(),
+            }),
         }
     }
     pub fn get_type_id(&self) -> Option<TypeId> {
@@ -284,25 +466,19 @@ impl List {
 
     // 传入Iterator<Item = List<T>>类型,以确保既可以传入[V(1),V(2)],也可以传入[List1,List2]
     pub fn from_iterator<I: Iterator<Item = List>>(items: &mut I) -> Self {
-        match items.next() {
-            Some(v) => List::pair(v.clone(), List::from_iterator(items)),
-            None => List::Nil,
-        }
+        items.collect()
     }
     /// Append another list to the current list.
     /// 将另一个链表追加到当前链表。
     pub fn append(&self, other: &Self) -> Self {
-        match self {
-            List::Nil => (*other).clone(),
-            List::Cons(value, next) => Self::pair(
-                List::extract_clone(value),
-                List::extract_clone(next).append(other),
-            ),
-            List::V(_) => {
-                eprintln!("self is a value, not a list, convert it to list");
-                Self::pair((*self).clone(), Self::Nil).append(other)
-            }
+        let (mut items, last) = self.spine("append");
+        if last.is_value() {
+            eprintln!("self is a value, not a list, convert it to list");
+            items.push(last);
         }
+        items
+            .into_iter()
+            .rfold(other.clone(), |acc, item| List::pair(item, acc))
     }
     pub fn append_mutator(&self, y: List) -> List {
         assert!(!self.is_empty(), "append_mutator cannot work on empty pair");
@@ -313,36 +489,28 @@ impl List {
     where
         F: Fn(&List) -> List,
     {
-        match self {
-            List::Nil => List::Nil,
-            List::Cons(value, next) => List::pair(
-                fun(&List::extract_clone(value)),
-                List::extract_clone(next).map(fun),
-            ),
-            List::V(_) => fun(self),
-        }
+        let (items, last) = self.spine("map");
+        let mapped: Vec<List> = items.iter().map(&fun).collect();
+        let last = if last.is_value() { fun(&last) } else { last };
+        mapped
+            .into_iter()
+            .rfold(last, |acc, item| List::pair(item, acc))
     }
     pub fn accumulate<F, U>(&self, fun: F, initial: U) -> U
     where
         F: Fn(&List, U) -> U + Clone,
     {
-        match self {
-            List::Nil => initial,
-            List::Cons(value, next) => {
-                let f = fun.clone();
-                fun(
-                    &List::extract_clone(value),
-                    List::extract_clone(next).accumulate(f, initial),
-                )
-            }
-            List::V(_) => {
-                //unreachable_with_location("Accumulate only accept list", &self),
-                eprintln!(
-                    "Warning: accumulate only accepts list, not value. May caused by pair construct not ending with Nil."
-                );
-                fun(self, initial)
-            }
+        let (mut items, last) = self.spine("accumulate");
+        if last.is_value() {
+            eprintln!(
+                "Warning: accumulate only accepts list, not value. May caused by pair construct not ending with Nil."
+            );
+            items.push(last);
         }
+        items
+            .iter()
+            .rev()
+            .fold(initial, |result, current| fun(current, result))
     }
     pub fn filter<F>(&self, fun: F) -> List
     where
@@ -366,143 +534,230 @@ impl List {
         self.map(fun)
             .accumulate(|current, result| current.append(&result), List::Nil)
     }
-    pub fn for_each<F>(&self, fun: F) -> ()
+    pub fn for_each<F>(&self, fun: F)
     where
-        F: Fn(&List) -> (),
+        F: Fn(&List),
     {
-        match self {
-            List::Nil => (),
-            List::Cons(value, next) => {
-                fun(&List::extract_clone(value));
-                List::extract_clone(next).for_each(fun)
-            }
-            List::V(_) => fun(self),
-        };
+        let (items, last) = self.spine("for_each");
+        items.iter().for_each(&fun);
+        if last.is_value() {
+            fun(&last);
+        }
     }
     pub fn fold_left<U, F>(&self, fun: F, initial: U) -> U
     where
         F: Fn(U, &List) -> U + Clone,
     {
-        fn iter<U, F>(op: F, result: U, rest: &List) -> U
-        where
-            F: Fn(U, &List) -> U + Clone,
-        {
-            match rest {
-                List::Nil => result,
-                List::Cons(value, next) => iter(
-                    op.clone(),
-                    op(result, &List::extract_clone(value)),
-                    &List::extract_clone(next),
-                ),
-                List::V(_) => {
-                    // unreachable_with_location("Flod_left only accept list", &rest)},
-                    eprintln!(
-                        "Warning: fold_left only accepts list, not value. May caused by pair construct not ending with Nil."
-                    );
-                    op(result, &rest)
-                }
-            }
+        let (items, last) = self.spine("fold_left");
+        let result = items.iter().fold(initial, &fun);
+        if last.is_value() {
+            eprintln!(
+                "Warning: fold_left only accepts list, not value. May caused by pair construct not ending with Nil."
+            );
+            fun(result, &last)
+        } else {
+            result
         }
-        iter(fun, initial, self)
     }
     pub fn accumulate_n<F>(&self, op: F, initial: List) -> List
     where
         F: Fn(&List, List) -> List + Clone,
     {
-        match self.head() {
-            List::Nil => List::Nil,
-            List::Cons(_, _) => {
-                let l1 = self
-                    .map(|y| (y.head()).clone())
-                    .accumulate(op.clone(), initial.clone());
-                let l2 = self
-                    .map(|y| (y.tail()).clone())
-                    .accumulate_n(op.clone(), initial.clone());
-                List::pair(l1, l2)
-            }
-            List::V(_) => {
-                //unreachable_with_location("Accumulate_n only accept list", &self)
-                panic!(
-                    "Error: accumulate_n only accepts list, not value. May caused by pair construct not ending with Nil."
-                );
-            }
-        }
+        self.try_accumulate_n(op, initial)
+            .unwrap_or_else(|e| panic_with_location(&format!("Error: {}", e), self))
     }
-    fn reverse_with<F: Fn(&List) -> List>(&self, fun: F) -> Self {
-        fn reverse_with_iter<F>(l: &List, result: List, fun: F) -> List
-        where
-            F: Fn(&List) -> List,
-        {
-            match l {
-                List::Nil => result,
-                List::Cons(value, _) => {
-                    let value = fun(&List::extract_clone(value));
-                    reverse_with_iter(&l.tail(), List::pair(value, result), fun)
+    /// Like `accumulate_n`, but returns an error instead of panicking when `self` is not a
+    /// non-empty list of equally long lists.
+    /// 与 `accumulate_n` 相同，但输入不是等长链表组成的非空链表时返回错误。
+    pub fn try_accumulate_n<F>(&self, op: F, initial: List) -> Result<List, ListError>
+    where
+        F: Fn(&List, List) -> List + Clone,
+    {
+        let mut columns = vec![];
+        let mut rows = self.clone();
+        loop {
+            match rows.try_head()? {
+                List::Nil => break,
+                List::Cons(_, _) => {
+                    let heads = rows
+                        .iter()
+                        .map(|y| y.try_head())
+                        .collect::<Result<List, _>>()?;
+                    columns.push(heads.accumulate(op.clone(), initial.clone()));
+                    rows = rows
+                        .iter()
+                        .map(|y| y.try_tail())
+                        .collect::<Result<List, _>>()?;
+                }
+                List::V(_) => {
+                    return Err(ListError::WrongVariant {
+                        op: "accumulate_n",
+                        expected: "list of lists",
+                        found: self.to_string(),
+                    });
                 }
-                List::V(_) => panic!("reverse_with_iter only accept list, not value"),
             }
         }
-        reverse_with_iter(self, List::Nil, fun)
+        Ok(List::from_slice(&columns))
+    }
+    fn try_reverse_with<F: Fn(&List) -> Result<List, ListError>>(
+        &self,
+        op: &'static str,
+        fun: F,
+    ) -> Result<List, ListError> {
+        let (items, last) = self.try_spine(op)?;
+        if last.is_value() {
+            return Err(ListError::ImproperList {
+                op,
+                found: self.to_string(),
+            });
+        }
+        items.iter().try_fold(List::Nil, |result, value| {
+            Ok(List::pair(fun(value)?, result))
+        })
     }
     pub fn reverse(&self) -> Self {
-        self.reverse_with(|x| (*x).clone())
+        self.try_reverse()
+            .unwrap_or_else(|e| panic_with_location(&e.to_string(), self))
     }
     pub fn deep_reverse(&self) -> Self {
-        self.reverse_with(|x| match (*x).clone() {
-            List::Cons(_, _) => x.deep_reverse(),
-            List::V(t) => List::V(t),
-            List::Nil => List::Nil,
+        self.try_deep_reverse()
+            .unwrap_or_else(|e| panic_with_location(&e.to_string(), self))
+    }
+    /// Reverse the list, or return an error if it is improper or cyclic.
+    /// 反转链表；若链表不以 `Nil` 结尾或尾部成环则返回错误。
+    pub fn try_reverse(&self) -> Result<List, ListError> {
+        self.try_reverse_with("reverse", |x| Ok(x.clone()))
+    }
+    /// Reverse the list and every nested list, or return an error if any of them is improper.
+    /// 递归反转链表及其中的子链表；若其中有不以 `Nil` 结尾的链表则返回错误。
+    pub fn try_deep_reverse(&self) -> Result<List, ListError> {
+        self.try_reverse_with("deep_reverse", |x| match x {
+            List::Cons(_, _) => x.try_deep_reverse(),
+            _ => Ok(x.clone()),
         })
     }
+    /// Count the cells along the tail; a non-`Nil` final tail counts as one more element.
+    /// 沿尾部计数；若链表不以 `Nil` 结尾，末尾的值也计为一个元素。
+    ///
+    /// # Panics
+    /// 若尾部成环（例如练习 3.13 的 `make_cycle`），将会触发 panic 而不是无限循环；
+    /// 不希望 panic 时使用 `try_length`。
     pub fn length(&self) -> usize {
-        match self {
-            List::Nil => 0,
-            List::Cons(_, next) => 1 + List::extract_clone(next).length(),
-            List::V(_) => 1,
+        self.try_length()
+            .unwrap_or_else(|_| panic_with_location("length called on a cyclic list", &self))
+    }
+    /// Like `length`, but returns `ListError::ImproperList` for a cyclic tail.
+    /// 与 `length` 相同，但尾部成环时返回 `ListError::ImproperList`。
+    pub fn try_length(&self) -> Result<usize, ListError> {
+        // Floyd 判圈：fast 每次走两步，slow 走一步，二者相遇说明尾部成环
+        let mut slow = self.clone();
+        let mut fast = self.clone();
+        let mut n = 0;
+        loop {
+            for _ in 0..2 {
+                match &fast {
+                    List::Nil => return Ok(n),
+                    List::V(_) => return Ok(n + 1),
+                    List::Cons(_, next) => {
+                        let next = List::extract_clone(next);
+                        fast = next;
+                        n += 1;
+                    }
+                }
+            }
+            slow = slow.tail();
+            if fast.is_pair() && slow.is_eq(&fast) {
+                return Err(ListError::ImproperList {
+                    op: "length",
+                    found: self.to_string(),
+                });
+            }
         }
     }
     pub fn find_index(&self, x: &List) -> Option<usize> {
-        if self.head().is_empty() {
-            None
-        } else if self.head() == *x {
-            Some(0)
-        } else {
-            let n = self.tail().find_index(x);
-            if let Some(n) = n { Some(n + 1) } else { None }
-        }
+        let (items, _) = self.spine("find_index");
+        items.iter().position(|item| item == x)
     }
+    /// Count the values in the whole tree; shared sub-lists are counted each time they appear.
+    /// 统计整棵树中值的个数，共享的子结构按出现次数重复计数。
+    ///
+    /// # Panics
+    /// 若结构中存在环，将会触发 panic；不希望 panic 时使用 `try_deep_length`。
     pub fn deep_length(&self) -> usize {
-        match self {
-            List::Nil => 0,
-            List::Cons(current, next) => {
-                List::extract_clone(current).deep_length() + List::extract_clone(next).deep_length()
+        self.try_deep_length()
+            .unwrap_or_else(|_| panic_with_location("deep_length called on a cyclic list", &self))
+    }
+    /// Like `deep_length`, but returns `ListError::ImproperList` if any cell is reachable from
+    /// itself.
+    /// 与 `deep_length` 相同，但结构中存在环时返回 `ListError::ImproperList`。
+    pub fn try_deep_length(&self) -> Result<usize, ListError> {
+        if self.is_cyclic() {
+            return Err(ListError::ImproperList {
+                op: "deep_length",
+                found: self.to_string(),
+            });
+        }
+        let mut n = 0;
+        let mut stack = vec![self.clone()];
+        while let Some(l) = stack.pop() {
+            match &l {
+                List::Nil => (),
+                List::V(_) => n += 1,
+                List::Cons(current, next) => {
+                    stack.push(List::extract_clone(next));
+                    stack.push(List::extract_clone(current));
+                }
             }
-            List::V(_) => 1,
         }
+        Ok(n)
     }
+    /// Print a list as `(a, b, (c, d))`; cycles are shown with datum labels.
+    /// 以逗号分隔的形式打印链表，循环结构以数据标签表示。
     pub fn pretty_print(&self) -> String {
-        format!(
-            "({}",
-            self.accumulate(
-                |current, result| {
-                    let print_str = if current.is_empty() {
-                        // accumulate never return empty list, do nothing
-                        "".to_string()
-                    } else if current.is_pair() {
-                        current.pretty_print()
-                    } else {
-                        current.to_string()
-                    };
-                    //println!("current value:{} print_str {}", current, print_str);
-                    if result.starts_with(")") || result == "" {
-                        format!("{}{}", print_str, result)
-                    } else {
-                        format!("{}, {}", print_str, result)
-                    }
-                },
-                ")".to_string(),
-            )
-        )
+        fn iter(l: &List, labels: &mut Labels) -> String {
+            let prefix = match labels.visit(l) {
+                Label::Ref(n) => return format!("#{}#", n),
+                Label::Define(n) => format!("#{}=", n),
+                Label::None => "".to_string(),
+            };
+            let mut items = vec![];
+            let mut rest = l.clone();
+            loop {
+                let current = rest.head();
+                items.push(if current.is_empty() {
+                    "".to_string()
+                } else if current.is_pair() {
+                    iter(&current, labels)
+                } else {
+                    current.to_string()
+                });
+                rest = rest.tail();
+                if !rest.is_pair() || labels.is_target(&rest) {
+                    break;
+                }
+            }
+            let mut result = ")".to_string();
+            if rest.is_pair() {
+                result = format!(" . {})", iter(&rest, labels));
+            } else if rest.is_value() {
+                // 不以 Nil 结尾时，末尾的值按普通元素打印
+                items.push(rest.to_string());
+            }
+            for print_str in items.iter().rev() {
+                result = if result.starts_with(')') || result.starts_with(" . ") {
+                    format!("{}{}", print_str, result)
+                } else {
+                    format!("{}, {}", print_str, result)
+                };
+            }
+            format!("{}({}", prefix, result)
+        }
+        match self {
+            List::Cons(_, _) => iter(self, &mut Labels::cycles(self)),
+            List::V(_) => format!("({})", self),
+            List::Nil => "()".to_string(),
+        }
     }
 }
 
@@ -529,47 +784,108 @@ pub fn panic_with_location(message: &str, self_repr: &impl std::fmt::Display) ->
 pub fn apply_in_underlying_rust(prim: impl Fn(&List) -> List, arglist: &List) -> List {
     prim(arglist)
 }
+/// Prints the nested-tuple form `(1, (2, Nil))`; cycles are shown with datum labels,
+/// e.g. `#0=(1, #0#)`.
+/// 以嵌套元组形式打印，循环结构以数据标签表示。
 impl fmt::Display for List {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match self {
-            List::Cons(v, next) => {
-                write!(
-                    f,
-                    "({}, {})",
-                    List::extract_clone(v),
-                    List::extract_clone(next)
-                )
-            }
-            List::V(t) => {
-                write!(f, "{}", t.as_ref().as_string())
+        enum Work {
+            Datum(List),
+            Text(&'static str),
+        }
+        let mut labels = Labels::cycles(self);
+        let mut stack = vec![Work::Datum(self.clone())];
+        while let Some(work) = stack.pop() {
+            match work {
+                Work::Text(s) => f.write_str(s)?,
+                Work::Datum(List::V(ref t)) => write!(f, "{}", t.as_ref().as_string())?,
+                Work::Datum(List::Nil) => write!(f, "Nil")?,
+                Work::Datum(cell) => {
+                    match labels.visit(&cell) {
+                        Label::Ref(n) => {
+                            write!(f, "#{}#", n)?;
+                            continue;
+                        }
+                        Label::Define(n) => write!(f, "#{}=", n)?,
+                        Label::None => (),
+                    }
+                    f.write_str("(")?;
+                    stack.push(Work::Text(")"));
+                    stack.push(Work::Datum(cell.tail()));
+                    stack.push(Work::Text(", "));
+                    stack.push(Work::Datum(cell.head()));
+                }
             }
-
-            List::Nil => write!(f, "Nil"),
         }
+        Ok(())
     }
 }
 
+/// Pairs compared before the lists are checked for cycles; comparisons of acyclic lists that
+/// finish sooner never allocate the set of compared pairs.
+/// 比较的序对数超过该值时才检查是否有环，较小的无环链表比较时无需记录已比较的序对。
+const CYCLE_CHECK_PAIRS: usize = 1 << 10;
+
+/// Walks `a` and `b` in step, head before tail, until `values` reports a result for two values
+/// or the shapes differ (`mismatch`); returns `None` if the walk completes. Once either list
+/// turns out to be cyclic, pairs of cells already being compared are assumed equal, so the
+/// walk terminates.
+/// 同步遍历两个链表；确认有环后，已在比较中的序对组合视为相等，因此遍历总会终止。
+fn compare_in_step<R>(
+    a: &List,
+    b: &List,
+    mismatch: R,
+    mut values: impl FnMut(&dyn ListV, &dyn ListV) -> Option<R>,
+) -> Option<R> {
+    let mut seen = None;
+    let mut pairs = 0;
+    let mut stack = vec![(a.clone(), b.clone())];
+    while let Some((x, y)) = stack.pop() {
+        match (&x, &y) {
+            (List::Nil, List::Nil) => (),
+            (List::V(x1), List::V(y1)) => {
+                if let Some(result) = values(x1.as_ref(), y1.as_ref()) {
+                    return Some(result);
+                }
+            }
+            (List::Cons(_, _), List::Cons(_, _)) => {
+                pairs += 1;
+                if pairs == CYCLE_CHECK_PAIRS && (a.is_cyclic() || b.is_cyclic()) {
+                    // 此前比较过的部分均相等，记录已比较的序对后从头开始
+                    seen = Some(HashSet::new());
+                    stack = vec![(a.clone(), b.clone())];
+                    continue;
+                }
+                if let Some(seen) = &mut seen
+                    && !seen.insert((x.identity(), y.identity()))
+                {
+                    continue;
+                }
+                stack.push((x.tail(), y.tail()));
+                stack.push((x.head(), y.head()));
+            }
+            _ => return Some(mismatch),
+        }
+    }
+    None
+}
+/// Structural equality. Pairs of cells already being compared are assumed equal, so
+/// comparing cyclic structures terminates (as Scheme's `equal?` does).
+/// 结构相等；已在比较中的序对组合视为相等，因此比较循环结构也会终止。
 impl PartialEq for List {
     fn eq(&self, other: &Self) -> bool {
-        match (self, other) {
-            (List::Nil, List::Nil) => true,
-            (List::Cons(x1, x2), List::Cons(y1, y2)) => x1 == y1 && x2 == y2,
-            (List::V(x1), List::V(y1)) => x1.as_ref() == y1.as_ref(),
-            _ => false,
-        }
+        compare_in_step(self, other, (), |x, y| (x != y).then_some(())).is_none()
     }
 }
+/// Lexicographic order, head before tail; cycles are handled as in `PartialEq`.
+/// 先比较头部再比较尾部的字典序，循环结构的处理与 `PartialEq` 相同。
 impl PartialOrd for List {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        match (self, other) {
-            (List::Nil, List::Nil) => Some(std::cmp::Ordering::Equal),
-            (List::Cons(x1, x2), List::Cons(y1, y2)) => match x1.partial_cmp(y1) {
-                Some(std::cmp::Ordering::Equal) => x2.partial_cmp(y2),
-                ord => ord,
-            },
-            (List::V(x1), List::V(y1)) => x1.as_ref().partial_cmp(y1.as_ref()),
-            _ => None,
-        }
+        compare_in_step(self, other, None, |x, y| match x.partial_cmp(y) {
+            Some(std::cmp::Ordering::Equal) => None,
+            ord => Some(ord),
+        })
+        .unwrap_or(Some(std::cmp::Ordering::Equal))
     }
 }
 /// Macro for creating a list from values.
@@ -595,19 +911,24 @@ macro_rules! pair {
     };
 }
 
+#[cfg(not(feature = "multi_threaded"))]
+type ClosureFn = Shared<dyn Fn(&List) -> Option<List>>;
+#[cfg(feature = "multi_threaded")]
+type ClosureFn = Shared<dyn Fn(&List) -> Option<List> + Send + Sync>;
+
 /// 用于包装闭包类型，实现 Debug+Clone trait & 类型擦除，从而支持List存储与取出并解析值
 pub struct ClosureWrapper {
-    func: Rc<dyn Fn(&List) -> Option<List>>,
+    func: ClosureFn,
 }
 
 impl ClosureWrapper {
     // 创建一个新的 ClosureWrapper
     pub fn new<F>(func: F) -> Self
     where
-        F: Fn(&List) -> Option<List> + 'static,
+        F: Fn(&List) -> Option<List> + MaybeSendSync + 'static,
     {
         ClosureWrapper {
-            func: Rc::new(func),
+            func: Shared::new(func),
         }
     }
 
@@ -621,7 +942,7 @@ impl ClosureWrapper {
 impl Clone for ClosureWrapper {
     fn clone(&self) -> Self {
         ClosureWrapper {
-            func: Rc::clone(&self.func),
+            func: Shared::clone(&self.func),
         }
     }
 }
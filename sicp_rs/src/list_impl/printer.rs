@@ -0,0 +1,354 @@
+// src/list_impl/printer.rs
+//! # Scheme Printer
+//!
+//! Print a `List` in the book's notation, `(1 2 3)` and `(1 . 2)`, as an alternative to the
+//! nested-tuple `Display`. The output is accepted by `List::parse`, so printed data can be
+//! stored and reloaded.
+//! 以 SICP 书中的记法打印 `List`，输出可被 `List::parse` 读回。
+//!
+//! ## Conventions
+//! - `String` values are printed as quoted strings: `"hello"`.
+//! - `&str` values are strings too, and read back as `String`.
+//! - `Symbol` values are printed bare: `hello`. Names that would not read back as a symbol are
+//!   wrapped in bars: `|two words|`.
+//! - `bool` is printed as `#t`/`#f`, `List::Nil` as `()`.
+//! - `(quote x)` is abbreviated to `'x`.
+//!
+//! ## Examples
+//! ```rust
+//! use sicp_rs::prelude::*;
+//!
+//! let l = list![1, sym!(x), "text".to_string(), pair![2, 3], List::Nil];
+//! assert_eq!(l.to_scheme_string(), r#"(1 x "text" (2 . 3) ())"#);
+//! assert_eq!(List::parse(&l.to_scheme_string()).unwrap(), l);
+//!
+//! let deriv = List::parse("(+ (* x y) (* y (+ x 3)))").unwrap();
+//! assert_eq!(
+//!     deriv.pretty_scheme(20),
+//!     "(+ (* x y)\n   (* y (+ x 3)))"
+//! );
+//! ```
+
+use std::fmt::Write;
+
+use super::cycle::{Label, Labels};
+use super::reader::{is_delimiter, parse_atom};
+use super::{List, Symbol};
+use crate::prelude::ListV;
+
+impl List {
+    /// Print the list on one line in Scheme notation.
+    /// Cells that form a cycle are printed with datum labels: `#0=(a b . #0#)`.
+    /// 以 Scheme 记法单行打印链表，循环结构以数据标签表示。
+    pub fn to_scheme_string(&self) -> String {
+        let mut out = String::new();
+        write_flat(self, &mut Labels::cycles(self), &mut out, None);
+        out
+    }
+
+    /// Like `to_scheme_string`, but labels every cell that is referenced more than once,
+    /// so shared structure is visible: `(#0=(a) #0#)`.
+    /// 与 `to_scheme_string` 相同，但为所有共享的序对加标签。
+    pub fn to_scheme_string_shared(&self) -> String {
+        let mut out = String::new();
+        write_flat(self, &mut Labels::shared(self), &mut out, None);
+        out
+    }
+
+    /// Print the list in Scheme notation, breaking nested lists over several lines so that
+    /// each line stays within `width` columns where possible.
+    /// 按给定宽度换行并缩进打印深层嵌套的数据。
+    pub fn pretty_scheme(&self, width: usize) -> String {
+        let mut labels = Labels::cycles(self);
+        let mut flat = Flat {
+            text: String::new(),
+            spans: Vec::new(),
+        };
+        write_flat(self, &mut labels, &mut flat.text, Some(&mut flat.spans));
+        let mut out = String::new();
+        write_pretty(self, 0, width, &flat, &mut 0, &labels, &mut out);
+        out
+    }
+}
+
+/// Split a list into its elements and its final tail (`Nil` for a proper list).
+/// The walk stops at a labelled cell, which is then printed as a dotted tail.
+/// 拆分出链表的各元素以及末尾；遇到带标签的序对时停止，将其作为点对的尾部打印。
+fn elements(l: &List, labels: &Labels) -> (Vec<List>, List) {
+    let mut items = vec![l.head()];
+    let mut rest = l.tail();
+    while rest.is_pair() && !labels.is_target(&rest) {
+        items.push(rest.head());
+        rest = rest.tail();
+    }
+    (items, rest)
+}
+
+/// Recognise `(quote x)` and friends so they can be printed as `'x`.
+/// 识别 `(quote x)` 等形式，以便打印为 `'x`。
+fn quote_prefix(l: &List, labels: &Labels) -> Option<(&'static str, List)> {
+    let head = l.head();
+    if !head.is_value() {
+        return None;
+    }
+    let prefix = match head.try_as_basis_value::<Symbol>().map(Symbol::as_str) {
+        Ok("quote") => "'",
+        Ok("quasiquote") => "`",
+        Ok("unquote") => ",",
+        Ok("unquote-splicing") => ",@",
+        _ => return None,
+    };
+    let rest = l.tail();
+    if rest.is_pair() && !labels.is_target(&rest) && rest.tail().is_empty() {
+        Some((prefix, rest.head()))
+    } else {
+        None
+    }
+}
+
+/// Write the label in front of a cell; returns `false` when only a reference was written.
+/// 输出序对前的标签；若只输出了引用 `#n#` 则返回 `false`。
+fn write_label(l: &List, labels: &mut Labels, out: &mut String) -> bool {
+    match labels.visit(l) {
+        Label::None => true,
+        Label::Define(n) => {
+            let _ = write!(out, "#{}=", n);
+            true
+        }
+        Label::Ref(n) => {
+            let _ = write!(out, "#{}#", n);
+            false
+        }
+    }
+}
+
+/// The one-line rendering of a list together with the extent of every pair printed in it, so
+/// that the pretty printer measures each sublist once instead of re-rendering it at every depth.
+/// 单行打印的结果及其中每个序对的位置，美化打印时无需在每一层重新打印子表。
+struct Flat {
+    text: String,
+    spans: Vec<Span>,
+}
+
+/// A pair as it appears in `Flat::text`; spans are stored in printing order.
+/// 序对在单行打印结果中的位置，按打印顺序存放。
+struct Span {
+    /// Byte offset of the label or the opening parenthesis.
+    start: usize,
+    /// Byte offset just after the `#n=` label; equal to `end` for a `#n#` reference.
+    body: usize,
+    end: usize,
+    /// Width in characters.
+    width: usize,
+    /// Index of the first span after this pair and its contents.
+    next: usize,
+}
+
+fn write_flat(l: &List, labels: &mut Labels, out: &mut String, mut spans: Option<&mut Vec<Span>>) {
+    // 使用显式栈，避免长链表导致 Rust 递归栈溢出
+    enum Work {
+        Datum(List),
+        Rest(List),
+        Text(&'static str),
+        End(usize),
+    }
+    // 已输出的字符数，仅在记录 spans 时统计
+    let mut chars = 0;
+    let mut stack = vec![Work::Datum(l.clone())];
+    while let Some(work) = stack.pop() {
+        let before = out.len();
+        match work {
+            Work::Text(s) => out.push_str(s),
+            Work::End(i) => {
+                if let Some(spans) = spans.as_deref_mut() {
+                    let next = spans.len();
+                    let span = &mut spans[i];
+                    span.end = out.len();
+                    // width 暂存的是起始处的字符数
+                    span.width = chars - span.width;
+                    span.next = next;
+                }
+            }
+            Work::Datum(List::Nil) => out.push_str("()"),
+            Work::Datum(List::V(ref v)) => write_atom(v.as_ref(), out),
+            Work::Datum(cell) => {
+                let span = spans.as_deref_mut().map(|spans| {
+                    spans.push(Span {
+                        start: out.len(),
+                        body: out.len(),
+                        end: out.len(),
+                        width: chars,
+                        next: 0,
+                    });
+                    spans.len() - 1
+                });
+                if let Some(i) = span {
+                    stack.push(Work::End(i));
+                }
+                let defined = write_label(&cell, labels, out);
+                if let (Some(i), Some(spans)) = (span, spans.as_deref_mut()) {
+                    spans[i].body = out.len();
+                }
+                if !defined {
+                    // 只输出了引用 `#n#`
+                } else if let Some((prefix, quoted)) = quote_prefix(&cell, labels) {
+                    out.push_str(prefix);
+                    stack.push(Work::Datum(quoted));
+                } else {
+                    out.push('(');
+                    stack.push(Work::Text(")"));
+                    stack.push(Work::Rest(cell.tail()));
+                    stack.push(Work::Datum(cell.head()));
+                }
+            }
+            Work::Rest(List::Nil) => (),
+            Work::Rest(rest) => {
+                if rest.is_pair() && !labels.is_target(&rest) {
+                    out.push(' ');
+                    stack.push(Work::Rest(rest.tail()));
+                    stack.push(Work::Datum(rest.head()));
+                } else {
+                    out.push_str(" . ");
+                    stack.push(Work::Datum(rest));
+                }
+            }
+        }
+        if spans.is_some() {
+            chars += out[before..].chars().count();
+        }
+    }
+}
+
+/// Pretty-print `l`, whose one-line rendering starts at `flat.spans[*next]`. The traversal
+/// visits pairs in the same order as `write_flat`, so `next` walks the spans in step.
+/// 美化打印 `l`；遍历序对的顺序与 `write_flat` 相同，`next` 随之依次前进。
+fn write_pretty(
+    l: &List,
+    indent: usize,
+    width: usize,
+    flat: &Flat,
+    next: &mut usize,
+    labels: &Labels,
+    out: &mut String,
+) {
+    let span = match l {
+        List::Nil => return out.push_str("()"),
+        List::V(v) => return write_atom(v.as_ref(), out),
+        List::Cons(_, _) => &flat.spans[*next],
+    };
+    // 放得下一行（或只是引用 `#n#`）就直接采用单行打印的结果
+    if indent + span.width <= width || span.body == span.end {
+        out.push_str(&flat.text[span.start..span.end]);
+        *next = span.next;
+        return;
+    }
+    *next += 1;
+    out.push_str(&flat.text[span.start..span.body]);
+    let indent = indent + (span.body - span.start);
+    if let Some((prefix, quoted)) = quote_prefix(l, labels) {
+        out.push_str(prefix);
+        write_pretty(
+            &quoted,
+            indent + prefix.len(),
+            width,
+            flat,
+            next,
+            labels,
+            out,
+        );
+        return;
+    }
+    let (items, tail) = elements(l, labels);
+    out.push('(');
+    // 若首元素为原子（通常是运算符），其后的参数与第一个参数纵向对齐
+    let (first, child_indent) = if !items[0].is_pair() && items.len() > 1 {
+        let op = items[0].to_scheme_string();
+        out.push_str(&op);
+        out.push(' ');
+        let child_indent = indent + 1 + op.chars().count() + 1;
+        write_pretty(&items[1], child_indent, width, flat, next, labels, out);
+        (2, child_indent)
+    } else {
+        write_pretty(&items[0], indent + 1, width, flat, next, labels, out);
+        (1, indent + 1)
+    };
+    for item in &items[first..] {
+        newline(child_indent, out);
+        write_pretty(item, child_indent, width, flat, next, labels, out);
+    }
+    if !tail.is_empty() {
+        newline(child_indent, out);
+        out.push_str(". ");
+        write_pretty(&tail, child_indent + 2, width, flat, next, labels, out);
+    }
+    out.push(')');
+}
+
+fn newline(indent: usize, out: &mut String) {
+    out.push('\n');
+    out.extend(std::iter::repeat_n(' ', indent));
+}
+
+fn write_atom(v: &dyn ListV, out: &mut String) {
+    let any = v.as_any();
+    if let Some(b) = any.downcast_ref::<bool>() {
+        out.push_str(if *b { "#t" } else { "#f" });
+    } else if let Some(s) = any.downcast_ref::<String>() {
+        write_string(s, out);
+    } else if let Some(s) = any.downcast_ref::<&str>() {
+        write_string(s, out);
+    } else if let Some(s) = any.downcast_ref::<Symbol>() {
+        write_symbol(s.as_str(), out);
+    } else if let Some(f) = any.downcast_ref::<f64>() {
+        write_float(*f, out);
+    } else if let Some(f) = any.downcast_ref::<f32>() {
+        write_float(*f as f64, out);
+    } else {
+        out.push_str(&v.as_string());
+    }
+}
+
+fn write_float(f: f64, out: &mut String) {
+    if f.is_nan() {
+        out.push_str("+nan.0");
+    } else if f.is_infinite() {
+        out.push_str(if f > 0.0 { "+inf.0" } else { "-inf.0" });
+    } else {
+        // `{:?}` 总是保留小数点或指数，读回时仍为浮点数
+        out.push_str(&format!("{:?}", f));
+    }
+}
+
+fn write_string(s: &str, out: &mut String) {
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\t' => out.push_str("\\t"),
+            '\r' => out.push_str("\\r"),
+            '\0' => out.push_str("\\0"),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+fn write_symbol(s: &str, out: &mut String) {
+    // 只有读回后仍是同名符号时才直接打印
+    let bare =
+        !s.is_empty() && s != "." && !s.chars().any(is_delimiter) && parse_atom(s).is_symbol();
+    if bare {
+        out.push_str(s);
+    } else {
+        out.push('|');
+        for c in s.chars() {
+            if c == '|' || c == '\\' {
+                out.push('\\');
+            }
+            out.push(c);
+        }
+        out.push('|');
+    }
+}
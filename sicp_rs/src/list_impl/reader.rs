@@ -0,0 +1,457 @@
+// src/list_impl/reader.rs
+//! # S-expression Reader
+//!
+//! Parse Scheme-style text into the same `List::Cons`/`List::V` structure built by `list!`/`pair!`.
+//! 将 Scheme 风格的文本解析为与 `list!`/`pair!` 相同的 `List` 结构。
+//!
+//! ## Syntax
+//! - Integers (`42`, `-7`) become `i32`, widening to `i64`/`i128`/`BigInt` when they do not fit.
+//! - Rationals (`1/3`, `-6/4`) become a reduced `BigRational`, or an integer when the
+//!   denominator reduces to 1.
+//! - Floats (`3.5`, `-1e-3`) become `f64`.
+//! - Strings (`"hello\n"`) become `String`.
+//! - Booleans (`#t`, `#f`) become `bool`.
+//! - `+inf.0`, `-inf.0` and `+nan.0` are the special `f64` values.
+//! - Any other atom is a `Symbol` (e.g. `+`, `x`, `make-sum`). `|two words|` reads a symbol
+//!   verbatim.
+//! - `()` is `List::Nil`, `(1 . 2)` is a dotted pair.
+//! - `'x`, `` `x ``, `,x` and `,@x` expand to `(quote x)`, `(quasiquote x)`, `(unquote x)`
+//!   and `(unquote-splicing x)`.
+//! - `;` starts a comment that runs to the end of the line.
+//!
+//! ## Examples
+//! ```rust
+//! use sicp_rs::prelude::*;
+//!
+//! let exp = List::parse("(+ x (* 3 y))").unwrap();
+//! assert_eq!(exp, list![sym!("+"), sym!(x), list![sym!("*"), 3, sym!(y)]]);
+//!
+//! let err = List::parse("(1 2").unwrap_err();
+//! assert_eq!((err.line, err.column), (1, 1));
+//! ```
+
+use std::error::Error;
+use std::fmt;
+use std::iter::Peekable;
+use std::str::Chars;
+
+use num::{BigInt, BigRational, Zero};
+
+use crate::prelude::*;
+
+/// Error produced when the reader meets malformed input.
+/// 解析失败时返回的错误，携带出错位置（行号与列号均从 1 开始）。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub message: String,
+}
+
+impl ParseError {
+    fn new(line: usize, column: usize, message: impl Into<String>) -> Self {
+        ParseError {
+            line,
+            column,
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}:{}: {}", self.line, self.column, self.message)
+    }
+}
+
+impl Error for ParseError {}
+
+/// Tokens recognised by the reader, each tagged with its start position.
+/// 词法单元。
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Open,
+    Close,
+    Dot,
+    Quote(&'static str),
+    Str(String),
+    Symbol(String),
+    Atom(String),
+}
+
+struct Reader<'a> {
+    chars: Peekable<Chars<'a>>,
+    line: usize,
+    column: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn new(input: &'a str) -> Self {
+        Reader {
+            chars: input.chars().peekable(),
+            line: 1,
+            column: 1,
+        }
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.chars.next()?;
+        if c == '\n' {
+            self.line += 1;
+            self.column = 1;
+        } else {
+            self.column += 1;
+        }
+        Some(c)
+    }
+
+    fn skip_whitespace_and_comments(&mut self) {
+        while let Some(&c) = self.chars.peek() {
+            if c.is_whitespace() {
+                self.bump();
+            } else if c == ';' {
+                while let Some(c) = self.bump() {
+                    if c == '\n' {
+                        break;
+                    }
+                }
+            } else {
+                break;
+            }
+        }
+    }
+
+    fn read_bar_symbol(&mut self, line: usize, column: usize) -> Result<Token, ParseError> {
+        let mut s = String::new();
+        loop {
+            match self.bump() {
+                None => return Err(ParseError::new(line, column, "unterminated '|' symbol")),
+                Some('|') => return Ok(Token::Symbol(s)),
+                Some('\\') => match self.bump() {
+                    Some(c) => s.push(c),
+                    None => return Err(ParseError::new(line, column, "unterminated '|' symbol")),
+                },
+                Some(c) => s.push(c),
+            }
+        }
+    }
+
+    fn read_string(&mut self, line: usize, column: usize) -> Result<Token, ParseError> {
+        let mut s = String::new();
+        loop {
+            let (esc_line, esc_column) = (self.line, self.column);
+            match self.bump() {
+                None => return Err(ParseError::new(line, column, "unterminated string")),
+                Some('"') => return Ok(Token::Str(s)),
+                Some('\\') => match self.bump() {
+                    Some('n') => s.push('\n'),
+                    Some('t') => s.push('\t'),
+                    Some('r') => s.push('\r'),
+                    Some('0') => s.push('\0'),
+                    Some('\\') => s.push('\\'),
+                    Some('"') => s.push('"'),
+                    Some(c) => {
+                        return Err(ParseError::new(
+                            esc_line,
+                            esc_column,
+                            format!("unknown escape sequence \\{}", c),
+                        ));
+                    }
+                    None => return Err(ParseError::new(line, column, "unterminated string")),
+                },
+                Some(c) => s.push(c),
+            }
+        }
+    }
+
+    fn next_token(&mut self) -> Result<Option<(Token, usize, usize)>, ParseError> {
+        self.skip_whitespace_and_comments();
+        let (line, column) = (self.line, self.column);
+        let c = match self.bump() {
+            Some(c) => c,
+            None => return Ok(None),
+        };
+        let token = match c {
+            '(' => Token::Open,
+            ')' => Token::Close,
+            '\'' => Token::Quote("quote"),
+            '`' => Token::Quote("quasiquote"),
+            ',' => {
+                if self.chars.peek() == Some(&'@') {
+                    self.bump();
+                    Token::Quote("unquote-splicing")
+                } else {
+                    Token::Quote("unquote")
+                }
+            }
+            '"' => self.read_string(line, column)?,
+            '|' => self.read_bar_symbol(line, column)?,
+            c => {
+                let mut atom = c.to_string();
+                while let Some(&c) = self.chars.peek() {
+                    if is_delimiter(c) {
+                        break;
+                    }
+                    atom.push(c);
+                    self.bump();
+                }
+                if atom == "." {
+                    Token::Dot
+                } else {
+                    Token::Atom(atom)
+                }
+            }
+        };
+        Ok(Some((token, line, column)))
+    }
+
+    /// Read one datum, or `None` at end of input.
+    /// 读取一个完整的数据，输入结束时返回 `None`。
+    fn read(&mut self) -> Result<Option<List>, ParseError> {
+        // 使用显式栈，避免深层嵌套导致 Rust 递归栈溢出
+        let mut stack: Vec<Frame> = vec![];
+        loop {
+            let next = self.next_token()?;
+            let mut datum = match (next, stack.last_mut()) {
+                (None, None) => return Ok(None),
+                (None, Some(Frame::Quote { name, line, column })) => {
+                    return Err(ParseError::new(
+                        *line,
+                        *column,
+                        format!("expected datum after {}", name),
+                    ));
+                }
+                (
+                    None,
+                    Some(Frame::List {
+                        dot: Some((l, c)),
+                        tail: None,
+                        ..
+                    }),
+                ) => {
+                    return Err(ParseError::new(*l, *c, "no datum after '.'"));
+                }
+                (None, Some(Frame::List { line, column, .. })) => {
+                    return Err(ParseError::new(*line, *column, "unclosed '('"));
+                }
+                // 点号之后的数据已读取，只能是 `)`
+                (Some((token, l, c)), Some(Frame::List { tail: Some(_), .. })) => {
+                    if token != Token::Close {
+                        return Err(ParseError::new(l, c, "expected ')' after dotted tail"));
+                    }
+                    let Some(Frame::List { items, tail, .. }) = stack.pop() else {
+                        unreachable!()
+                    };
+                    List::from_items(items, tail.unwrap())
+                }
+                (Some((Token::Open, line, column)), _) => {
+                    stack.push(Frame::List {
+                        items: vec![],
+                        line,
+                        column,
+                        dot: None,
+                        tail: None,
+                    });
+                    continue;
+                }
+                (Some((Token::Quote(name), line, column)), _) => {
+                    stack.push(Frame::Quote { name, line, column });
+                    continue;
+                }
+                (Some((Token::Close, l, c)), Some(Frame::List { dot: Some(_), .. })) => {
+                    return Err(ParseError::new(l, c, "no datum after '.'"));
+                }
+                (Some((Token::Close, _, _)), Some(Frame::List { .. })) => {
+                    let Some(Frame::List { items, .. }) = stack.pop() else {
+                        unreachable!()
+                    };
+                    List::from_items(items, List::Nil)
+                }
+                (Some((Token::Close, l, c)), _) => {
+                    return Err(ParseError::new(l, c, "unexpected ')'"));
+                }
+                (
+                    Some((Token::Dot, l, c)),
+                    Some(Frame::List {
+                        items,
+                        dot: dot @ None,
+                        ..
+                    }),
+                ) => {
+                    if items.is_empty() {
+                        return Err(ParseError::new(l, c, "no datum before '.'"));
+                    }
+                    *dot = Some((l, c));
+                    continue;
+                }
+                (Some((Token::Dot, l, c)), _) => {
+                    return Err(ParseError::new(l, c, "unexpected '.'"));
+                }
+                (Some((Token::Str(s), _, _)), _) => s.to_listv(),
+                (Some((Token::Symbol(s), _, _)), _) => Symbol::new(&s).to_listv(),
+                (Some((Token::Atom(atom), _, _)), _) => parse_atom(&atom),
+            };
+            // 将读完的数据交给外层：quote 包装后继续向外传递，链表则加入元素
+            loop {
+                match stack.last_mut() {
+                    None => return Ok(Some(datum)),
+                    Some(Frame::Quote { name, .. }) => {
+                        datum = list![Symbol::new(name), datum];
+                        stack.pop();
+                    }
+                    Some(Frame::List {
+                        dot: Some(_), tail, ..
+                    }) => {
+                        *tail = Some(datum);
+                        break;
+                    }
+                    Some(Frame::List { items, .. }) => {
+                        items.push(datum);
+                        break;
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// A partially read datum on the reader's stack.
+/// 读取过程中尚未完成的数据。
+enum Frame {
+    /// `'x` and friends, waiting for the quoted datum.
+    Quote {
+        name: &'static str,
+        line: usize,
+        column: usize,
+    },
+    /// A list whose `(` was at `line`/`column`; `dot` is the position of a `.` already read,
+    /// and `tail` the datum after it.
+    List {
+        items: Vec<List>,
+        line: usize,
+        column: usize,
+        dot: Option<(usize, usize)>,
+        tail: Option<List>,
+    },
+}
+
+impl List {
+    fn from_items(items: Vec<List>, last: List) -> List {
+        items
+            .into_iter()
+            .rfold(last, |acc, item| List::pair(item, acc))
+    }
+}
+
+/// Classify an atom as a boolean, number or symbol.
+/// 将原子解析为布尔值、数字或符号。
+pub(super) fn parse_atom(atom: &str) -> List {
+    match atom {
+        "#t" | "#true" => return true.to_listv(),
+        "#f" | "#false" => return false.to_listv(),
+        "+inf.0" => return f64::INFINITY.to_listv(),
+        "-inf.0" => return f64::NEG_INFINITY.to_listv(),
+        "+nan.0" | "-nan.0" => return f64::NAN.to_listv(),
+        _ => (),
+    }
+    if looks_numeric(atom) {
+        if let Ok(i) = atom.parse::<i32>() {
+            return i.to_listv();
+        }
+        if let Ok(i) = atom.parse::<i64>() {
+            return i.to_listv();
+        }
+        if let Ok(i) = atom.parse::<i128>() {
+            return i.to_listv();
+        }
+        if let Some(i) = parse_big_int(atom) {
+            return i.to_listv();
+        }
+        if let Some(r) = parse_rational(atom) {
+            return r;
+        }
+        if let Ok(f) = atom.parse::<f64>() {
+            return f.to_listv();
+        }
+    }
+    Symbol::new(atom).to_listv()
+}
+
+// `BigInt` 的解析还接受 `_` 分隔符，这里只接受带可选符号的纯数字
+fn parse_big_int(atom: &str) -> Option<BigInt> {
+    let digits = atom.strip_prefix(['+', '-']).unwrap_or(atom);
+    if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    atom.parse().ok()
+}
+
+fn parse_rational(atom: &str) -> Option<List> {
+    let (numer, denom) = atom.split_once('/')?;
+    if denom.starts_with(['+', '-']) {
+        return None;
+    }
+    let (numer, denom) = (parse_big_int(numer)?, parse_big_int(denom)?);
+    if denom.is_zero() {
+        return None;
+    }
+    Some(List::from_rational(BigRational::new(numer, denom)))
+}
+
+pub(super) fn is_delimiter(c: char) -> bool {
+    c.is_whitespace() || matches!(c, '(' | ')' | '"' | ';' | '\'' | '`' | ',' | '|')
+}
+
+// 避免把 `inf`、`nan`、`+`、`-` 等符号当作数字
+fn looks_numeric(atom: &str) -> bool {
+    let rest = atom.strip_prefix(['+', '-']).unwrap_or(atom);
+    let rest = rest.strip_prefix('.').unwrap_or(rest);
+    rest.starts_with(|c: char| c.is_ascii_digit())
+}
+
+impl List {
+    /// Parse exactly one datum from Scheme-style text.
+    /// 从 Scheme 风格的文本中解析出唯一一个数据，多余的内容视为错误。
+    ///
+    /// # Examples
+    /// ```rust
+    /// use sicp_rs::prelude::*;
+    /// assert_eq!(List::parse("(1 . 2)").unwrap(), pair![1, 2]);
+    /// assert_eq!(List::parse("'x").unwrap(), list![sym!(quote), sym!(x)]);
+    /// ```
+    pub fn parse(input: &str) -> Result<List, ParseError> {
+        let mut reader = Reader::new(input);
+        let datum = match reader.read()? {
+            Some(datum) => datum,
+            None => {
+                return Err(ParseError::new(
+                    reader.line,
+                    reader.column,
+                    "unexpected end of input",
+                ));
+            }
+        };
+        if let Some((_, line, column)) = reader.next_token()? {
+            return Err(ParseError::new(line, column, "unexpected trailing datum"));
+        }
+        Ok(datum)
+    }
+
+    /// Parse every datum in the input, returning them as a list.
+    /// 解析输入中的全部数据（例如整个源文件），按顺序组成链表返回。
+    ///
+    /// # Examples
+    /// ```rust
+    /// use sicp_rs::prelude::*;
+    /// let data = List::parse_all("1 ; one\n(2 3)").unwrap();
+    /// assert_eq!(data, list![1, list![2, 3]]);
+    /// ```
+    pub fn parse_all(input: &str) -> Result<List, ParseError> {
+        let mut reader = Reader::new(input);
+        let mut items = vec![];
+        while let Some(datum) = reader.read()? {
+            items.push(datum);
+        }
+        Ok(List::from_slice(&items))
+    }
+}
@@ -0,0 +1,382 @@
+// src/list_impl/serde_impl.rs
+//! # Serde Support
+//!
+//! With the `serde` feature, `List` implements `Serialize` and `Deserialize`, so tables and
+//! polynomials built from lists can be saved and loaded with any serde format.
+//! 启用 `serde` 特性后，`List` 可以通过 serde 序列化与反序列化，便于持久化保存。
+//!
+//! ## Data Model
+//! - A proper list is a sequence: `(1 (2 3))` is `[1, [2, 3]]`, and `Nil` is `[]`.
+//! - `i32`, `f64`, `String` and `bool` values are written as plain numbers, strings and
+//!   booleans (`&str` is written as a string and reads back as `String`). Other integer types
+//!   and `f32` are tagged with their type (`{"i64": 5}`), `BigInt` and `BigRational` are tagged
+//!   decimal strings (`{"bigrational": "1/3"}`), and a `Symbol` is written as
+//!   `{"symbol": "+"}`, so these values read back with the same Rust type. Other value types
+//!   (e.g. `ClosureWrapper`) cannot be serialized.
+//! - A list not ending with `Nil` is an object: `(1 2 . 3)` is `{"list": [1, 2], "tail": 3}`.
+//! - A pair referenced more than once (shared or cyclic) is written once with an `"id"`,
+//!   assigned in order of first appearance, and referred to later as `{"ref": id}`:
+//!   `#0=(1 . #0#)` is `{"id": 0, "list": [1], "tail": {"ref": 0}}`.
+//!
+//! Formats without NaN or infinities (such as JSON) cannot hold non-finite floats.
+//! JSON 等格式无法表示 NaN 与无穷大。
+//!
+//! ## Examples
+//! ```rust
+//! use sicp_rs::prelude::*;
+//!
+//! let shared = list![1, 2];
+//! let l = list![sym!(x), shared.clone(), shared, pair![3, 4.5]];
+//! let json = serde_json::to_string(&l).unwrap();
+//! assert_eq!(
+//!     json,
+//!     r#"[{"symbol":"x"},{"id":0,"list":[1,2]},{"ref":0},{"list":[3],"tail":4.5}]"#
+//! );
+//!
+//! let back: List = serde_json::from_str(&json).unwrap();
+//! assert_eq!(back, l);
+//! // 共享结构在读回后仍然共享
+//! assert_eq!(back.tail().head().unique_id(), back.tail().tail().head().unique_id());
+//! ```
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+
+use num::{BigInt, BigRational};
+use serde::de::{self, DeserializeSeed, MapAccess, SeqAccess, Visitor};
+use serde::ser::{self, SerializeMap, SerializeSeq};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use super::cycle::{Label, Labels};
+use super::{List, Symbol};
+use crate::prelude::ListV;
+
+impl Serialize for List {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let labels = RefCell::new(Labels::shared(self));
+        Node {
+            list: self,
+            labels: &labels,
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for List {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let ids = RefCell::new(HashMap::new());
+        NodeSeed { ids: &ids }.deserialize(deserializer)
+    }
+}
+
+/// A list being serialized, together with the datum labels of the whole structure.
+/// 待序列化的链表，以及整个结构的标签记录。
+struct Node<'a> {
+    list: &'a List,
+    labels: &'a RefCell<Labels>,
+}
+
+struct Items<'a> {
+    items: &'a [List],
+    labels: &'a RefCell<Labels>,
+}
+
+impl Serialize for Node<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let id = match self.list {
+            List::Nil => return serializer.serialize_seq(Some(0))?.end(),
+            List::V(v) => return serialize_value(v.as_ref(), serializer),
+            List::Cons(_, _) => match self.labels.borrow_mut().visit(self.list) {
+                Label::Ref(n) => {
+                    let mut map = serializer.serialize_map(Some(1))?;
+                    map.serialize_entry("ref", &n)?;
+                    return map.end();
+                }
+                Label::Define(n) => Some(n),
+                Label::None => None,
+            },
+        };
+        let (items, tail) = elements(self.list, &self.labels.borrow());
+        let items = Items {
+            items: &items,
+            labels: self.labels,
+        };
+        if id.is_none() && tail.is_empty() {
+            return items.serialize(serializer);
+        }
+        let mut map = serializer.serialize_map(None)?;
+        if let Some(id) = id {
+            map.serialize_entry("id", &id)?;
+        }
+        map.serialize_entry("list", &items)?;
+        if !tail.is_empty() {
+            map.serialize_entry(
+                "tail",
+                &Node {
+                    list: &tail,
+                    labels: self.labels,
+                },
+            )?;
+        }
+        map.end()
+    }
+}
+
+impl Serialize for Items<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut seq = serializer.serialize_seq(Some(self.items.len()))?;
+        for item in self.items {
+            seq.serialize_element(&Node {
+                list: item,
+                labels: self.labels,
+            })?;
+        }
+        seq.end()
+    }
+}
+
+/// Split a list into its elements and final tail, stopping before a labelled pair so that
+/// it is written with its own id.
+/// 拆分出链表的各元素以及末尾；遇到带标签的序对时停止。
+fn elements(l: &List, labels: &Labels) -> (Vec<List>, List) {
+    let mut items = vec![l.head()];
+    let mut rest = l.tail();
+    while rest.is_pair() && !labels.is_target(&rest) {
+        items.push(rest.head());
+        rest = rest.tail();
+    }
+    (items, rest)
+}
+
+fn serialize_value<S: Serializer>(v: &dyn ListV, serializer: S) -> Result<S::Ok, S::Error> {
+    let any = v.as_any();
+    if let Some(x) = any.downcast_ref::<i32>() {
+        return serializer.serialize_i32(*x);
+    } else if let Some(x) = any.downcast_ref::<f64>() {
+        return serializer.serialize_f64(*x);
+    } else if let Some(x) = any.downcast_ref::<String>() {
+        return serializer.serialize_str(x);
+    } else if let Some(x) = any.downcast_ref::<bool>() {
+        return serializer.serialize_bool(*x);
+    } else if let Some(x) = any.downcast_ref::<&str>() {
+        return serializer.serialize_str(x);
+    } else if let Some(x) = any.downcast_ref::<Symbol>() {
+        return tagged(serializer, "symbol", x.as_str());
+    }
+    macro_rules! tagged_numbers {
+        ($($t:ty),*) => {
+            $(
+                if let Some(x) = any.downcast_ref::<$t>() {
+                    return tagged(serializer, stringify!($t), x);
+                }
+            )*
+        };
+    }
+    tagged_numbers!(
+        i8, i16, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32
+    );
+    if let Some(x) = any.downcast_ref::<BigInt>() {
+        return tagged(serializer, "bigint", &x.to_string());
+    } else if let Some(x) = any.downcast_ref::<BigRational>() {
+        return tagged(serializer, "bigrational", &x.to_string());
+    }
+    Err(ser::Error::custom(format!(
+        "cannot serialize value {:?}: only numbers, strings, symbols and booleans are supported",
+        v
+    )))
+}
+
+fn tagged<S: Serializer, T: Serialize + ?Sized>(
+    serializer: S,
+    tag: &'static str,
+    value: &T,
+) -> Result<S::Ok, S::Error> {
+    let mut map = serializer.serialize_map(Some(1))?;
+    map.serialize_entry(tag, value)?;
+    map.end()
+}
+
+/// Deserializes one node; `ids` maps the labels seen so far to their pairs.
+/// 反序列化一个节点；`ids` 记录已读到的标签及其对应的序对。
+#[derive(Clone, Copy)]
+struct NodeSeed<'a> {
+    ids: &'a RefCell<HashMap<usize, List>>,
+}
+
+struct ItemsSeed<'a> {
+    ids: &'a RefCell<HashMap<usize, List>>,
+}
+
+impl<'de> DeserializeSeed<'de> for NodeSeed<'_> {
+    type Value = List;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<List, D::Error> {
+        deserializer.deserialize_any(self)
+    }
+}
+
+impl<'de> DeserializeSeed<'de> for ItemsSeed<'_> {
+    type Value = Vec<List>;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Vec<List>, D::Error> {
+        deserializer.deserialize_seq(self)
+    }
+}
+
+impl<'de> Visitor<'de> for ItemsSeed<'_> {
+    type Value = Vec<List>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a sequence of list elements")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Vec<List>, A::Error> {
+        let mut items = vec![];
+        while let Some(item) = seq.next_element_seed(NodeSeed { ids: self.ids })? {
+            items.push(item);
+        }
+        Ok(items)
+    }
+}
+
+impl<'de> Visitor<'de> for NodeSeed<'_> {
+    type Value = List;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a list, a value, or a tagged object")
+    }
+
+    fn visit_bool<E: de::Error>(self, v: bool) -> Result<List, E> {
+        Ok(v.to_listv())
+    }
+
+    // 与读取器相同：整数优先为 i32，放不下时依次扩大为 i64、i128
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<List, E> {
+        Ok(i32::try_from(v).map_or_else(|_| v.to_listv(), |v| v.to_listv()))
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<List, E> {
+        match i64::try_from(v) {
+            Ok(v) => self.visit_i64(v),
+            Err(_) => Ok((v as i128).to_listv()),
+        }
+    }
+
+    fn visit_i128<E: de::Error>(self, v: i128) -> Result<List, E> {
+        match i64::try_from(v) {
+            Ok(v) => self.visit_i64(v),
+            Err(_) => Ok(v.to_listv()),
+        }
+    }
+
+    fn visit_f64<E: de::Error>(self, v: f64) -> Result<List, E> {
+        Ok(v.to_listv())
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<List, E> {
+        Ok(v.to_string().to_listv())
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, seq: A) -> Result<List, A::Error> {
+        let items = ItemsSeed { ids: self.ids }.visit_seq(seq)?;
+        Ok(List::from_slice(&items))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<List, A::Error> {
+        let Some(key) = map.next_key::<String>()? else {
+            return Err(de::Error::custom("empty object"));
+        };
+        macro_rules! tagged_numbers {
+            ($($t:ty),*) => {
+                match key.as_str() {
+                    $(stringify!($t) => return Ok(map.next_value::<$t>()?.to_listv()),)*
+                    _ => (),
+                }
+            };
+        }
+        tagged_numbers!(
+            i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64
+        );
+        match key.as_str() {
+            "symbol" => Ok(Symbol::new(&map.next_value::<String>()?).to_listv()),
+            "bigint" => parse_tagged::<BigInt, A::Error>(&map.next_value::<String>()?),
+            "bigrational" => parse_tagged::<BigRational, A::Error>(&map.next_value::<String>()?),
+            "ref" => {
+                let id = map.next_value::<usize>()?;
+                let target = self.ids.borrow().get(&id).cloned();
+                target.ok_or_else(|| de::Error::custom(format!("undefined reference #{}#", id)))
+            }
+            "id" | "list" | "tail" => self.visit_labelled(key, map),
+            _ => Err(de::Error::unknown_field(
+                &key,
+                &[
+                    "id",
+                    "list",
+                    "tail",
+                    "ref",
+                    "symbol",
+                    "bigint",
+                    "bigrational",
+                ],
+            )),
+        }
+    }
+}
+
+fn parse_tagged<T: FromStr + ListV, E: de::Error>(s: &str) -> Result<List, E> {
+    s.parse::<T>()
+        .map(ListV::to_listv)
+        .map_err(|_| de::Error::invalid_value(de::Unexpected::Str(s), &"a number"))
+}
+
+impl NodeSeed<'_> {
+    /// Read `{"id": n, "list": [...], "tail": t}`. The pair for `id` is created before its
+    /// elements are read, so references inside them (cycles) can already point to it.
+    /// 读取带标签或不以 `Nil` 结尾的链表；先创建带标签的序对，以便内部的引用（环）可以指向它。
+    fn visit_labelled<'de, A: MapAccess<'de>>(
+        self,
+        first_key: String,
+        mut map: A,
+    ) -> Result<List, A::Error> {
+        let mut cell = None;
+        let mut items = None;
+        let mut tail = List::Nil;
+        let mut key = Some(first_key);
+        while let Some(k) = key {
+            match k.as_str() {
+                "id" if cell.is_none() && items.is_none() => {
+                    let id = map.next_value::<usize>()?;
+                    let pair = List::pair(List::Nil, List::Nil);
+                    self.ids.borrow_mut().insert(id, pair.clone());
+                    cell = Some(pair);
+                }
+                "id" => {
+                    return Err(de::Error::custom(
+                        "\"id\" must come first in a labelled list",
+                    ));
+                }
+                "list" => items = Some(map.next_value_seed(ItemsSeed { ids: self.ids })?),
+                "tail" => tail = map.next_value_seed(self)?,
+                _ => return Err(de::Error::unknown_field(&k, &["id", "list", "tail"])),
+            }
+            key = map.next_key::<String>()?;
+        }
+        let items = items.ok_or_else(|| de::Error::missing_field("list"))?;
+        let Some((first, rest)) = items.split_first() else {
+            return Err(de::Error::invalid_length(0, &"a non-empty \"list\""));
+        };
+        let rest = rest
+            .iter()
+            .rfold(tail, |acc, item| List::pair(item.clone(), acc));
+        match cell {
+            Some(cell) => {
+                cell.set_head(first.clone());
+                cell.set_tail(rest);
+                Ok(cell)
+            }
+            None => Ok(List::pair(first.clone(), rest)),
+        }
+    }
+}
@@ -0,0 +1,93 @@
+// src/list_impl/shared.rs
+//! # Shared Ownership
+//!
+//! `List` cells, `ListV` values and closures are held behind `Shared<T>`, and mutable cells
+//! behind `InnerCell<T>`. By default (feature `single_threaded`) these are `Rc` and `RefCell`;
+//! with feature `multi_threaded` they become `Arc` and `RwLock`, `ListV` requires
+//! `Send + Sync`, and `List`, `ClosureWrapper` and `ArithmeticContext` can be sent between
+//! threads (e.g. for the concurrency material of SICP 3.4).
+//! 默认使用 `Rc`/`RefCell`；启用 `multi_threaded` 特性后改为 `Arc`/`RwLock`，
+//! 此时 `List` 等类型可在线程间共享。
+//!
+//! ## Examples
+//! ```rust
+//! use sicp_rs::list_impl::{InnerCell, InnerCellExt, Shared};
+//! use sicp_rs::prelude::*;
+//!
+//! let cell = Shared::new(InnerCell::new(list![1, 2]));
+//! *cell.write_cell() = list![3];
+//! assert_eq!(*cell.read_cell(), list![3]);
+//! ```
+
+use std::ops::{Deref, DerefMut};
+
+#[cfg(not(feature = "multi_threaded"))]
+use std::cell::RefCell;
+#[cfg(not(feature = "multi_threaded"))]
+use std::rc::Rc;
+#[cfg(feature = "multi_threaded")]
+use std::sync::{Arc, PoisonError, RwLock};
+
+/// Reference-counted pointer used throughout the crate.
+/// 全局使用的引用计数指针。
+#[cfg(not(feature = "multi_threaded"))]
+pub type Shared<T> = Rc<T>;
+#[cfg(feature = "multi_threaded")]
+pub type Shared<T> = Arc<T>;
+
+/// Interior-mutable cell used for the head and tail of a pair.
+/// 序对头部与尾部所用的内部可变单元。
+#[cfg(not(feature = "multi_threaded"))]
+pub type InnerCell<T> = RefCell<T>;
+#[cfg(feature = "multi_threaded")]
+pub type InnerCell<T> = RwLock<T>;
+
+/// Bound required of everything stored in a `List`: nothing in single-threaded builds,
+/// `Send + Sync` in multi-threaded builds.
+/// 存入 `List` 的类型需满足的约束：单线程下无约束，多线程下为 `Send + Sync`。
+#[cfg(not(feature = "multi_threaded"))]
+pub trait MaybeSendSync {}
+#[cfg(not(feature = "multi_threaded"))]
+impl<T: ?Sized> MaybeSendSync for T {}
+#[cfg(feature = "multi_threaded")]
+pub trait MaybeSendSync: Send + Sync {}
+#[cfg(feature = "multi_threaded")]
+impl<T: ?Sized + Send + Sync> MaybeSendSync for T {}
+
+/// Uniform access to `InnerCell`, whichever cell type backs it.
+/// 统一 `RefCell` 与 `RwLock` 的访问方式。
+pub trait InnerCellExt<T> {
+    /// Shared access, like `RefCell::borrow` / `RwLock::read`.
+    fn read_cell(&self) -> impl Deref<Target = T> + '_;
+    /// Exclusive access, like `RefCell::borrow_mut` / `RwLock::write`.
+    fn write_cell(&self) -> impl DerefMut<Target = T> + '_;
+    /// Access through a unique reference, without locking.
+    fn get_cell_mut(&mut self) -> &mut T;
+}
+
+#[cfg(not(feature = "multi_threaded"))]
+impl<T> InnerCellExt<T> for RefCell<T> {
+    fn read_cell(&self) -> impl Deref<Target = T> + '_ {
+        self.borrow()
+    }
+    fn write_cell(&self) -> impl DerefMut<Target = T> + '_ {
+        self.borrow_mut()
+    }
+    fn get_cell_mut(&mut self) -> &mut T {
+        self.get_mut()
+    }
+}
+
+// 写入只是整体替换值，即使持锁线程 panic 也不会留下半修改的数据，因此忽略锁中毒
+#[cfg(feature = "multi_threaded")]
+impl<T> InnerCellExt<T> for RwLock<T> {
+    fn read_cell(&self) -> impl Deref<Target = T> + '_ {
+        self.read().unwrap_or_else(PoisonError::into_inner)
+    }
+    fn write_cell(&self) -> impl DerefMut<Target = T> + '_ {
+        self.write().unwrap_or_else(PoisonError::into_inner)
+    }
+    fn get_cell_mut(&mut self) -> &mut T {
+        self.get_mut().unwrap_or_else(PoisonError::into_inner)
+    }
+}
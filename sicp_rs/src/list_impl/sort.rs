@@ -0,0 +1,93 @@
+// src/list_impl/sort.rs
+//! # Sorting and Searching
+//!
+//! `sort`/`sort_by` return a sorted copy of a list. The sort is a stable merge sort, so equal
+//! elements keep their order. `member`, `remove` and `delete_duplicates` compare elements with
+//! `is_equal` (Scheme's `equal?`).
+//!
+//! 排序与查找：`sort`/`sort_by` 为稳定的归并排序，返回新链表；`member`、`remove`、
+//! `delete_duplicates` 按 `is_equal` 比较元素。
+//!
+//! ## Examples
+//! ```rust
+//! use sicp_rs::prelude::*;
+//!
+//! let l = list![3, 1.5, 2, 1];
+//! assert_eq!(l.sort(), list![1, 1.5, 2, 3]);
+//! assert_eq!(l.sort_by(|a, b| b.partial_cmp(a).unwrap()), list![3, 2, 1.5, 1]);
+//!
+//! assert_eq!(l.member(&2.to_listv()), Some(list![2, 1]));
+//! assert_eq!(list![1, 2, 1, 3].remove(&1.to_listv()), list![2, 3]);
+//! assert_eq!(list![1, 2, 1.0, 3, 2].delete_duplicates(), list![1, 2, 3]);
+//! ```
+
+use std::cmp::Ordering;
+use std::collections::HashSet;
+
+use super::{List, panic_with_location};
+
+impl List {
+    fn proper_elements(&self, op: &'static str) -> Vec<List> {
+        let (items, last) = self.spine(op);
+        if last.is_value() {
+            panic_with_location(&format!("{} called on an improper list", op), self);
+        }
+        items
+    }
+
+    /// Returns the elements sorted in ascending order by `PartialOrd for List`.
+    /// 按 `List` 的 `PartialOrd` 升序排序，返回新链表。
+    ///
+    /// # Panics
+    /// 若存在无法比较的元素（如数与字符串），或链表成环、不以 `Nil` 结尾，将会触发 panic。
+    pub fn sort(&self) -> List {
+        self.sort_by(|a, b| {
+            a.partial_cmp(b).unwrap_or_else(|| {
+                panic_with_location(
+                    "sort found incomparable elements",
+                    &List::from_slice(&[a.clone(), b.clone()]),
+                )
+            })
+        })
+    }
+
+    /// Returns the elements sorted by `compare`, keeping equal elements in their original order.
+    /// 按 `compare` 稳定排序，返回新链表。
+    pub fn sort_by<F>(&self, compare: F) -> List
+    where
+        F: FnMut(&List, &List) -> Ordering,
+    {
+        let mut items = self.proper_elements("sort_by");
+        // slice::sort_by 为稳定的归并排序
+        items.sort_by(compare);
+        List::from_slice(&items)
+    }
+
+    /// Returns the first sub-list whose head is `equal?` to `x`, like Scheme's `member`.
+    /// 返回以第一个与 `x` 相等的元素开头的子链表。
+    pub fn member(&self, x: &List) -> Option<List> {
+        self.pairs().find(|cell| cell.head().is_equal(x))
+    }
+
+    /// Returns the list without the elements `equal?` to `x`.
+    /// 返回移除所有与 `x` 相等的元素后的链表。
+    ///
+    /// # Panics
+    /// 若链表成环或不以 `Nil` 结尾，将会触发 panic；`delete_duplicates` 与 `sort_by` 同理。
+    pub fn remove(&self, x: &List) -> List {
+        self.proper_elements("remove")
+            .into_iter()
+            .filter(|item| !item.is_equal(x))
+            .collect()
+    }
+
+    /// Returns the list keeping only the first of the `equal?` elements, in O(n) expected time.
+    /// 去除重复元素，只保留第一次出现的元素。
+    pub fn delete_duplicates(&self) -> List {
+        let mut seen = HashSet::new();
+        self.proper_elements("delete_duplicates")
+            .into_iter()
+            .filter(|item| seen.insert(item.clone()))
+            .collect()
+    }
+}
@@ -0,0 +1,116 @@
+// src/list_impl/symbol.rs
+//! # Symbols
+//!
+//! `Symbol` is the value type for Scheme symbols such as `x`, `+` or `make-sum`, kept apart from
+//! strings so that the variable `x` and the string `"x"` are different data, as in the book.
+//! Names are interned in a process-wide table, so two symbols are equal exactly when they point
+//! to the same name and comparing them is a pointer comparison.
+//! Interned names are never freed: memory grows with the number of distinct names, not with the
+//! number of `Symbol` values, so building symbols from unbounded input (e.g. reading untrusted
+//! text) leaks one allocation per new name.
+//! `Symbol` 表示 Scheme 中的符号，与字符串区分开；符号名全局驻留，比较时只需比较指针。
+//! 驻留的符号名不会释放，内存随不同符号名的数量增长。
+//!
+//! ## Examples
+//! ```rust
+//! use sicp_rs::prelude::*;
+//!
+//! let exp = list![sym!("+"), sym!(x), 3];
+//! assert!(exp.head().is_symbol());
+//! assert_eq!(exp.tail().head(), sym!(x).to_listv());
+//! // 符号与同名字符串不相等
+//! assert_ne!(sym!(x).to_listv(), "x".to_string().to_listv());
+//! assert_eq!(List::parse("(+ x 3)").unwrap(), exp);
+//! ```
+
+use std::collections::HashSet;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::ptr;
+use std::sync::{LazyLock, Mutex, PoisonError};
+
+static SYMBOLS: LazyLock<Mutex<HashSet<&'static str>>> =
+    LazyLock::new(|| Mutex::new(HashSet::new()));
+
+/// An interned symbol name.
+/// 驻留的符号名。
+#[derive(Clone, Copy)]
+pub struct Symbol(&'static str);
+
+impl Symbol {
+    /// Get the symbol with the given name, interning the name on first use.
+    /// The interned name lives until the process exits.
+    /// 获取指定名称的符号，首次使用时驻留该名称；驻留的名称直到进程结束才释放。
+    pub fn new(name: &str) -> Symbol {
+        let mut symbols = SYMBOLS.lock().unwrap_or_else(PoisonError::into_inner);
+        if let Some(symbol) = symbols.get(name) {
+            return Symbol(symbol);
+        }
+        let symbol: &'static str = Box::leak(name.to_string().into_boxed_str());
+        symbols.insert(symbol);
+        Symbol(symbol)
+    }
+
+    /// The name of the symbol.
+    /// 符号名。
+    pub fn as_str(&self) -> &'static str {
+        self.0
+    }
+}
+
+impl PartialEq for Symbol {
+    fn eq(&self, other: &Self) -> bool {
+        ptr::eq(self.0, other.0)
+    }
+}
+
+impl Eq for Symbol {}
+
+impl Hash for Symbol {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        ptr::hash(self.0, state);
+    }
+}
+
+// 按名称排序，使结果与驻留顺序无关
+impl PartialOrd for Symbol {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Symbol {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.0.cmp(other.0)
+    }
+}
+
+impl fmt::Debug for Symbol {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.0)
+    }
+}
+
+impl fmt::Display for Symbol {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.0)
+    }
+}
+
+impl From<&str> for Symbol {
+    fn from(name: &str) -> Self {
+        Symbol::new(name)
+    }
+}
+
+/// Create a `Symbol`: `sym!(x)` for identifiers, `sym!("+")` for any other name.
+/// 创建符号：标识符可直接书写，其他名称使用字符串字面量。
+#[macro_export]
+macro_rules! sym {
+    ($name:ident) => {
+        $crate::list_impl::Symbol::new(stringify!($name))
+    };
+    ($name:expr) => {
+        $crate::list_impl::Symbol::new($name)
+    };
+}
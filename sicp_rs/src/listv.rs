@@ -1,6 +1,10 @@
 // src/listv.rs:
-use std::{any::Any, error::Error, fmt::Debug, io, ptr};
+use std::hash::{Hash, Hasher};
+use std::{any::Any, cmp::Ordering, error::Error, fmt::Debug, io, ptr};
 
+use num::{BigInt, BigRational, FromPrimitive};
+
+use crate::list_impl::{MaybeSendSync, Symbol};
 use crate::prelude::List;
 
 /// A macro to check if a `ListV` object is of a specific type.
@@ -31,21 +35,30 @@ macro_rules! is_type {
 ///
 /// 此 trait 提供了类型检查、相等性判断和字符串转换等功能，
 /// 支持将任意类型封装为 `ListV` 动态类型。
-pub trait ListV: Any + Debug {
+///
+/// With the `multi_threaded` feature, `ListV` also requires `Send + Sync`.
+/// 启用 `multi_threaded` 特性时，`ListV` 还要求 `Send + Sync`。
+pub trait ListV: Any + Debug + MaybeSendSync {
     /// Returns a reference to the underlying `Any` type.
     fn as_any(&self) -> &dyn Any;
 
     /// Checks if the object is a string (`String` or `&str`).
     fn is_string(&self) -> bool;
 
-    /// Checks if the object is an integer type (`i8`, `i16`, ..., `u128`).
+    /// Checks if the object is a `Symbol`.
+    fn is_symbol(&self) -> bool;
+
+    /// Checks if the object is an integer type (`i8`, `i16`, ..., `u128`, `BigInt`).
     fn is_integer(&self) -> bool;
 
+    /// Checks if the object is an exact rational (`BigRational`).
+    fn is_rational(&self) -> bool;
+
     /// Checks if the object is a floating-point type (`f32` or `f64`).
     fn is_float(&self) -> bool;
 
     fn is_number(&self) -> bool {
-        self.is_integer() || self.is_float()
+        self.is_integer() || self.is_rational() || self.is_float()
     }
     /// Determines if the object is the same instance as another `ListV` object.
     /// This is based on pointer equality, inspired by the SICP concept of sameness.
@@ -61,14 +74,21 @@ pub trait ListV: Any + Debug {
         if let Some(s) = self.as_any().downcast_ref::<&str>() {
             return s.to_string();
         }
+        if let Some(s) = self.as_any().downcast_ref::<Symbol>() {
+            return s.to_string();
+        }
+        // `Ratio` 的 Debug 输出为结构体形式，改用 `1/3` 记法
+        if let Some(r) = self.as_any().downcast_ref::<BigRational>() {
+            return r.to_string();
+        }
 
-        return format!("{:?}", self);
+        format!("{:?}", self)
     }
     fn to_listv(self) -> List;
 }
 impl<T> ListV for T
 where
-    T: Any + Debug + Clone + 'static,
+    T: Any + Debug + Clone + MaybeSendSync + 'static,
 {
     fn as_any(&self) -> &dyn Any {
         self
@@ -76,6 +96,9 @@ where
     fn is_string(&self) -> bool {
         is_type!(self, String) || is_type!(self, &str)
     }
+    fn is_symbol(&self) -> bool {
+        is_type!(self, Symbol)
+    }
     fn is_integer(&self) -> bool {
         is_type!(self, i8)
             || is_type!(self, i16)
@@ -87,6 +110,10 @@ where
             || is_type!(self, u32)
             || is_type!(self, u64)
             || is_type!(self, u128)
+            || is_type!(self, BigInt)
+    }
+    fn is_rational(&self) -> bool {
+        is_type!(self, BigRational)
     }
     fn is_float(&self) -> bool {
         is_type!(self, f32) || is_type!(self, f64)
@@ -114,16 +141,15 @@ impl PartialEq for dyn ListV {
                 || judge(other, self)
                 || compare_as::<String>(self, other)
                 || compare_as::<&str>(self, other)
+        } else if self.is_symbol() && other.is_symbol() {
+            // Symbol comparison: interned names compare by pointer.
+            compare_as::<Symbol>(self, other)
         } else if self.is_float() && other.is_float() {
-            // Float comparison: Promote to `f64` for strict comparison.
+            // Float comparison: Promote to `f64` for exact comparison. No epsilon, so that
+            // equality stays transitive and agrees with `Hash`; the generic arithmetic `is_equal`
+            // for floats in `ch2_5` keeps the rounding tolerance instead.
             if let (Ok(a), Ok(b)) = (to_f64(self), to_f64(other)) {
-                const ABS_EPSILON: f64 = f64::EPSILON;
-                let dynamic_epsilon = f64::EPSILON * a.abs().max(b.abs());
-                if (a - b).abs() < ABS_EPSILON || (a - b).abs() < dynamic_epsilon {
-                    true
-                } else {
-                    false
-                }
+                a == b
             } else {
                 false
             }
@@ -133,9 +159,14 @@ impl PartialEq for dyn ListV {
                 a == b
             } else if let (Ok(a), Ok(b)) = (to_u128(self), to_u128(other)) {
                 a == b
+            } else if let (Ok(a), Ok(b)) = (to_big_int(self), to_big_int(other)) {
+                a == b
             } else {
                 false
             }
+        } else if self.is_number() && other.is_number() {
+            // Mixed integer/rational/float comparison: exact, so `1 == 1.0` but `1 != 1.0000001`.
+            real_cmp(self, other) == Some(Ordering::Equal)
         } else if self.type_id() == other.type_id() && self.as_string() == other.as_string() {
             // Non-primitive types: Compare by string representation.
             true
@@ -145,6 +176,44 @@ impl PartialEq for dyn ListV {
     }
 }
 
+/// Hashes consistently with `PartialEq`: equal numbers of any type (`1_i32`, `1_u64`, `1.0`,
+/// `BigInt`/`BigRational` one) hash alike, and so do a `String` and a `&str` with equal text.
+/// 与 `PartialEq` 保持一致：数值相等的不同类型、内容相同的 `String` 与 `&str` 哈希值相同。
+impl Hash for dyn ListV {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        if self.is_string() {
+            0u8.hash(state);
+            self.as_string().hash(state);
+        } else if let Some(s) = self.as_any().downcast_ref::<Symbol>() {
+            1u8.hash(state);
+            s.hash(state);
+        } else if self.is_number() {
+            2u8.hash(state);
+            // 先尝试以 i128 哈希整数值，避免为常见情形构造大数
+            let integral = to_i128(self).ok().or_else(|| {
+                to_f64(self)
+                    .ok()
+                    .filter(|f| f.fract() == 0.0 && f.abs() < 2f64.powi(127))
+                    .map(|f| f as i128)
+            });
+            match integral {
+                Some(i) => i.hash(state),
+                None => match to_real(self) {
+                    Some(Real::Finite(r)) if r.is_integer() => match i128::try_from(r.numer()) {
+                        Ok(i) => i.hash(state),
+                        Err(_) => r.hash(state),
+                    },
+                    real => real.hash(state),
+                },
+            }
+        } else {
+            3u8.hash(state);
+            self.type_id().hash(state);
+            self.as_string().hash(state);
+        }
+    }
+}
+
 impl PartialOrd for dyn ListV {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
         if self.is_float() && other.is_float() {
@@ -158,12 +227,14 @@ impl PartialOrd for dyn ListV {
                 a.partial_cmp(&b)
             } else if let (Ok(a), Ok(b)) = (to_u128(self), to_u128(other)) {
                 a.partial_cmp(&b)
+            } else if let (Ok(a), Ok(b)) = (to_big_int(self), to_big_int(other)) {
+                a.partial_cmp(&b)
             } else {
                 None
             }
-        } else if self.is_string() && other.is_string() {
-            self.as_string().partial_cmp(&other.as_string())
-        } else if self.type_id() == other.type_id() {
+        } else if self.is_number() && other.is_number() {
+            real_cmp(self, other)
+        } else if (self.is_string() && other.is_string()) || self.type_id() == other.type_id() {
             self.as_string().partial_cmp(&other.as_string())
         } else {
             None
@@ -171,6 +242,87 @@ impl PartialOrd for dyn ListV {
     }
 }
 
+impl dyn ListV {
+    /// A total order over all values, for sorting.
+    /// Numbers come first, ordered exactly across integers, rationals and floats; NaN is
+    /// greater than every other number and equal to itself. Then come booleans, strings,
+    /// symbols and other types, each ordered by their string form.
+    /// 用于排序的全序：数值最先并按精确值比较，NaN 大于所有数值且与自身相等；
+    /// 其后依次为布尔值、字符串、符号与其他类型。
+    pub fn total_cmp(&self, other: &dyn ListV) -> Ordering {
+        fn rank(v: &dyn ListV) -> u8 {
+            if v.is_number() {
+                0
+            } else if is_type!(v, bool) {
+                1
+            } else if v.is_string() {
+                2
+            } else if v.is_symbol() {
+                3
+            } else {
+                4
+            }
+        }
+        rank(self).cmp(&rank(other)).then_with(|| {
+            if self.is_number() {
+                to_real(self).cmp(&to_real(other))
+            } else {
+                self.as_string()
+                    .cmp(&other.as_string())
+                    .then_with(|| self.type_id().cmp(&other.type_id()))
+            }
+        })
+    }
+
+    /// The exact value of an integer, rational or finite float, or `None` for other values.
+    /// 返回整数、有理数或有限浮点数的精确值；其他值返回 `None`。
+    pub fn to_big_rational(&self) -> Option<BigRational> {
+        match to_real(self)? {
+            Real::Finite(r) => Some(r),
+            _ => None,
+        }
+    }
+}
+
+/// A number placed on the numeric tower, for exact comparison between numeric types.
+/// Variants are declared in ascending order; `NaN` is last so `Ord` puts it after all numbers.
+/// 数值塔上的实数，用于不同数值类型之间的精确比较。
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash)]
+enum Real {
+    NegInf,
+    Finite(BigRational),
+    PosInf,
+    NaN,
+}
+
+fn to_real(value: &dyn ListV) -> Option<Real> {
+    if let Some(r) = value.as_any().downcast_ref::<BigRational>() {
+        Some(Real::Finite(r.clone()))
+    } else if let Ok(i) = to_big_int(value) {
+        Some(Real::Finite(BigRational::from_integer(i)))
+    } else if let Ok(f) = to_f64(value) {
+        Some(if f.is_nan() {
+            Real::NaN
+        } else if f == f64::INFINITY {
+            Real::PosInf
+        } else if f == f64::NEG_INFINITY {
+            Real::NegInf
+        } else {
+            // 有限浮点数都能精确表示为有理数
+            Real::Finite(BigRational::from_f64(f)?)
+        })
+    } else {
+        None
+    }
+}
+
+fn real_cmp(a: &dyn ListV, b: &dyn ListV) -> Option<Ordering> {
+    match (to_real(a)?, to_real(b)?) {
+        (Real::NaN, _) | (_, Real::NaN) => None,
+        (a, b) => Some(a.cmp(&b)),
+    }
+}
+
 // Helper functions for type conversion and comparison
 fn compare_as<T: PartialEq + 'static>(a: &dyn ListV, b: &dyn ListV) -> bool {
     if let (Some(a), Some(b)) = (
@@ -210,6 +362,15 @@ fn to_i128(value: &dyn ListV) -> Result<i128, Box<dyn Error>> {
         )))
     }
 }
+fn to_big_int(value: &dyn ListV) -> Result<BigInt, Box<dyn Error>> {
+    if let Some(value) = value.as_any().downcast_ref::<BigInt>() {
+        Ok(value.clone())
+    } else if let Ok(value) = to_i128(value) {
+        Ok(BigInt::from(value))
+    } else {
+        to_u128(value).map(BigInt::from)
+    }
+}
 fn to_u128(value: &dyn ListV) -> Result<u128, Box<dyn Error>> {
     if let Some(value) = value.as_any().downcast_ref::<i8>() {
         u128::try_from(*value).map_err(|e| Box::new(e) as Box<dyn Error>)
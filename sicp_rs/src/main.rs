@@ -10,5 +10,5 @@ fn main() {
         List::Nil    // 直接使用 List 实例
     ];
     println!("{:?}", l);
-    println!("{}",l);
+    println!("{}", l);
 }
@@ -0,0 +1,77 @@
+use sicp_rs::ch2::ch2_5::{ArithmeticContext, install_arithmetic_package, make_integer};
+use sicp_rs::prelude::*;
+
+fn constant(name: &'static str) -> ClosureWrapper {
+    ClosureWrapper::new(move |_: &List| Some(name.to_listv()))
+}
+
+#[test]
+fn test_put_get() {
+    let arith = ArithmeticContext::new();
+    arith.put("op", list!["a", "b"], constant("ab"));
+    arith.put("op", list!["b"], constant("b"));
+    let call = |keys: List| arith.get(keys).map(|f| f.call(&List::Nil).unwrap());
+
+    assert_eq!(call(list!["op", list!["a", "b"]]), Some("ab".to_listv()));
+    assert_eq!(call(list!["op", list!["b"]]), Some("b".to_listv()));
+    assert!(arith.get(list!["op", list!["b", "a"]]).is_none());
+    assert!(arith.get(list!["other", list!["b"]]).is_none());
+    // 字符串标签与同名符号等价
+    assert_eq!(
+        call(list![sym!(op), list![sym!(a), "b"]]),
+        Some("ab".to_listv())
+    );
+
+    // 再次 put 覆盖原有过程
+    arith.put("op", list!["b"], constant("b2"));
+    assert_eq!(call(list!["op", list!["b"]]), Some("b2".to_listv()));
+}
+
+#[test]
+fn test_signatures_order() {
+    let arith = ArithmeticContext::new();
+    arith.put("op", list!["a", "b"], constant("ab"));
+    arith.put("other", list!["a"], constant("a"));
+    arith.put("op", list!["b"], constant("b"));
+    arith.put("op", list!["a", "b"], constant("ab2"));
+
+    let signatures = arith.signatures("op");
+    assert_eq!(
+        signatures.map(|s| s.head()),
+        list![list!["b"], list!["a", "b"]]
+    );
+    let proc = signatures.tail().head().tail();
+    let proc = proc.try_as_basis_value::<ClosureWrapper>().unwrap();
+    assert_eq!(proc.call(&List::Nil), Some("ab2".to_listv()));
+    assert_eq!(arith.signatures("missing"), List::Nil);
+}
+
+#[test]
+fn test_coercion_table() {
+    let mut arith = ArithmeticContext::new();
+    let (t1, t2, t3) = ("t1".to_listv(), "t2".to_listv(), "t3".to_listv());
+    arith.put_coercion(&t1, &t2, constant("first"));
+    arith.put_coercion(&t1, &t2, constant("second"));
+    arith.put_coercion(&t2, &t3, constant("t2->t3"));
+
+    // 已存在的类型转换不会被覆盖
+    let proc = arith.get_coercion(&t1, &t2).unwrap();
+    assert_eq!(proc.call(&List::Nil), Some("first".to_listv()));
+    assert!(arith.get_coercion(&t2, &t1).is_none());
+    assert!(arith.get_coercion(&sym!(t2).to_listv(), &t3).is_some());
+    assert_eq!(
+        arith
+            .coercions()
+            .map(|entry| list![entry.head(), entry.tail().head()]),
+        list![list!["t2", "t3"], list!["t1", "t2"]]
+    );
+}
+
+#[test]
+fn test_context_clones_share_tables() {
+    let arith = ArithmeticContext::new();
+    let other = arith.clone();
+    install_arithmetic_package(&other);
+    let three = arith.add(&make_integer(1, &arith), &make_integer(2, &arith));
+    assert_eq!(three, 3.to_listv());
+}
@@ -0,0 +1,320 @@
+use num::{BigInt, BigRational};
+use sicp_rs::ch2::ch2_5::{
+    ArithmeticContext, DispatchError, MAX_POW_EXPONENT, install_arithmetic_package, make_bigint,
+    make_bigrational, make_float, make_integer, make_rational, type_tag,
+};
+use sicp_rs::prelude::*;
+
+fn arith() -> ArithmeticContext {
+    let arith = ArithmeticContext::new();
+    install_arithmetic_package(&arith);
+    arith
+}
+
+fn big(x: &str) -> BigInt {
+    x.parse().unwrap()
+}
+
+fn ratio(n: &str, d: &str) -> BigRational {
+    BigRational::new(big(n), big(d))
+}
+
+#[test]
+fn test_make_integer_promotes_on_overflow() {
+    let arith = arith();
+    assert_eq!(make_integer(7, &arith), 7.to_listv());
+    assert_eq!(make_integer(7_i64, &arith), 7.to_listv());
+    assert_eq!(make_integer(big("7"), &arith), 7.to_listv());
+
+    let x = make_integer(i64::from(i32::MAX) + 1, &arith);
+    assert_eq!(type_tag(&x), "bigint".to_listv());
+    assert_eq!(x, make_bigint(big("2147483648"), &arith));
+}
+
+#[test]
+fn test_integer_overflow_promotes_to_bigint() {
+    let arith = arith();
+    let max = make_integer(i32::MAX, &arith);
+    let min = make_integer(i32::MIN, &arith);
+    let one = make_integer(1, &arith);
+
+    assert_eq!(
+        arith.add(&max, &one),
+        make_bigint(big("2147483648"), &arith)
+    );
+    assert_eq!(
+        arith.sub(&min, &one),
+        make_bigint(big("-2147483649"), &arith)
+    );
+    assert_eq!(
+        arith.mul(&max, &max),
+        make_bigint(big("4611686014132420609"), &arith)
+    );
+    assert_eq!(arith.negative(&min), make_bigint(big("2147483648"), &arith));
+    assert_eq!(arith.abs(&min), make_bigint(big("2147483648"), &arith));
+    assert_eq!(
+        arith.pow(&make_integer(2, &arith), &make_integer(100, &arith)),
+        make_bigint(big("1267650600228229401496703205376"), &arith)
+    );
+    assert_eq!(
+        arith.pow(&make_integer(2, &arith), &make_integer(10, &arith)),
+        1024.to_listv()
+    );
+    assert_eq!(
+        arith.try_pow(&make_integer(2, &arith), &make_integer(-1, &arith)),
+        Err(DispatchError::OperationFailed {
+            op: "pow".to_string(),
+            types: vec!["integer".to_string(), "integer".to_string()],
+            reason: "negative exponent".to_string(),
+        })
+    );
+}
+
+#[test]
+fn test_bigint_arithmetic() {
+    let arith = arith();
+    let x = make_bigint(big("100000000000000000000"), &arith);
+    let y = make_integer(3, &arith);
+
+    assert_eq!(
+        arith.add(&x, &y),
+        make_bigint(big("100000000000000000003"), &arith)
+    );
+    assert_eq!(
+        arith.mul(&y, &x),
+        make_bigint(big("300000000000000000000"), &arith)
+    );
+    assert_eq!(
+        arith.gcd(&x, &make_bigint(big("300"), &arith)),
+        make_bigint(big("100"), &arith)
+    );
+    assert_eq!(
+        arith.reduce(&x, &make_bigint(big("300"), &arith)),
+        list![
+            make_bigint(big("1000000000000000000"), &arith),
+            make_bigint(big("3"), &arith)
+        ]
+    );
+    assert_eq!(
+        arith.is_equal(&make_bigint(big("3"), &arith), &y),
+        true.to_listv()
+    );
+    assert!(arith.try_div(&x, &make_bigint(big("0"), &arith)).is_err());
+}
+
+#[test]
+fn test_bigint_sqrt() {
+    let arith = arith();
+    let square = make_bigint(big("10000000000000000000000"), &arith);
+    assert_eq!(
+        arith.sqrt(&square),
+        make_bigint(big("100000000000"), &arith)
+    );
+    assert_eq!(arith.sqrt(&make_bigint(big("49"), &arith)), 7.to_listv());
+    let not_square = make_bigint(big("2"), &arith);
+    assert_eq!(type_tag(&arith.sqrt(&not_square)), "float".to_listv());
+}
+
+#[test]
+fn test_rational_promotes_to_bigrational() {
+    let arith = arith();
+    let x = make_rational(
+        make_bigint(big("6000000002"), &arith),
+        make_integer(4, &arith),
+        &arith,
+    );
+    assert_eq!(x, make_bigrational(ratio("3000000001", "2"), &arith));
+    assert_eq!(arith.numer(&x), make_bigint(big("3000000001"), &arith));
+    assert_eq!(arith.denom(&x), 2.to_listv());
+
+    // 约分后可放入 i32 时仍为 rational
+    let y = make_rational(
+        make_bigint(big("6000000000"), &arith),
+        make_bigint(big("4000000000"), &arith),
+        &arith,
+    );
+    assert_eq!(type_tag(&y), "rational".to_listv());
+    assert_eq!(y, make_rational(3.to_listv(), 2.to_listv(), &arith));
+
+    let half = make_rational(1.to_listv(), 2.to_listv(), &arith);
+    assert_eq!(
+        arith.add(&half, &make_bigint(big("3000000000"), &arith)),
+        make_bigrational(ratio("6000000001", "2"), &arith)
+    );
+}
+
+#[test]
+fn test_bigrational_arithmetic() {
+    let arith = arith();
+    let x = make_bigrational(ratio("1", "3"), &arith);
+    let y = make_rational(1.to_listv(), 6.to_listv(), &arith);
+    assert_eq!(arith.add(&x, &y), make_bigrational(ratio("1", "2"), &arith));
+    assert_eq!(
+        arith.pow(&x, &make_integer(-2, &arith)),
+        make_bigrational(ratio("9", "1"), &arith)
+    );
+    assert_eq!(
+        arith.sqrt(&make_bigrational(ratio("4", "9"), &arith)),
+        make_rational(2.to_listv(), 3.to_listv(), &arith)
+    );
+    assert_eq!(type_tag(&arith.sqrt(&x)), "float".to_listv());
+    assert_eq!(
+        arith.add(&x, &make_float(0.5, &arith)),
+        make_float(1.0 / 3.0 + 0.5, &arith)
+    );
+    assert!(
+        arith
+            .try_div(&x, &make_bigrational(ratio("0", "1"), &arith))
+            .is_err()
+    );
+}
+
+#[test]
+fn test_big_tower() {
+    let arith = arith();
+    let rank = |t: &str| arith.type_rank(&t.to_string().to_listv()).unwrap();
+    assert!(rank("integer") < rank("bigint"));
+    assert!(rank("bigint") < rank("rational"));
+    assert!(rank("rational") < rank("bigrational"));
+    assert!(rank("bigrational") < rank("float"));
+    assert_eq!(
+        arith.least_common_supertype(&list!["integer", "bigint"]),
+        Some("bigint".to_listv())
+    );
+    assert_eq!(
+        arith.least_common_supertype(&list!["bigint", "rational"]),
+        Some("bigrational".to_listv())
+    );
+    // raise 仍沿原有路径
+    assert_eq!(
+        type_tag(&arith.raise(&make_integer(1, &arith))),
+        "rational".to_listv()
+    );
+}
+
+#[test]
+fn test_big_drop() {
+    let arith = arith();
+    assert_eq!(arith.drop(&make_bigint(big("42"), &arith)), 42.to_listv());
+    let large = make_bigint(big("3000000000"), &arith);
+    assert_eq!(arith.drop(&large), large);
+    assert_eq!(
+        arith.drop(&make_bigrational(ratio("6", "3"), &arith)),
+        2.to_listv()
+    );
+    assert_eq!(
+        arith.drop(&make_bigrational(ratio("3000000000", "1"), &arith)),
+        large
+    );
+    assert_eq!(
+        arith.drop(&make_bigrational(ratio("1", "2"), &arith)),
+        make_rational(1.to_listv(), 2.to_listv(), &arith)
+    );
+    let third = make_bigrational(ratio("1", "3000000000"), &arith);
+    assert_eq!(arith.drop(&third), third);
+}
+
+#[test]
+fn test_number_with_big_values() {
+    let arith = arith();
+    let x = arith.number(i32::MAX) + 1;
+    assert_eq!(x.type_tag(), "bigint");
+    assert_eq!(format!("{}", x), "2147483648");
+    assert_eq!(x, arith.number(2147483648_i64));
+    assert!(x > arith.number(i32::MAX));
+
+    let y = arith.number(ratio("-1", "3000000000"));
+    assert_eq!(format!("{}", y), "-1/3000000000");
+    assert!(y < arith.number(0));
+}
+
+#[test]
+fn test_integer_div_and_gcd_overflow() {
+    let arith = arith();
+    let min = make_integer(i32::MIN, &arith);
+    let minus_one = make_integer(-1, &arith);
+    let zero = make_integer(0, &arith);
+    let two_pow_31 = make_bigint(big("2147483648"), &arith);
+
+    assert_eq!(arith.try_div(&min, &minus_one), Ok(two_pow_31.clone()));
+    assert_eq!(
+        arith.div(&min, &make_integer(2, &arith)),
+        (-1073741824).to_listv()
+    );
+    assert_eq!(arith.gcd(&min, &zero), two_pow_31);
+    assert_eq!(arith.gcd(&min, &min), two_pow_31);
+    assert_eq!(
+        arith.gcd(&make_integer(12, &arith), &make_integer(-18, &arith)),
+        6.to_listv()
+    );
+    // 约分时除以正的最大公约数，符号保持不变
+    assert_eq!(arith.reduce(&min, &min), list![-1, -1]);
+    assert_eq!(
+        arith.reduce(&min, &make_integer(-2, &arith)),
+        list![-1073741824, -1]
+    );
+    assert!(arith.try_reduce(&zero, &zero).is_err());
+}
+
+#[test]
+fn test_pow_exponent_bound() {
+    let arith = arith();
+    let too_large = |tag: &str| {
+        Err(DispatchError::OperationFailed {
+            op: "pow".to_string(),
+            types: vec![tag.to_string(), tag.to_string()],
+            reason: "exponent too large".to_string(),
+        })
+    };
+    let bound = MAX_POW_EXPONENT as i32;
+    let (two, one) = (make_integer(2, &arith), make_integer(1, &arith));
+    assert_eq!(
+        type_tag(&arith.pow(&two, &make_integer(bound, &arith))),
+        "bigint".to_listv()
+    );
+    assert_eq!(
+        arith.try_pow(&two, &make_integer(bound + 1, &arith)),
+        too_large("integer")
+    );
+    assert_eq!(arith.pow(&one, &make_integer(i32::MAX, &arith)), one);
+
+    let big_two = make_bigint(big("2"), &arith);
+    let huge = make_bigint(big("100000000000"), &arith);
+    assert_eq!(arith.try_pow(&big_two, &huge), too_large("bigint"));
+    assert_eq!(
+        arith.try_pow(&big_two, &make_bigint(big("-1"), &arith)),
+        Err(DispatchError::OperationFailed {
+            op: "pow".to_string(),
+            types: vec!["bigint".to_string(), "bigint".to_string()],
+            reason: "exponent must be a non-negative u32".to_string(),
+        })
+    );
+    let minus_one = make_bigint(big("-1"), &arith);
+    assert_eq!(
+        arith.pow(&minus_one, &make_bigint(big("4000000001"), &arith)),
+        minus_one
+    );
+
+    let half = make_bigrational(BigRational::new(1.into(), 2.into()), &arith);
+    let exponent = make_bigrational(BigRational::from_integer((bound + 1).into()), &arith);
+    assert_eq!(arith.try_pow(&half, &exponent), too_large("bigrational"));
+}
+
+#[test]
+fn test_bigint_reduce_by_zero() {
+    let arith = arith();
+    let zero = make_bigint(big("0"), &arith);
+    assert_eq!(
+        arith.try_reduce(&zero, &zero),
+        Err(DispatchError::OperationFailed {
+            op: "reduce".to_string(),
+            types: vec!["bigint".to_string(), "bigint".to_string()],
+            reason: "divide by zero".to_string(),
+        })
+    );
+    let x = make_bigint(big("4294967296"), &arith);
+    assert_eq!(
+        arith.reduce(&x, &zero),
+        list![make_bigint(big("1"), &arith), zero.clone()]
+    );
+}
@@ -0,0 +1,210 @@
+use sicp_rs::ch2::ch2_5::{
+    ArithmeticContext, DispatchError, install_arithmetic_package, install_dense_terms_package,
+    install_polynomial_package, install_sparse_terms_package, make_complex_from_real_imag,
+    make_float, make_integer, make_polynomial_from_dense, make_rational, raise_to_common_type,
+};
+use sicp_rs::prelude::*;
+
+fn arith() -> ArithmeticContext {
+    let arith = ArithmeticContext::new();
+    install_arithmetic_package(&arith);
+    arith
+}
+
+fn types(tags: &[&str]) -> Vec<String> {
+    tags.iter().map(|t| t.to_string()).collect()
+}
+
+fn divide_by_zero(tag: &str) -> DispatchError {
+    DispatchError::OperationFailed {
+        op: "div".to_string(),
+        types: types(&[tag, tag]),
+        reason: "divide by zero".to_string(),
+    }
+}
+
+#[test]
+fn test_operation_failed() {
+    let arith = arith();
+    let (one, zero) = (make_integer(1, &arith), make_integer(0, &arith));
+    assert_eq!(arith.try_div(&one, &zero), Err(divide_by_zero("integer")));
+    assert_eq!(
+        arith.try_div(&make_float(1.0, &arith), &make_float(0.0, &arith)),
+        Err(divide_by_zero("float"))
+    );
+    // 整数提升为有理数后再报错
+    let half = make_rational(1.to_listv(), 2.to_listv(), &arith);
+    assert_eq!(arith.try_div(&half, &zero), Err(divide_by_zero("rational")));
+    assert_eq!(
+        arith.try_div(&one, &half),
+        Ok(make_rational(2.to_listv(), 1.to_listv(), &arith))
+    );
+}
+
+#[test]
+fn test_no_method_and_arity() {
+    let arith = arith();
+    let (a, b) = (pair!("a", 1), pair!("b", 2));
+    assert_eq!(
+        arith.try_add(&a, &b),
+        Err(DispatchError::NoMethod {
+            op: "add".to_string(),
+            types: types(&["a", "b"]),
+        })
+    );
+    assert_eq!(
+        arith.try_add(&a, &a),
+        Err(DispatchError::NoMethod {
+            op: "add".to_string(),
+            types: types(&["a", "a"]),
+        })
+    );
+    assert_eq!(
+        arith.try_apply_generic("add", &list![1, 2, 3]),
+        Err(DispatchError::ArityMismatch {
+            op: "add".to_string(),
+            types: types(&["integer", "integer", "integer"]),
+        })
+    );
+}
+
+#[test]
+fn test_coercion() {
+    let mut arith = arith();
+    arith.put(
+        "add",
+        list!["b", "b"],
+        ClosureWrapper::new(|args| {
+            let value = |x: List| *x.try_as_basis_value::<i32>().unwrap();
+            Some(pair!("b", value(args.head()) + value(args.tail().head())))
+        }),
+    );
+    arith.put_coercion(
+        &"a".to_listv(),
+        &"b".to_listv(),
+        ClosureWrapper::new(|args| Some(pair!("b", args.head().tail()))),
+    );
+    arith.put_coercion(
+        &"c".to_listv(),
+        &"b".to_listv(),
+        ClosureWrapper::new(|_| None),
+    );
+
+    let (a, b, c) = (pair!("a", 1), pair!("b", 2), pair!("c", 3));
+    assert_eq!(arith.try_add(&b, &a), Ok(pair!("b", 3)));
+    assert_eq!(
+        arith.try_add(&b, &c),
+        Err(DispatchError::CoercionFailed {
+            op: "add".to_string(),
+            types: types(&["b", "c"]),
+            from: "c".to_string(),
+            to: "b".to_string(),
+        })
+    );
+}
+
+#[test]
+fn test_display() {
+    assert_eq!(
+        divide_by_zero("integer").to_string(),
+        "divide by zero -- div (integer integer)"
+    );
+    let e = DispatchError::NoMethod {
+        op: "add".to_string(),
+        types: types(&["a", "b"]),
+    };
+    assert_eq!(e.to_string(), "No method for these types -- add (a b)");
+}
+
+#[test]
+#[should_panic(expected = "divide by zero -- div (integer integer)")]
+fn test_panicking_form() {
+    let arith = arith();
+    arith.div(&make_integer(1, &arith), &make_integer(0, &arith));
+}
+
+#[test]
+fn test_nested_failure_is_reported() {
+    let arith = arith();
+    let one = make_complex_from_real_imag(1.0.to_listv(), 0.0.to_listv(), &arith);
+    let zero = make_complex_from_real_imag(0.0.to_listv(), 0.0.to_listv(), &arith);
+    // 模长下降为整数 0，内部的整数除以零报告为复数除法的失败
+    assert_eq!(
+        arith.try_div(&one, &zero),
+        Err(DispatchError::OperationFailed {
+            op: "div".to_string(),
+            types: types(&["complex", "complex"]),
+            reason: divide_by_zero("integer").to_string(),
+        })
+    );
+    assert!(arith.try_div(&one, &one).is_ok());
+    // 分量不是 f64 时同样报告失败而不 panic
+    let int_parts = make_complex_from_real_imag(1.to_listv(), 0.to_listv(), &arith);
+    assert!(arith.try_div(&int_parts, &int_parts).is_err());
+}
+
+#[test]
+fn test_untagged_argument() {
+    let arith = arith();
+    let x = "x".to_listv();
+    assert_eq!(
+        arith.try_add(&make_integer(1, &arith), &x),
+        Err(DispatchError::BadTaggedDatum {
+            op: "add".to_string(),
+            datum: x.to_string(),
+        })
+    );
+    assert_eq!(
+        arith.try_negative(&x).unwrap_err().to_string(),
+        "Bad tagged datum x -- negative"
+    );
+}
+
+#[test]
+fn test_raise_failure_is_reported() {
+    let arith = arith();
+    install_sparse_terms_package(&arith);
+    install_dense_terms_package(&arith);
+    install_polynomial_package(&arith);
+    let x = sym!(x).to_listv();
+    let x_plus_1 = make_polynomial_from_dense(&x, &list![1, 1], &arith);
+    let x_plus_2 = make_polynomial_from_dense(&x, &list![1, 2], &arith);
+    let r = make_rational(x_plus_1, x_plus_2, &arith);
+    let float = make_float(1.5, &arith);
+    // 多项式的有理分式无法提升为 float
+    assert_eq!(
+        arith.try_add(&r, &float),
+        Err(DispatchError::OperationFailed {
+            op: "add".to_string(),
+            types: types(&["rational", "float"]),
+            reason: "cannot raise rational to float".to_string(),
+        })
+    );
+    assert_eq!(arith.raise_to(&r, &"float".to_listv()), None);
+    assert_eq!(raise_to_common_type(&list![r.clone(), float], &arith), None);
+    assert_eq!(
+        arith.try_sqrt(&r),
+        Err(DispatchError::OperationFailed {
+            op: "sqrt".to_string(),
+            types: types(&["rational"]),
+            reason: "numerator and denominator must be integers".to_string(),
+        })
+    );
+}
+
+#[test]
+fn test_try_sqrt() {
+    let arith = arith();
+    assert_eq!(
+        arith.try_sqrt(&make_float(2.25, &arith)),
+        Ok(make_float(1.5, &arith))
+    );
+    let i = make_complex_from_real_imag(0.0.to_listv(), 1.0.to_listv(), &arith);
+    assert_eq!(
+        arith.try_sqrt(&i),
+        Err(DispatchError::NoMethod {
+            op: "sqrt".to_string(),
+            types: types(&["complex"]),
+        })
+    );
+}
@@ -0,0 +1,187 @@
+use num::BigRational;
+use sicp_rs::ch2::ch2_3::evaluate::{eval_expr, eval_expr_in, partial_eval};
+use sicp_rs::ch2::ch2_3::simplify::deriv;
+use sicp_rs::ch2::ch2_5::{
+    ArithmeticContext, install_arithmetic_package, make_bigrational, make_complex_from_real_imag,
+    make_float, make_integer, make_rational,
+};
+use sicp_rs::prelude::*;
+
+fn parse(s: &str) -> List {
+    List::parse(s).unwrap()
+}
+
+#[test]
+fn test_eval_expr() {
+    let bindings = list![
+        pair![sym!(x), 2],
+        pair![sym!(y), 0.5],
+        pair![sym!(z), 1_u64]
+    ];
+    let cases = [
+        ("(+ x y z)", 3.5),
+        ("(- x)", -2.0),
+        ("(- x y z)", 0.5),
+        ("(* x y 3)", 3.0),
+        ("(/ x)", 0.5),
+        ("(/ 1 x y)", 1.0),
+        ("(** x 10)", 1024.0),
+        ("(** 4 y)", 2.0),
+        ("(+ (exp 0) (log 1) (cos 0) (sin 0))", 2.0),
+        ("(+ 1/2 x)", 2.5),
+        ("7", 7.0),
+        ("y", 0.5),
+    ];
+    for (exp, expected) in cases {
+        assert_eq!(eval_expr(&parse(exp), &bindings), expected, "{}", exp);
+    }
+    // 关联表中先出现的绑定优先
+    let shadowed = list![pair![sym!(x), 10], pair![sym!(x), 2]];
+    assert_eq!(eval_expr(&parse("(* x x)"), &shadowed), 100.0);
+}
+
+#[test]
+#[should_panic(expected = "unbound variable -- EVAL")]
+fn test_eval_unbound_variable() {
+    eval_expr(&parse("(+ x y)"), &list![pair![sym!(x), 1]]);
+}
+
+#[test]
+#[should_panic(expected = "unknown expression type -- EVAL")]
+fn test_eval_unknown_operator() {
+    eval_expr(&parse("(tan x)"), &list![pair![sym!(x), 1]]);
+}
+
+/// 用中心差分验证 deriv 的结果
+fn check_deriv(exp: &str, points: &[f64]) {
+    let exp = parse(exp);
+    let x = sym!(x).to_listv();
+    let derivative = deriv(&exp, &x);
+    let h = 1e-6;
+    for &p in points {
+        let at = |v: f64| list![pair![sym!(x), v], pair![sym!(y), 1.5]];
+        let numeric = (eval_expr(&exp, &at(p + h)) - eval_expr(&exp, &at(p - h))) / (2.0 * h);
+        let symbolic = eval_expr(&derivative, &at(p));
+        assert!(
+            (numeric - symbolic).abs() <= 1e-5 * (1.0 + symbolic.abs()),
+            "d/dx {} at {}: {} = {} vs finite difference {}",
+            exp,
+            p,
+            derivative,
+            symbolic,
+            numeric
+        );
+    }
+}
+
+#[test]
+fn test_deriv_against_finite_differences() {
+    let points = [0.3, 1.0, 1.7, 2.5];
+    for exp in [
+        "(* (* x y) (+ x 3))",
+        "(- (** x 3) (* 2 x) 7)",
+        "(/ (+ x 1) (- x 4))",
+        "(/ x y x x)",
+        "(** x y)",
+        "(** 2 x)",
+        "(** x x)",
+        "(** (+ (* x x) 1) 1/2)",
+        "(sin (* 2 x))",
+        "(cos (** x 2))",
+        "(exp (* (sin x) y))",
+        "(log (+ (* x x) 1))",
+        "(* (sin x) (cos x) (exp x))",
+        "(/ (sin x) (+ 2 (cos x)))",
+    ] {
+        check_deriv(exp, &points);
+    }
+}
+
+#[test]
+fn test_eval_expr_in_arithmetic_context() {
+    let arith = ArithmeticContext::new();
+    install_arithmetic_package(&arith);
+    let integer = |x| make_integer(x, &arith);
+    let rational = |n: i32, d: i32| make_rational(n.to_listv(), d.to_listv(), &arith);
+
+    let bindings = list![pair![sym!(x), integer(3)], pair![sym!(y), rational(1, 3)]];
+    assert_eq!(
+        eval_expr_in(&parse("(+ x (* 2 x) 1)"), &bindings, &arith),
+        integer(10)
+    );
+    assert_eq!(
+        eval_expr_in(&parse("(- (* x y) (/ y))"), &bindings, &arith),
+        rational(-2, 1)
+    );
+    assert_eq!(
+        eval_expr_in(&parse("(** x 2)"), &bindings, &arith),
+        integer(9)
+    );
+    assert_eq!(
+        eval_expr_in(&parse("(* x 0.5)"), &bindings, &arith),
+        make_float(1.5, &arith)
+    );
+
+    // 精确常量保持精确
+    assert_eq!(
+        eval_expr_in(&parse("(+ x 1/2)"), &bindings, &arith),
+        rational(7, 2)
+    );
+    assert_eq!(
+        eval_expr_in(&parse("-6/4"), &bindings, &arith),
+        rational(-3, 2)
+    );
+    assert_eq!(
+        eval_expr_in(&parse("10000000000"), &bindings, &arith),
+        make_integer(10_000_000_000_i64, &arith)
+    );
+    assert_eq!(
+        eval_expr_in(&parse("1/10000000000"), &bindings, &arith),
+        make_bigrational(
+            BigRational::new(1.into(), 10_000_000_000_i64.into()),
+            &arith
+        )
+    );
+
+    let i = make_complex_from_real_imag(0.0.to_listv(), 1.0.to_listv(), &arith);
+    let bindings = list![pair![sym!(i), i]];
+    let z = eval_expr_in(&parse("(- (+ i i i 1.5) i)"), &bindings, &arith);
+    assert_eq!(arith.real_part(&z), 1.5.to_listv());
+    assert_eq!(arith.imag_part(&z), 2.0.to_listv());
+}
+
+#[test]
+fn test_partial_eval() {
+    let exp = parse("(+ (* x y) (* 2 x) z)");
+    assert_eq!(
+        partial_eval(&exp, &list![pair![sym!(x), 3]]),
+        parse("(+ (* 3 y) z 6)")
+    );
+    assert_eq!(
+        partial_eval(
+            &exp,
+            &list![pair![sym!(x), 3], pair![sym!(y), -2], pair![sym!(z), 1]]
+        ),
+        1.to_listv()
+    );
+    assert_eq!(
+        partial_eval(&exp, &List::Nil),
+        parse("(+ (* 2 x) z (* x y))")
+    );
+    // 绑定到表达式时整体代入
+    assert_eq!(
+        partial_eval(&parse("(* x x)"), &list![pair![sym!(x), parse("(+ y 1)")]]),
+        parse("(+ (* 2 y) (** y 2) 1)")
+    );
+    // 运算符不被代入，超越函数保持精确形式
+    assert_eq!(
+        partial_eval(&parse("(sin (* 2 sin))"), &list![pair![sym!(sin), 1]]),
+        parse("(sin 2)")
+    );
+
+    // 部分求值后再求值，与直接求值结果一致
+    let exp = parse("(/ (+ (** x 2) (sin y)) (exp z))");
+    let all = list![pair![sym!(x), 1.5], pair![sym!(y), 0.5], pair![sym!(z), 2]];
+    let partial = partial_eval(&exp, &list![pair![sym!(x), 1.5]]);
+    assert!((eval_expr(&partial, &all) - eval_expr(&exp, &all)).abs() < 1e-12);
+}
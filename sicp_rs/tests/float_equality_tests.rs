@@ -0,0 +1,48 @@
+use sicp_rs::ch2::ch2_5::{
+    ArithmeticContext, install_arithmetic_package, make_float, make_integer, make_rational,
+};
+use sicp_rs::prelude::*;
+
+fn arith() -> ArithmeticContext {
+    let arith = ArithmeticContext::new();
+    install_arithmetic_package(&arith);
+    arith
+}
+
+#[test]
+fn test_float_is_equal_tolerates_rounding() {
+    let arith = arith();
+    let sum = arith.add(&make_float(0.1, &arith), &make_float(0.2, &arith));
+    let is_equal = |x: &List, y: &List| arith.is_equal(x, y) == true.to_listv();
+
+    assert!(is_equal(&sum, &make_float(0.3, &arith)));
+    assert!(is_equal(
+        &make_float(1.0, &arith),
+        &make_float(1.0 + f64::EPSILON, &arith)
+    ));
+    assert!(is_equal(
+        &make_float(1e20, &arith),
+        &make_float(1e20 + 1e4, &arith)
+    ));
+    assert!(!is_equal(
+        &make_float(1.0, &arith),
+        &make_float(1.000_001, &arith)
+    ));
+    assert!(!is_equal(
+        &make_float(0.0, &arith),
+        &make_float(1e-10, &arith)
+    ));
+    // 其他类型先提升为 float 再比较
+    let three_tenths = make_rational(3.to_listv(), 10.to_listv(), &arith);
+    assert!(is_equal(&three_tenths, &sum));
+    assert!(is_equal(&make_integer(1, &arith), &make_float(1.0, &arith)));
+}
+
+#[test]
+fn test_list_equality_on_floats_stays_exact() {
+    let arith = arith();
+    let sum = arith.add(&make_float(0.1, &arith), &make_float(0.2, &arith));
+    // List 的 `==` 精确比较，以便与 Hash 一致
+    assert_ne!(sum, make_float(0.3, &arith));
+    assert_eq!(sum, make_float(0.1 + 0.2, &arith));
+}
@@ -0,0 +1,148 @@
+use sicp_rs::ch2::ch2_3::huffman::*;
+use sicp_rs::prelude::*;
+
+fn sample_tree() -> List {
+    make_code_tree(
+        make_leaf(sym!(A).to_listv(), 4),
+        make_code_tree(
+            make_leaf(sym!(B).to_listv(), 2),
+            make_code_tree(
+                make_leaf(sym!(D).to_listv(), 1),
+                make_leaf(sym!(C).to_listv(), 1),
+            ),
+        ),
+    )
+}
+
+#[test]
+fn test_sample_tree() {
+    let tree = sample_tree();
+    assert_eq!(weight(&tree), 8);
+    assert_eq!(
+        make_leaf(sym!(A).to_listv(), 4),
+        list![sym!(leaf), sym!(A), 4]
+    );
+    assert_eq!(symbols(&tree), list![sym!(A), sym!(B), sym!(D), sym!(C)]);
+    assert_eq!(
+        tree.to_scheme_string(),
+        "((leaf A 4) ((leaf B 2) ((leaf D 1) (leaf C 1) (D C) 2) (B D C) 4) (A B D C) 8)"
+    );
+
+    // 练习 2.67
+    let sample_message = list![0, 1, 1, 0, 0, 1, 0, 1, 0, 1, 1, 1, 0];
+    let decoded = decode(&sample_message, &tree);
+    assert_eq!(decoded, List::parse("(A D A B B C A)").unwrap());
+    // 练习 2.68
+    assert_eq!(encode(&decoded, &tree), sample_message);
+}
+
+#[test]
+#[should_panic(expected = "symbol not in tree")]
+fn test_encode_unknown_symbol() {
+    encode(&list![sym!(E)], &sample_tree());
+}
+
+#[test]
+#[should_panic(expected = "bad bit")]
+fn test_decode_bad_bit() {
+    decode(&list![2], &sample_tree());
+}
+
+#[test]
+fn test_generate_huffman_tree() {
+    let pairs = List::parse("((A 4) (B 2) (C 1) (D 1))").unwrap();
+    assert_eq!(
+        make_leaf_set(&pairs).to_scheme_string(),
+        "((leaf D 1) (leaf C 1) (leaf B 2) (leaf A 4))"
+    );
+    let tree = generate_huffman_tree(&pairs);
+    assert_eq!(weight(&tree), 8);
+    for (symbol, len) in [(sym!(A), 1), (sym!(B), 2), (sym!(C), 3), (sym!(D), 3)] {
+        assert_eq!(encode_symbol(&symbol.to_listv(), &tree).length(), len);
+    }
+
+    // 练习 2.70：摇滚歌词
+    let pairs =
+        List::parse("((A 2) (GET 2) (SHA 3) (WAH 1) (BOOM 1) (JOB 2) (NA 16) (YIP 9))").unwrap();
+    let tree = generate_huffman_tree(&pairs);
+    let song = List::parse(
+        "(GET A JOB SHA NA NA NA NA NA NA NA NA
+          GET A JOB SHA NA NA NA NA NA NA NA NA
+          WAH YIP YIP YIP YIP YIP YIP YIP YIP YIP
+          SHA BOOM)",
+    )
+    .unwrap();
+    let bits = encode(&song, &tree);
+    assert_eq!(bits.length(), 84);
+    assert_eq!(decode(&bits, &tree), song);
+}
+
+#[test]
+fn test_compress_round_trip() {
+    let text = "this is an example of a huffman tree, compressed and decompressed".repeat(20);
+    let inputs: Vec<Vec<u8>> = vec![
+        vec![],
+        vec![42],
+        vec![7; 1000],
+        vec![0, 255],
+        text.into_bytes(),
+        (0..=255u8).cycle().take(5000).collect(),
+        (0..4000u32).map(|i| (i * i % 251) as u8).collect(),
+    ];
+    for input in inputs {
+        let compressed = compress(&input);
+        assert_eq!(decompress(&compressed), Some(input.clone()));
+    }
+}
+
+#[test]
+fn test_compress_shrinks_skewed_input() {
+    let mut input = vec![b'a'; 3000];
+    input.extend_from_slice(&[b'b'; 100]);
+    input.extend_from_slice(b"cdefg");
+    let compressed = compress(&input);
+    assert!(compressed.len() < input.len() / 4);
+    assert_eq!(decompress(&compressed).unwrap(), input);
+}
+
+#[test]
+fn test_decompress_malformed() {
+    let compressed = compress(b"hello, huffman");
+    assert_eq!(decompress(&[]), None);
+    assert_eq!(decompress(&compressed[..compressed.len() - 1]), None);
+    assert_eq!(decompress(&compressed[..12]), None);
+    let mut wrong_len = compressed.clone();
+    wrong_len[0] += 1;
+    assert_eq!(decompress(&wrong_len), None);
+}
+
+// 头部声明的长度远超输入所能编码的字节数
+fn crafted_header(len: u64, counts: &[(u8, u64)], bits: &[u8]) -> Vec<u8> {
+    let mut data = len.to_le_bytes().to_vec();
+    data.extend_from_slice(&(counts.len() as u16).to_le_bytes());
+    for &(b, count) in counts {
+        data.push(b);
+        data.extend_from_slice(&count.to_le_bytes());
+    }
+    data.extend_from_slice(bits);
+    data
+}
+
+#[test]
+fn test_decompress_rejects_untrusted_length() {
+    let huge = 1 << 61;
+    let single = crafted_header(huge, &[(b'a', huge)], &[0, 0]);
+    assert_eq!(single.len(), 21);
+    assert_eq!(decompress(&single), None);
+
+    let pair = crafted_header(huge, &[(b'a', huge - 1), (b'b', 1)], &[0; 4]);
+    assert_eq!(decompress(&pair), None);
+    // 17 个字节至少需要 17 位，而 2 个字节只有 16 位
+    let short = crafted_header(17, &[(b'a', 16), (b'b', 1)], &[0; 2]);
+    assert_eq!(decompress(&short), None);
+    let zero_count = crafted_header(1, &[(b'a', 0), (b'b', 1)], &[0xff]);
+    assert_eq!(decompress(&zero_count), None);
+
+    let fits = crafted_header(16, &[(b'a', 15), (b'b', 1)], &[0, 1]);
+    assert_eq!(decompress(&fits).map(|out| out.len()), Some(16));
+}
@@ -0,0 +1,159 @@
+use sicp_rs::ch2::ch2_5::{
+    ArithmeticContext, DispatchError, install_arithmetic_package, install_dense_terms_package,
+    install_interval_coercion, install_interval_package, install_polynomial_coercion,
+    install_polynomial_package, install_sparse_terms_package, make_center_percent, make_integer,
+    make_interval, make_polynomial_from_dense, make_rational, type_tag,
+};
+use sicp_rs::prelude::*;
+
+fn arith() -> ArithmeticContext {
+    let mut arith = ArithmeticContext::new();
+    install_arithmetic_package(&arith);
+    install_interval_package(&arith);
+    install_interval_coercion(&mut arith);
+    arith
+}
+
+fn value(x: &List) -> f64 {
+    *x.try_as_basis_value::<f64>().unwrap()
+}
+
+fn assert_close(x: f64, y: f64) {
+    assert!((x - y).abs() < 1e-9, "{} != {}", x, y);
+}
+
+fn assert_bounds(arith: &ArithmeticContext, x: &List, lower: f64, upper: f64) {
+    assert_close(value(&arith.lower_bound(x)), lower);
+    assert_close(value(&arith.upper_bound(x)), upper);
+}
+
+#[test]
+fn test_interval_constructors() {
+    let arith = arith();
+    let x = make_interval(3.0, 1.0, &arith);
+    assert_eq!(type_tag(&x), "interval".to_listv());
+    assert_bounds(&arith, &x, 1.0, 3.0);
+    assert_close(value(&arith.center(&x)), 2.0);
+    assert_close(value(&arith.width(&x)), 1.0);
+    assert_close(value(&arith.percent(&x)), 50.0);
+
+    let y = make_center_percent(6.8, 10.0, &arith);
+    assert_bounds(&arith, &y, 6.12, 7.48);
+    assert_close(value(&arith.center(&y)), 6.8);
+    assert_close(value(&arith.percent(&y)), 10.0);
+}
+
+#[test]
+fn test_interval_arithmetic() {
+    let arith = arith();
+    let x = make_interval(1.0, 2.0, &arith);
+    let y = make_interval(-3.0, 4.0, &arith);
+    assert_bounds(&arith, &arith.add(&x, &y), -2.0, 6.0);
+    assert_bounds(&arith, &arith.sub(&x, &y), -3.0, 5.0);
+    assert_bounds(&arith, &arith.mul(&x, &y), -6.0, 8.0);
+    assert_bounds(&arith, &arith.negative(&y), -4.0, 3.0);
+    assert_bounds(&arith, &arith.div(&y, &x), -3.0, 4.0);
+    assert_eq!(
+        arith.is_equal(&x, &make_interval(2.0, 1.0, &arith)),
+        true.to_listv()
+    );
+    assert_eq!(
+        arith.is_equal_to_zero(&make_interval(0.0, 0.0, &arith)),
+        true.to_listv()
+    );
+}
+
+#[test]
+fn test_interval_division_spanning_zero() {
+    let arith = arith();
+    let x = make_interval(1.0, 2.0, &arith);
+    let y = make_interval(-1.0, 1.0, &arith);
+    assert_eq!(
+        arith.try_div(&x, &y),
+        Err(DispatchError::OperationFailed {
+            op: "div".to_string(),
+            types: vec!["interval".to_string(), "interval".to_string()],
+            reason: "division by an interval that spans zero".to_string(),
+        })
+    );
+    assert!(arith.try_div(&x, &make_interval(0.0, 1.0, &arith)).is_err());
+}
+
+#[test]
+fn test_interval_coercion() {
+    let arith = arith();
+    let x = make_interval(1.0, 2.0, &arith);
+    assert_bounds(&arith, &arith.add(&make_integer(1, &arith), &x), 2.0, 3.0);
+    let half = make_rational(1.to_listv(), 2.to_listv(), &arith);
+    assert_bounds(&arith, &arith.mul(&x, &half), 0.5, 1.0);
+    assert_bounds(&arith, &arith.sub(&x, &0.5.to_listv()), 0.5, 1.5);
+}
+
+// 练习 2.14：代数上等价的两个并联电阻公式给出不同的区间
+#[test]
+fn test_parallel_resistors() {
+    let arith = arith();
+    let par1 = |r1: &List, r2: &List| arith.div(&arith.mul(r1, r2), &arith.add(r1, r2));
+    let par2 = |r1: &List, r2: &List| {
+        let one = make_integer(1, &arith);
+        arith.div(&one, &arith.add(&arith.div(&one, r1), &arith.div(&one, r2)))
+    };
+    let r1 = make_center_percent(6.8, 10.0, &arith);
+    let r2 = make_center_percent(4.7, 5.0, &arith);
+
+    let (p1, p2) = (par1(&r1, &r2), par2(&r1, &r2));
+    // r1 = [6.12, 7.48], r2 = [4.465, 4.935]
+    assert_bounds(
+        &arith,
+        &p1,
+        6.12 * 4.465 / (7.48 + 4.935),
+        7.48 * 4.935 / (6.12 + 4.465),
+    );
+    assert_bounds(
+        &arith,
+        &p2,
+        1.0 / (1.0 / 6.12 + 1.0 / 4.465),
+        1.0 / (1.0 / 7.48 + 1.0 / 4.935),
+    );
+    assert!(value(&arith.width(&p2)) < value(&arith.width(&p1)));
+}
+
+#[test]
+fn test_interval_polynomial_coefficients() {
+    let mut arith = arith();
+    install_sparse_terms_package(&arith);
+    install_dense_terms_package(&arith);
+    install_polynomial_package(&arith);
+    install_polynomial_coercion(&mut arith);
+
+    let x = sym!(x).to_listv();
+    let a = make_interval(1.0, 2.0, &arith);
+    let b = make_interval(-1.0, 1.0, &arith);
+    // p = [1, 2]x + 1, q = [-1, 1]x + [1, 2]
+    let p = make_polynomial_from_dense(&x, &list![a.clone(), 1], &arith);
+    let q = make_polynomial_from_dense(&x, &list![b.clone(), a.clone()], &arith);
+
+    let sum = arith.add(&p, &q);
+    let expected = make_polynomial_from_dense(
+        &x,
+        &list![
+            make_interval(0.0, 3.0, &arith),
+            make_interval(2.0, 3.0, &arith)
+        ],
+        &arith,
+    );
+    assert_eq!(arith.is_equal(&sum, &expected), true.to_listv());
+
+    let product = arith.mul(&p, &q);
+    assert_eq!(type_tag(&product), "polynomial".to_listv());
+    let expected = make_polynomial_from_dense(
+        &x,
+        &list![
+            make_interval(-2.0, 2.0, &arith),
+            make_interval(0.0, 5.0, &arith),
+            make_interval(1.0, 2.0, &arith)
+        ],
+        &arith,
+    );
+    assert_eq!(arith.is_equal(&product, &expected), true.to_listv());
+}
@@ -0,0 +1,139 @@
+use std::collections::HashMap;
+
+use sicp_rs::prelude::*;
+
+#[test]
+fn test_assoc_by_equality_kind() {
+    let key = list![1, 2];
+    let s = "name".to_string().to_listv();
+    let alist = list![
+        pair![key.clone(), sym!(list)],
+        pair![s.clone(), sym!(string)],
+        pair![2, sym!(int)],
+        pair![sym!(a), sym!(symbol)]
+    ];
+
+    assert_eq!(
+        alist.assoc(&list![1, 2]),
+        Some(pair![list![1, 2], sym!(list)])
+    );
+    assert_eq!(alist.assv(&list![1, 2]), None);
+    assert_eq!(alist.assq(&key).unwrap().tail(), sym!(list).to_listv());
+
+    assert!(alist.assoc(&"name".to_listv()).is_some());
+    assert!(alist.assq(&"name".to_string().to_listv()).is_none());
+    assert!(alist.assq(&s).is_some());
+
+    assert!(alist.assoc(&2.0.to_listv()).is_some());
+    assert!(alist.assv(&2_u64.to_listv()).is_some());
+    assert!(alist.assv(&2.0.to_listv()).is_none());
+    assert!(alist.assq(&sym!(a).to_listv()).is_some());
+    assert!(List::Nil.assoc(&sym!(a).to_listv()).is_none());
+}
+
+#[test]
+fn test_assoc_entry_is_shared() {
+    let alist = list![pair![sym!(a), 1]];
+    alist
+        .assoc(&sym!(a).to_listv())
+        .unwrap()
+        .set_tail(2.to_listv());
+    assert_eq!(alist, list![pair![sym!(a), 2]]);
+}
+
+#[test]
+fn test_alist_insert_remove() {
+    let a = sym!(a).to_listv();
+    let b = sym!(b).to_listv();
+    let alist = List::Nil
+        .alist_insert(a.clone(), 1.to_listv())
+        .alist_insert(b.clone(), 2.to_listv());
+    let replaced = alist.alist_insert(a.clone(), 3.to_listv());
+    assert_eq!(replaced, list![pair![sym!(a), 3], pair![sym!(b), 2]]);
+    assert_eq!(alist, list![pair![sym!(b), 2], pair![sym!(a), 1]]);
+
+    let dup = list![pair![sym!(a), 1], pair![sym!(b), 2], pair![sym!(a), 3]];
+    assert_eq!(dup.alist_remove(&a), list![pair![sym!(b), 2]]);
+    assert_eq!(dup.alist_remove(&sym!(c).to_listv()), dup);
+    assert_eq!(dup.alist_keys(), list![sym!(a), sym!(b), sym!(a)]);
+}
+
+#[test]
+fn test_alist_update() {
+    let count =
+        |v: Option<List>| (v.map_or(0, |v| *v.try_as_basis_value::<i32>().unwrap()) + 1).to_listv();
+    let words = List::parse("(the cat saw the dog)").unwrap();
+    let counts = words
+        .iter()
+        .fold(List::Nil, |acc, w| acc.alist_update(&w, count));
+    assert_eq!(
+        counts,
+        list![
+            pair![sym!(dog), 1],
+            pair![sym!(saw), 1],
+            pair![sym!(cat), 1],
+            pair![sym!(the), 2]
+        ]
+    );
+
+    // 重复的键：更新第一个表项，移除其余表项
+    let dup = list![pair![sym!(a), 1], pair![sym!(b), 2], pair![sym!(a), 3]];
+    assert_eq!(
+        dup.alist_update(&sym!(a).to_listv(), count),
+        list![pair![sym!(a), 2], pair![sym!(b), 2]]
+    );
+}
+
+#[test]
+fn test_alist_hash_map_conversion() {
+    let alist = list![
+        pair![sym!(a), 1],
+        pair![list![1, 2], "x"],
+        pair![sym!(a), 3]
+    ];
+    let map = alist.alist_to_hash_map();
+    assert_eq!(map.len(), 2);
+    assert_eq!(map[&sym!(a).to_listv()], 1.to_listv());
+    assert_eq!(map[&list![1.0, 2.0]], "x".to_listv());
+
+    let back = List::alist_from_hash_map(&map);
+    assert_eq!(back.length(), 2);
+    assert_eq!(back.alist_to_hash_map(), map);
+    assert_eq!(List::alist_from_hash_map(&HashMap::new()), List::Nil);
+}
+
+#[test]
+fn test_plist() {
+    let plist = list![sym!(color), "red", sym!(size), 3];
+    let size = sym!(size).to_listv();
+    assert_eq!(plist.plist_get(&size), Some(3.to_listv()));
+    assert_eq!(plist.plist_get(&"red".to_listv()), None);
+    assert_eq!(plist.plist_keys(), list![sym!(color), sym!(size)]);
+
+    assert_eq!(
+        plist.plist_put(size.clone(), 4.to_listv()),
+        list![sym!(color), "red", sym!(size), 4]
+    );
+    assert_eq!(
+        plist.plist_put(sym!(shape).to_listv(), sym!(round).to_listv()),
+        list![sym!(color), "red", sym!(size), 3, sym!(shape), sym!(round)]
+    );
+    assert_eq!(plist.plist_remove(&size), list![sym!(color), "red"]);
+    assert_eq!(plist.plist_to_alist().alist_to_plist(), plist);
+    assert_eq!(
+        plist.plist_to_alist(),
+        list![pair![sym!(color), "red"], pair![sym!(size), 3]]
+    );
+}
+
+#[test]
+#[should_panic(expected = "plist has a key without a value")]
+fn test_plist_odd_length() {
+    list![sym!(a), 1, sym!(b)].plist_get(&sym!(b).to_listv());
+}
+
+#[test]
+#[should_panic(expected = "plist has a key without a value")]
+fn test_plist_put_odd_length() {
+    list![sym!(a)].plist_put(sym!(b).to_listv(), 2.to_listv());
+}
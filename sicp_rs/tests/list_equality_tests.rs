@@ -0,0 +1,90 @@
+use std::collections::HashSet;
+
+use num::BigInt;
+use sicp_rs::list_impl::ByIdentity;
+use sicp_rs::prelude::*;
+
+#[test]
+fn test_is_eq() {
+    let x = pair![1, 2];
+    let y = pair![1, 2];
+    assert!(x.is_eq(&x.clone()));
+    assert!(!x.is_eq(&y));
+    // 经 head/tail 取出的同一序对
+    let l = list![x.clone(), x.clone()];
+    assert!(l.head().is_eq(&l.tail().head()));
+
+    let v = "text".to_string().to_listv();
+    assert!(v.is_eq(&v.clone()));
+    assert!(!v.is_eq(&"text".to_string().to_listv()));
+    assert!(!1.to_listv().is_eq(&1.to_listv()));
+
+    assert!(sym!(a).to_listv().is_eq(&List::parse("a").unwrap()));
+    assert!(!sym!(a).to_listv().is_eq(&sym!(b).to_listv()));
+    assert!(true.to_listv().is_eq(&true.to_listv()));
+    assert!(!true.to_listv().is_eq(&false.to_listv()));
+
+    assert!(List::Nil.is_eq(&List::Nil));
+    assert!(List::Nil.is_eq(&list![1].tail()));
+    assert!(!List::Nil.is_eq(&pair![List::Nil, List::Nil]));
+}
+
+#[test]
+fn test_is_eqv() {
+    assert!(2.to_listv().is_eqv(&2.to_listv()));
+    assert!(2.to_listv().is_eqv(&2_u64.to_listv()));
+    assert!(2.to_listv().is_eqv(&BigInt::from(2).to_listv()));
+    assert!(2.5.to_listv().is_eqv(&2.5_f32.to_listv()));
+    assert!(!2.to_listv().is_eqv(&2.0.to_listv()));
+    assert!(!2.to_listv().is_eqv(&3.to_listv()));
+    assert!(!f64::NAN.to_listv().is_eqv(&f64::NAN.to_listv()));
+
+    assert!(!"a".to_listv().is_eqv(&"a".to_listv()));
+    assert!(!list![1].is_eqv(&list![1]));
+    let x = list![1];
+    assert!(x.is_eqv(&x.clone()));
+}
+
+#[test]
+fn test_is_equal() {
+    assert!(list![1, list!["a", 2.0]].is_equal(&list![1, list!["a".to_string(), 2]]));
+    assert!(!list![1, 2].is_equal(&list![1, 2, 3]));
+    assert!(List::Nil.is_equal(&List::Nil));
+    assert!(2.to_listv().is_equal(&2.0.to_listv()));
+}
+
+#[test]
+fn test_nil_unique_id() {
+    let a = List::Nil;
+    let b = list![1].tail();
+    assert_eq!(a.unique_id(), b.unique_id());
+    assert_eq!(ByIdentity(a), ByIdentity(b));
+}
+
+/// 习题 3.17：以 eq? 记录已访问的序对，共享结构只计数一次
+fn count_pairs(x: &List, visited: &mut HashSet<ByIdentity>) -> usize {
+    if x.is_pair() && visited.insert(ByIdentity(x.clone())) {
+        1 + count_pairs(&x.head(), visited) + count_pairs(&x.tail(), visited)
+    } else {
+        0
+    }
+}
+
+#[test]
+fn test_count_pairs() {
+    let x = pair![sym!(a), sym!(b)];
+    let cases = [
+        pair![1, pair![2, pair![3, 4]]],
+        pair![1, pair![x.clone(), pair![3, 4]]],
+        pair![x.clone(), pair![x.clone(), pair![x.clone(), x.clone()]]],
+    ];
+    let counts: Vec<usize> = cases
+        .iter()
+        .map(|l| count_pairs(l, &mut HashSet::new()))
+        .collect();
+    assert_eq!(counts, [3, 4, 4]);
+
+    let y = pair![1, 2];
+    y.set_tail(y.clone());
+    assert_eq!(count_pairs(&y, &mut HashSet::new()), 1);
+}
@@ -0,0 +1,116 @@
+use sicp_rs::list_impl::ListError;
+use sicp_rs::prelude::*;
+
+#[test]
+fn test_try_accessors() {
+    let l = list![1, 2];
+    assert_eq!(l.try_head(), Ok(1.to_listv()));
+    assert_eq!(l.try_tail(), Ok(list![2]));
+    assert_eq!(
+        List::Nil.try_head(),
+        Err(ListError::EmptyList { op: "head" })
+    );
+    assert_eq!(
+        1.to_listv().try_tail(),
+        Err(ListError::WrongVariant {
+            op: "tail",
+            expected: "pair",
+            found: "1".to_string()
+        })
+    );
+
+    assert_eq!(l.try_set_head(3.to_listv()), Ok(()));
+    assert_eq!(l.try_set_tail(List::Nil), Ok(()));
+    assert_eq!(l, list![3]);
+    assert_eq!(
+        List::Nil.try_set_tail(List::Nil),
+        Err(ListError::EmptyList { op: "set_tail" })
+    );
+    assert!(matches!(
+        "x".to_listv().try_set_head(List::Nil),
+        Err(ListError::WrongVariant { op: "set_head", .. })
+    ));
+}
+
+#[test]
+fn test_try_basis_value() {
+    let v = 1.to_listv();
+    assert_eq!(v.try_as_basis_value::<i32>(), Ok(&1));
+    assert_eq!(
+        v.try_as_basis_value::<f64>(),
+        Err(ListError::TypeMismatch {
+            expected: "f64",
+            found: "1".to_string()
+        })
+    );
+    assert!(matches!(
+        List::Nil.try_as_basis_value::<i32>(),
+        Err(ListError::WrongVariant {
+            expected: "value",
+            ..
+        })
+    ));
+    // 只记录变体名称，不格式化整个链表
+    assert_eq!(
+        list![1, 2, 3].try_as_basis_value::<i32>(),
+        Err(ListError::WrongVariant {
+            op: "try_as_basis_value",
+            expected: "value",
+            found: "pair".to_string()
+        })
+    );
+    assert!(v.try_get_basis_value().is_ok());
+    assert!(list![1].try_get_basis_value().is_err());
+}
+
+#[test]
+fn test_try_combinators() {
+    let rows = list![list![1, 2, 3], list![4, 5, 6]];
+    let add = |x: &List, y: List| {
+        (x.try_as_basis_value::<i32>().unwrap() + y.try_as_basis_value::<i32>().unwrap()).to_listv()
+    };
+    assert_eq!(rows.try_accumulate_n(add, 0.to_listv()), Ok(list![5, 7, 9]));
+    assert_eq!(
+        List::Nil.try_accumulate_n(add, 0.to_listv()),
+        Err(ListError::EmptyList { op: "head" })
+    );
+    assert!(list![1, 2].try_accumulate_n(add, 0.to_listv()).is_err());
+    // 长度不一致的子链表
+    assert!(
+        list![list![1, 2], list![3]]
+            .try_accumulate_n(add, 0.to_listv())
+            .is_err()
+    );
+
+    assert_eq!(
+        list![1, list![2, 3]].try_deep_reverse(),
+        Ok(list![list![3, 2], 1])
+    );
+    assert!(matches!(
+        pair![1, 2].try_reverse(),
+        Err(ListError::ImproperList { op: "reverse", .. })
+    ));
+    let cycle = list![1, 2];
+    cycle.last_pair().set_tail(cycle.clone());
+    assert!(matches!(
+        cycle.try_reverse(),
+        Err(ListError::ImproperList { .. })
+    ));
+}
+
+#[test]
+fn test_list_error_display() {
+    let err: Box<dyn std::error::Error> = Box::new(List::Nil.try_head().unwrap_err());
+    assert_eq!(err.to_string(), "head: empty list");
+    assert_eq!(
+        1.to_listv()
+            .try_as_basis_value::<bool>()
+            .unwrap_err()
+            .to_string(),
+        "type mismatch: expected bool, found 1"
+    );
+    assert_eq!(
+        pair![1, 2].try_reverse().unwrap_err().to_string(),
+        "reverse: improper list, found (1, 2)"
+    );
+}
@@ -0,0 +1,95 @@
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{HashMap, HashSet};
+use std::hash::{Hash, Hasher};
+
+use num::{BigInt, BigRational};
+use sicp_rs::list_impl::ByIdentity;
+use sicp_rs::prelude::*;
+
+fn hash_of<T: Hash>(x: &T) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    x.hash(&mut hasher);
+    hasher.finish()
+}
+
+fn assert_same_key(a: List, b: List) {
+    assert_eq!(a, b);
+    assert_eq!(hash_of(&a), hash_of(&b), "{} and {}", a, b);
+}
+
+#[test]
+fn test_hash_agrees_with_eq() {
+    assert_same_key(1.to_listv(), 1_u64.to_listv());
+    assert_same_key(1.to_listv(), 1.0.to_listv());
+    assert_same_key((-3_i8).to_listv(), BigInt::from(-3).to_listv());
+    assert_same_key(
+        0.5.to_listv(),
+        BigRational::new(1.into(), 2.into()).to_listv(),
+    );
+    assert_same_key(u128::MAX.to_listv(), BigInt::from(u128::MAX).to_listv());
+    assert_same_key(0.0.to_listv(), (-0.0).to_listv());
+    assert_same_key("x".to_listv(), "x".to_string().to_listv());
+    assert_same_key(
+        list![1, list!["a", 2.5], List::Nil],
+        list![1_i64, list!["a".to_string(), 2.5_f32], List::Nil],
+    );
+    assert_ne!(hash_of(&list![1, 2]), hash_of(&list![2, 1]));
+    assert_ne!(hash_of(&list![1, list![2]]), hash_of(&list![list![1], 2]));
+}
+
+#[test]
+fn test_float_equality_is_exact() {
+    assert_ne!(1.0.to_listv(), (1.0 + f64::EPSILON).to_listv());
+    assert_ne!(1e-20.to_listv(), 0.0.to_listv());
+    assert_eq!((0.5 + 0.25).to_listv(), 0.75.to_listv());
+}
+
+#[test]
+fn test_list_as_map_key() {
+    let mut table = HashMap::new();
+    table.insert(list![sym!(x), 1], "x1");
+    table.insert(list![sym!(y), 1], "y1");
+    assert_eq!(table.get(&list![sym!(x), 1.0]), Some(&"x1"));
+    assert_eq!(table.get(&list![sym!(y), 1_u8]), Some(&"y1"));
+    assert_eq!(table.get(&list!["x", 1]), None);
+
+    let set: HashSet<List> = [list![1, 2], list![1.0, 2.0], pair![1, 2]]
+        .into_iter()
+        .collect();
+    assert_eq!(set.len(), 2);
+}
+
+#[test]
+fn test_hash_cyclic_lists() {
+    let a = list![1, 2];
+    a.last_pair().set_tail(a.clone());
+    // 与 a 相等的另一种展开形式：(1 2 1 2 ...)
+    let b = list![1, 2, 1, 2];
+    b.last_pair().set_tail(b.tail().tail());
+    assert_same_key(a.clone(), b);
+
+    let mut set = HashSet::new();
+    set.insert(a.clone());
+    assert!(set.contains(&a));
+}
+
+#[test]
+fn test_by_identity() {
+    let x = pair![1, 2];
+    let y = pair![1, 2];
+    let mut visited = HashSet::new();
+    assert!(visited.insert(ByIdentity(x.clone())));
+    assert!(!visited.insert(ByIdentity(x.clone())));
+    assert!(visited.insert(ByIdentity(y.clone())));
+
+    let l = list![x.clone(), x.clone(), y];
+    let distinct: HashSet<ByIdentity> = l.iter().map(ByIdentity).collect();
+    assert_eq!(distinct.len(), 2);
+
+    // head 与 tail 各自取出的子链表共享同一个序对
+    assert_eq!(ByIdentity(l.head()), ByIdentity(l.tail().head()));
+    assert_ne!(ByIdentity(list![1]), ByIdentity(list![1]));
+    assert_eq!(ByIdentity(List::Nil), ByIdentity(list![1].tail()));
+    let v = 1.to_listv();
+    assert_eq!(ByIdentity(v.clone()), ByIdentity(v));
+}
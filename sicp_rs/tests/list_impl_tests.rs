@@ -1,3 +1,4 @@
+use sicp_rs::list_impl::ListError;
 use sicp_rs::prelude::*;
 
 #[test]
@@ -18,6 +19,8 @@ fn test_list_length() {
     let nested = list![1, list![2, 3]];
     assert_eq!(nested.length(), 2);
     assert_eq!(nested.deep_length(), 3);
+    assert_eq!(pair![1, 2].length(), 2);
+    assert_eq!(list![1, 2, 3, 4, 5].length(), 5);
 }
 
 #[test]
@@ -74,6 +77,7 @@ fn test_list_append() {
 }
 
 #[test]
+#[allow(clippy::approx_constant)]
 fn test_try_as_basis_value() {
     let l = list![1, "hello", 3.14];
     assert_eq!(*l.head().try_as_basis_value::<i32>().unwrap(), 1);
@@ -96,3 +100,133 @@ fn test_nested_list_operations() {
     let mapped = nested.map(|x| x.map(|y| (y.try_as_basis_value::<i32>().unwrap() * 2).to_listv()));
     assert_eq!(mapped.to_string(), "((2, (4, Nil)), ((6, (8, Nil)), Nil))");
 }
+
+fn make_cycle(x: &List) -> List {
+    x.last_pair().set_tail(x.clone());
+    x.clone()
+}
+
+#[test]
+fn test_cyclic_display() {
+    let z = make_cycle(&list![sym!(a), sym!(b), sym!(c)]);
+    assert!(z.is_cyclic());
+    assert!(!list![1, 2].is_cyclic());
+    assert_eq!(z.to_string(), "#0=(a, (b, (c, #0#)))");
+    assert_eq!(z.pretty_print(), "#0=(a, b, c . #0#)");
+    assert_eq!(z.to_scheme_string(), "#0=(a b c . #0#)");
+
+    // 环经由头部：x 的 head 指向自身
+    let x = pair![1, 2];
+    x.set_head(x.clone());
+    assert_eq!(x.to_scheme_string(), "#0=(#0# . 2)");
+
+    // 非循环的共享结构仍按原样打印
+    let shared = pair![sym!(a), sym!(b)];
+    let l = list![shared.clone(), shared.clone()];
+    assert!(!l.is_cyclic());
+    assert_eq!(l.to_string(), "((a, b), ((a, b), Nil))");
+    assert_eq!(l.to_scheme_string(), "((a . b) (a . b))");
+    assert_eq!(l.to_scheme_string_shared(), "(#0=(a . b) #0#)");
+    assert_eq!(list![1, list![2, 3]].pretty_print(), "(1, (2, 3))");
+}
+
+#[test]
+fn test_cyclic_equality() {
+    let x = make_cycle(&list![1, 2]);
+    let y = make_cycle(&list![1, 2]);
+    let z = make_cycle(&list![1, 3]);
+    assert_eq!(x, y);
+    assert_ne!(x, z);
+    assert!(x < z);
+    assert_eq!(x.partial_cmp(&y), Some(std::cmp::Ordering::Equal));
+    // 展开一次的环与原环结构相同
+    assert_eq!(x, pair![1, pair![2, x.clone()]]);
+}
+
+#[test]
+#[should_panic(expected = "length called on a cyclic list")]
+fn test_cyclic_length() {
+    make_cycle(&list![1, 2, 3]).length();
+}
+
+#[test]
+fn test_try_length_on_cycles() {
+    assert_eq!(list![1, 2, 3].try_length(), Ok(3));
+    assert!(matches!(
+        make_cycle(&list![1, 2, 3]).try_length(),
+        Err(ListError::ImproperList { op: "length", .. })
+    ));
+    let x = list![1, 2];
+    assert_eq!(x.try_deep_length(), Ok(2));
+    x.set_head(x.clone());
+    assert!(matches!(
+        x.try_deep_length(),
+        Err(ListError::ImproperList {
+            op: "deep_length",
+            ..
+        })
+    ));
+}
+
+#[test]
+#[should_panic(expected = "deep_length called on a cyclic list")]
+fn test_cyclic_deep_length() {
+    let x = list![1, 2];
+    x.set_head(x.clone());
+    x.deep_length();
+}
+
+const LONG: i32 = 100_000;
+
+fn long_list() -> List {
+    List::from_iterator(&mut (0..LONG).map(|i| i.to_listv()))
+}
+
+#[test]
+fn test_long_list_combinators() {
+    // 长链表不应导致栈溢出
+    let l = long_list();
+    assert_eq!(l.length(), LONG as usize);
+    assert_eq!(l.deep_length(), LONG as usize);
+    assert_eq!(l.last_pair(), list![LONG - 1]);
+
+    let doubled = l.map(|x| (x.try_as_basis_value::<i32>().unwrap() * 2).to_listv());
+    assert_eq!(doubled.length(), LONG as usize);
+    assert_eq!(doubled.last_pair(), list![(LONG - 1) * 2]);
+
+    let evens = l.filter(|x| x.try_as_basis_value::<i32>().unwrap() % 2 == 0);
+    assert_eq!(evens.length(), (LONG / 2) as usize);
+
+    let sum = l.accumulate(
+        |x, acc| {
+            let x = *x.try_as_basis_value::<i32>().unwrap() as i64;
+            (x + acc.try_as_basis_value::<i64>().unwrap()).to_listv()
+        },
+        0i64.to_listv(),
+    );
+    assert_eq!(sum, ((LONG as i64) * (LONG as i64 - 1) / 2).to_listv());
+    let count = l.fold_left(|acc, _| acc + 1, 0);
+    assert_eq!(count, LONG);
+
+    let appended = l.append(&list![LONG]);
+    assert_eq!(appended.length(), LONG as usize + 1);
+    assert_eq!(appended.find_index(&LONG.to_listv()), Some(LONG as usize));
+    assert_eq!(l.reverse().head(), (LONG - 1).to_listv());
+    assert_eq!(l, l.map(|x| x.clone()));
+}
+
+#[test]
+fn test_long_list_printing_and_drop() {
+    let l = long_list();
+    let s = l.to_string();
+    assert!(s.starts_with("(0, (1, (2, "));
+    let closing = ")".repeat(LONG as usize - 1);
+    assert!(s.ends_with(&format!("({}, Nil){}", LONG - 1, closing)));
+    assert!(l.to_scheme_string().ends_with(&format!(" {})", LONG - 1)));
+    drop(l);
+
+    // 头部方向嵌套很深的结构同样可以安全释放
+    let nested = (0..LONG).fold(List::Nil, |acc, i| list![acc, i]);
+    assert_eq!(nested.length(), 2);
+    drop(nested);
+}
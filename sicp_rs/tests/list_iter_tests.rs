@@ -0,0 +1,73 @@
+use sicp_rs::prelude::*;
+
+#[test]
+fn test_iter_elements() {
+    let l = list![1, "a", list![2, 3]];
+    let items: Vec<List> = l.iter().collect();
+    assert_eq!(items, vec![1.to_listv(), "a".to_listv(), list![2, 3]]);
+    assert_eq!(List::Nil.iter().count(), 0);
+
+    // 非 Nil 结尾的链表只迭代到最后一个序对
+    assert_eq!(
+        pair![1, pair![2, 3]].iter().collect::<Vec<_>>(),
+        vec![1.to_listv(), 2.to_listv()]
+    );
+    assert_eq!(1.to_listv().iter().count(), 0);
+}
+
+#[test]
+fn test_into_iterator_and_collect() {
+    let l = list![1, 2, 3];
+    let mut sum = 0;
+    for x in &l {
+        sum += x.try_as_basis_value::<i32>().unwrap();
+    }
+    assert_eq!(sum, 6);
+
+    let zipped: List = l
+        .iter()
+        .zip(list!["a", "b", "c"])
+        .map(|(n, s)| List::pair(n, s))
+        .collect();
+    assert_eq!(zipped, list![pair![1, "a"], pair![2, "b"], pair![3, "c"]]);
+
+    let small: List = l
+        .into_iter()
+        .take_while(|x| *x.try_as_basis_value::<i32>().unwrap() < 3)
+        .collect();
+    assert_eq!(small, list![1, 2]);
+    assert_eq!(std::iter::empty().collect::<List>(), List::Nil);
+
+    let items: Vec<List> = list![1, 2, 3, 4].iter().collect();
+    let sums: List = items
+        .windows(2)
+        .map(|w| {
+            let a = w[0].try_as_basis_value::<i32>().unwrap();
+            let b = w[1].try_as_basis_value::<i32>().unwrap();
+            (a + b).to_listv()
+        })
+        .collect();
+    assert_eq!(sums, list![3, 5, 7]);
+}
+
+#[test]
+fn test_pairs_mutation() {
+    let l = list![1, 2, 3];
+    assert_eq!(l.pairs().count(), 3);
+    for (i, cell) in l.pairs().enumerate() {
+        cell.set_head((i as i32 * 10).to_listv());
+    }
+    assert_eq!(l, list![0, 10, 20]);
+
+    // 截断第二个序对之后的部分
+    l.pairs().nth(1).unwrap().set_tail(List::Nil);
+    assert_eq!(l, list![0, 10]);
+}
+
+#[test]
+fn test_iter_cyclic() {
+    let l = list![1, 2];
+    l.last_pair().set_tail(l.clone());
+    let items: List = l.iter().take(5).collect();
+    assert_eq!(items, list![1, 2, 1, 2, 1]);
+}
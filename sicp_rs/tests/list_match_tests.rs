@@ -0,0 +1,136 @@
+use sicp_rs::prelude::*;
+
+fn describe(exp: &List) -> String {
+    match_list!(exp, {
+        ("+", a, b) => format!("sum of {} and {}", a, b),
+        ("*", _, _) => "product".to_string(),
+        ("neg", n: i32) => format!("negative {}", -n),
+        ("name", s: String) => format!("named {}", s),
+        ((x, y) . rest) => format!("point {} {} then {}", x, y, rest.length()),
+        (op . args) => format!("{} with {} args", op, args.length()),
+        () => "empty".to_string(),
+        _ => "atom".to_string(),
+    })
+}
+
+#[test]
+fn test_match_shapes() {
+    assert_eq!(describe(&list!["+", 1, "x"]), "sum of 1 and x");
+    assert_eq!(describe(&list!["*", 2, 3]), "product");
+    assert_eq!(describe(&list!["neg", 5]), "negative -5");
+    assert_eq!(describe(&list!["name", "abc".to_string()]), "named abc");
+    assert_eq!(describe(&list![list![1, 2], 3, 4]), "point 1 2 then 2");
+    assert_eq!(describe(&List::Nil), "empty");
+    assert_eq!(describe(&1.to_listv()), "atom");
+}
+
+#[test]
+fn test_match_falls_through_on_mismatch() {
+    // 元素个数不符
+    assert_eq!(describe(&list!["+", 1]), "+ with 1 args");
+    assert_eq!(describe(&list!["+", 1, 2, 3]), "+ with 3 args");
+    // 类型不符
+    assert_eq!(describe(&list!["neg", 1.5]), "neg with 1 args");
+    assert_eq!(describe(&list!["name", "sym"]), "name with 1 args");
+    // 子模式不符
+    assert_eq!(describe(&list![list![1], 2]), "(1, Nil) with 1 args");
+    // 非 Nil 结尾
+    assert_eq!(describe(&pair!["*", pair![1, 2]]), "* with 2 args");
+}
+
+#[test]
+fn test_match_alternatives_and_blocks() {
+    let simplify = |exp: &List| {
+        match_list!(exp, {
+            ("+", 0, x) | ("+", x, 0) => x,
+            ("*", a: i32, b: i32) => {
+                let product = a * b;
+                product.to_listv()
+            }
+            (_, _ . _) => exp.clone(),
+        })
+    };
+    assert_eq!(simplify(&list!["+", 0, "x"]), "x".to_listv());
+    assert_eq!(simplify(&list!["+", "y", 0]), "y".to_listv());
+    assert_eq!(simplify(&list!["*", 3, 4]), 12.to_listv());
+    assert_eq!(simplify(&list!["-", 1, 2]), list!["-", 1, 2]);
+}
+
+#[test]
+fn test_match_nested_and_control_flow() {
+    let terms = list![list![2, 3], list![0, 1], "x", list![1, 5]];
+    let mut total = 0;
+    for term in &terms {
+        let (order, coeff) = match_list!(term, {
+            (order: i32, coeff: i32) => (order, coeff),
+            _ => continue,
+        });
+        // 分支体内可以再次使用 match_list!
+        total += match_list!(list![order, coeff], {
+            (0, _) => 0,
+            (_, c: i32) => c,
+        });
+    }
+    assert_eq!(total, 8);
+}
+
+#[test]
+fn test_match_literals() {
+    let classify = |l: &List| {
+        match_list!(l, {
+            (true, x) => format!("yes {}", x),
+            (false, _) => "no".to_string(),
+            (-1 . _) => "minus one".to_string(),
+            (2.5, s: String) => s,
+            _ => "other".to_string(),
+        })
+    };
+    assert_eq!(classify(&list![true, 1]), "yes 1");
+    assert_eq!(classify(&list![false, 1]), "no");
+    assert_eq!(classify(&list![-1, 2, 3]), "minus one");
+    assert_eq!(classify(&list![2.5, "s".to_string()]), "s");
+    assert_eq!(classify(&list![1, 2]), "other");
+}
+
+#[test]
+fn test_match_typed_element_before_dotted_tail() {
+    let scale = |l: &List| {
+        match_list!(l, {
+            (op, n: i32 . rest) => list![op, n * 2, rest.length() as i32],
+            (n: Vec<i32> . _) => (n.len() as i32).to_listv(),
+            _ => List::Nil,
+        })
+    };
+    assert_eq!(scale(&list!["*", 3, 4, 5]), list!["*", 6, 2]);
+    assert_eq!(scale(&list!["*", 3]), list!["*", 6, 0]);
+    assert_eq!(scale(&list![vec![1, 2, 3]]), 3.to_listv());
+    assert_eq!(scale(&list!["*", "x"]), List::Nil);
+}
+
+#[test]
+fn test_match_symbol_literals() {
+    let describe = |exp: &List| {
+        match_list!(exp, {
+            (sym!("+"), a: i32, b: i32) => format!("sum {}", a + b),
+            (sym!(quote), x) => format!("quoted {}", x),
+            ("+" . _) => "string +".to_string(),
+            _ => "other".to_string(),
+        })
+    };
+    let parse = |s: &str| List::parse(s).unwrap();
+    assert_eq!(describe(&parse("(+ 1 2)")), "sum 3");
+    assert_eq!(describe(&parse("'x")), "quoted x");
+    // 符号与字符串互不匹配
+    assert_eq!(describe(&list!["+", 1, 2]), "string +");
+    assert_eq!(describe(&parse("(\"+\" 1 2)")), "string +");
+    assert_eq!(describe(&parse("(- 1 2)")), "other");
+}
+
+#[test]
+#[should_panic(expected = "match_list: no pattern matched")]
+fn test_match_no_arm() {
+    let l = list![1, 2];
+    let _: i32 = match_list!(l, {
+        (a: i32) => a,
+    });
+}
@@ -0,0 +1,106 @@
+use sicp_rs::prelude::*;
+
+#[test]
+fn test_scheme_string_atoms() {
+    assert_eq!(List::Nil.to_scheme_string(), "()");
+    assert_eq!(42.to_listv().to_scheme_string(), "42");
+    assert_eq!(2.0.to_listv().to_scheme_string(), "2.0");
+    assert_eq!(f64::NAN.to_listv().to_scheme_string(), "+nan.0");
+    assert_eq!(true.to_listv().to_scheme_string(), "#t");
+    assert_eq!(sym!(x).to_listv().to_scheme_string(), "x");
+    assert_eq!("x".to_listv().to_scheme_string(), r#""x""#);
+    assert_eq!(
+        "say \"hi\"\n".to_string().to_listv().to_scheme_string(),
+        r#""say \"hi\"\n""#
+    );
+    assert_eq!(
+        sym!("two words").to_listv().to_scheme_string(),
+        "|two words|"
+    );
+    assert_eq!(sym!("42").to_listv().to_scheme_string(), "|42|");
+}
+
+#[test]
+fn test_scheme_string_lists() {
+    assert_eq!(list![1, 2, 3].to_scheme_string(), "(1 2 3)");
+    assert_eq!(pair![1, 2].to_scheme_string(), "(1 . 2)");
+    assert_eq!(pair![1, pair![2, 3]].to_scheme_string(), "(1 2 . 3)");
+    assert_eq!(
+        list![list![1, 2], List::Nil, list![sym!(quote), sym!(x)]].to_scheme_string(),
+        "((1 2) () 'x)"
+    );
+}
+
+#[test]
+fn test_pretty_scheme() {
+    let l = List::parse("(define (deriv exp var) (cond (number? exp) 0))").unwrap();
+    assert_eq!(
+        l.pretty_scheme(80),
+        "(define (deriv exp var) (cond (number? exp) 0))"
+    );
+    assert_eq!(
+        l.pretty_scheme(30),
+        "(define (deriv exp var)\n        (cond (number? exp) 0))"
+    );
+    assert_eq!(
+        l.pretty_scheme(20),
+        "(define (deriv exp\n               var)\n        (cond (number? exp)\n              0))"
+    );
+    assert_eq!(
+        list![list![1, 2], list![3, 4]].pretty_scheme(8),
+        "((1 2)\n (3 4))"
+    );
+}
+
+#[test]
+fn test_scheme_round_trip() {
+    let data = [
+        list![
+            1,
+            -2,
+            2.5,
+            sym!(sym),
+            "str".to_string(),
+            "s",
+            true,
+            List::Nil
+        ],
+        pair![list![sym!(a), pair![1, 2]], sym!(b)],
+        list![sym!(quote), list![sym!("+"), sym!(x), 1e-7]],
+        list![
+            sym!("|odd|"),
+            sym!(""),
+            sym!("."),
+            sym!("+inf.0"),
+            10000000000_i64
+        ],
+    ];
+    let inf = List::parse(&f64::NEG_INFINITY.to_listv().to_scheme_string()).unwrap();
+    assert_eq!(*inf.try_as_basis_value::<f64>().unwrap(), f64::NEG_INFINITY);
+    for l in data {
+        let text = l.to_scheme_string();
+        assert_eq!(List::parse(&text).unwrap(), l, "round trip of {}", text);
+        let pretty = l.pretty_scheme(10);
+        assert_eq!(List::parse(&pretty).unwrap(), l, "round trip of {}", pretty);
+    }
+}
+
+#[test]
+fn test_pretty_scheme_labels_and_quotes() {
+    let x = list![sym!(define), list![sym!(f), sym!(x)], sym!(x)];
+    x.last_pair().set_tail(x.clone());
+    let shared = list![sym!(quote), list![1, 2, 3]];
+    let l = list![x.clone(), shared.clone(), x.clone(), shared];
+    assert_eq!(
+        l.to_scheme_string(),
+        "(#0=(define (f x) x . #0#) '(1 2 3) #0# '(1 2 3))"
+    );
+    assert_eq!(
+        l.pretty_scheme(40),
+        "(#0=(define (f x) x . #0#)\n '(1 2 3)\n #0#\n '(1 2 3))"
+    );
+    assert_eq!(
+        l.pretty_scheme(12),
+        "(#0=(define (f x)\n            x\n            . #0#)\n '(1 2 3)\n #0#\n '(1 2 3))"
+    );
+}
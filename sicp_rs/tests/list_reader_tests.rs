@@ -0,0 +1,114 @@
+use sicp_rs::prelude::*;
+
+#[test]
+fn test_parse_atoms() {
+    assert_eq!(List::parse("42").unwrap(), 42.to_listv());
+    assert_eq!(List::parse("-7").unwrap(), (-7).to_listv());
+    assert_eq!(List::parse("2.5").unwrap(), 2.5.to_listv());
+    assert_eq!(List::parse("-1e-3").unwrap(), (-1e-3).to_listv());
+    assert_eq!(
+        List::parse("10000000000").unwrap(),
+        10000000000_i64.to_listv()
+    );
+    assert_eq!(List::parse("#t").unwrap(), true.to_listv());
+    assert_eq!(
+        List::parse("\"a \\\"b\\\"\\n\"").unwrap(),
+        "a \"b\"\n".to_listv()
+    );
+    assert_eq!(
+        List::parse("make-sum").unwrap(),
+        sym!("make-sum").to_listv()
+    );
+    assert_eq!(List::parse("-").unwrap(), sym!("-").to_listv());
+    assert_eq!(List::parse("nan").unwrap(), sym!(nan).to_listv());
+    assert_eq!(
+        List::parse("|two words|").unwrap(),
+        sym!("two words").to_listv()
+    );
+    assert!(
+        List::parse("x")
+            .unwrap()
+            .try_as_basis_value::<Symbol>()
+            .is_ok()
+    );
+    assert!(
+        List::parse("\"x\"")
+            .unwrap()
+            .try_as_basis_value::<String>()
+            .is_ok()
+    );
+    assert_eq!(List::parse("()").unwrap(), List::Nil);
+    assert_ne!(List::parse("x").unwrap(), List::parse("\"x\"").unwrap());
+}
+
+#[test]
+fn test_parse_nested_and_dotted() {
+    let exp = List::parse("(+ x (* 3 y))").unwrap();
+    assert_eq!(exp, list![sym!("+"), sym!(x), list![sym!("*"), 3, sym!(y)]]);
+
+    assert_eq!(List::parse("(1 . 2)").unwrap(), pair![1, 2]);
+    assert_eq!(List::parse("(1 2 . 3)").unwrap(), pair![1, pair![2, 3]]);
+    assert_eq!(List::parse("(1 . (2 3))").unwrap(), list![1, 2, 3]);
+}
+
+#[test]
+fn test_parse_quote_and_comments() {
+    let exp = List::parse("; derivative input\n'(x ; the variable\n y)").unwrap();
+    assert_eq!(exp, list![sym!(quote), list![sym!(x), sym!(y)]]);
+    assert_eq!(
+        List::parse("`(a ,b ,@c)").unwrap(),
+        list![
+            sym!(quasiquote),
+            list![
+                sym!(a),
+                list![sym!(unquote), sym!(b)],
+                list![sym!("unquote-splicing"), sym!(c)]
+            ]
+        ]
+    );
+}
+
+#[test]
+fn test_parse_all() {
+    let data = List::parse_all("(define x 1)\n(* x 2) ; done").unwrap();
+    assert_eq!(data.length(), 2);
+    assert_eq!(data.tail().head(), list![sym!("*"), sym!(x), 2]);
+    assert_eq!(List::parse_all("  ; nothing\n").unwrap(), List::Nil);
+}
+
+#[test]
+fn test_parse_errors() {
+    let err = List::parse("(1 2").unwrap_err();
+    assert_eq!((err.line, err.column), (1, 1));
+
+    let err = List::parse("(1\n 2))").unwrap_err();
+    assert_eq!((err.line, err.column), (2, 4));
+
+    let err = List::parse("(1 . 2 3)").unwrap_err();
+    assert_eq!((err.line, err.column), (1, 8));
+
+    for bad in ["", "( . 1)", "(1 . )", "'", "\"abc", "\"\\q\""] {
+        assert!(List::parse(bad).is_err(), "{:?} should not parse", bad);
+    }
+    assert_eq!(
+        List::parse(")").unwrap_err().to_string(),
+        "1:1: unexpected ')'"
+    );
+}
+
+#[test]
+fn test_parse_deep_nesting() {
+    let depth = 200_000;
+    let text = format!("{}1{}", "(".repeat(depth), ")".repeat(depth));
+    let mut x = List::parse(&text).unwrap();
+    for _ in 0..depth {
+        x = x.head();
+    }
+    assert_eq!(x, 1.to_listv());
+
+    let quoted = format!("{}x", "'".repeat(depth));
+    assert!(List::parse(&quoted).is_ok());
+
+    let err = List::parse(&"(".repeat(depth)).unwrap_err();
+    assert_eq!((err.line, err.column), (1, depth));
+}
@@ -0,0 +1,139 @@
+#![cfg(feature = "serde")]
+
+use sicp_rs::ch3::ch3_3::{insert, lookup, make_table};
+use sicp_rs::prelude::*;
+
+fn round_trip(l: &List) -> List {
+    let json = serde_json::to_string(l).unwrap();
+    serde_json::from_str(&json).unwrap()
+}
+
+#[test]
+fn test_serialize_proper_list() {
+    let l = list![1, list![2.5, "s".to_string()], true, List::Nil];
+    assert_eq!(
+        serde_json::to_string(&l).unwrap(),
+        r#"[1,[2.5,"s"],true,[]]"#
+    );
+    assert_eq!(serde_json::to_string(&List::Nil).unwrap(), "[]");
+    assert_eq!(round_trip(&l), l);
+}
+
+#[test]
+fn test_round_trip_value_types() {
+    let l = list![
+        1,
+        2.5,
+        sym!(sym),
+        "text".to_string(),
+        false,
+        1i8,
+        2i16,
+        3i64,
+        4i128,
+        5u8,
+        6u16,
+        7u32,
+        8u64,
+        9u128,
+        1.5f32
+    ];
+    let back = round_trip(&l);
+    assert_eq!(back, l);
+    assert!(
+        back.iter()
+            .nth(2)
+            .unwrap()
+            .try_as_basis_value::<Symbol>()
+            .is_ok()
+    );
+    assert!(
+        back.iter()
+            .nth(7)
+            .unwrap()
+            .try_as_basis_value::<i64>()
+            .is_ok()
+    );
+    assert!(
+        back.iter()
+            .nth(14)
+            .unwrap()
+            .try_as_basis_value::<f32>()
+            .is_ok()
+    );
+}
+
+#[test]
+fn test_deserialize_widens_large_integers() {
+    let l: List = serde_json::from_str("[1, 5000000000, 18446744073709551615]").unwrap();
+    assert_eq!(
+        l,
+        list![1, 5_000_000_000i64, 18_446_744_073_709_551_615i128]
+    );
+}
+
+#[test]
+fn test_round_trip_improper_list() {
+    let l = pair![1, pair![2, 3]];
+    let json = serde_json::to_string(&l).unwrap();
+    assert_eq!(json, r#"{"list":[1,2],"tail":3}"#);
+    assert_eq!(round_trip(&l), l);
+}
+
+#[test]
+fn test_round_trip_shared_structure() {
+    let shared = list![1, 2];
+    let l = list![shared.clone(), shared];
+    let back = round_trip(&l);
+    assert_eq!(back, l);
+    assert_eq!(back.to_scheme_string_shared(), l.to_scheme_string_shared());
+    assert_eq!(back.head().unique_id(), back.tail().head().unique_id());
+}
+
+#[test]
+fn test_round_trip_cyclic_list() {
+    let l = list![1, 2, 3];
+    l.last_pair().set_tail(l.clone());
+    let json = serde_json::to_string(&l).unwrap();
+    assert_eq!(json, r#"{"id":0,"list":[1,2,3],"tail":{"ref":0}}"#);
+    let back = round_trip(&l);
+    assert_eq!(back.to_scheme_string(), l.to_scheme_string());
+    assert_eq!(back.tail().tail().tail().unique_id(), back.unique_id());
+}
+
+#[test]
+fn test_round_trip_table() {
+    let table = make_table();
+    insert(&"a".to_listv(), 1.to_listv(), &table);
+    insert(&"b".to_listv(), list![2, 3], &table);
+    let back = round_trip(&table);
+    assert_eq!(back, table);
+    assert_eq!(lookup(&"b".to_listv(), &back), Some(list![2, 3]));
+}
+
+#[test]
+fn test_serialize_unsupported_value() {
+    let l = list![ClosureWrapper::new(|x: &List| Some(x.clone()))];
+    assert!(serde_json::to_string(&l).is_err());
+}
+
+#[test]
+fn test_deserialize_errors() {
+    assert!(serde_json::from_str::<List>(r#"{"ref":0}"#).is_err());
+    assert!(serde_json::from_str::<List>(r#"{"list":[1],"id":0}"#).is_err());
+    assert!(serde_json::from_str::<List>(r#"{"list":[]}"#).is_err());
+    assert!(serde_json::from_str::<List>(r#"{"other":1}"#).is_err());
+    assert!(serde_json::from_str::<List>("null").is_err());
+}
+
+#[test]
+fn test_round_trip_big_numbers() {
+    let l = List::parse("(123456789012345678901234567890123456789012 -1/3)").unwrap();
+    let json = serde_json::to_string(&l).unwrap();
+    assert_eq!(
+        json,
+        r#"[{"bigint":"123456789012345678901234567890123456789012"},{"bigrational":"-1/3"}]"#
+    );
+    assert_eq!(round_trip(&l), l);
+    assert!(serde_json::from_str::<List>(r#"{"bigint":"x"}"#).is_err());
+}
@@ -0,0 +1,147 @@
+use std::cmp::Ordering;
+
+use sicp_rs::ch2::ch2_3::{ordered_set, tree_set, unordered_set};
+use sicp_rs::prelude::*;
+
+fn nums(xs: &[i32]) -> List {
+    xs.iter().map(|&x| x.to_listv()).collect()
+}
+
+#[test]
+fn test_sort() {
+    assert_eq!(nums(&[3, 1, 2, 5, 4]).sort(), nums(&[1, 2, 3, 4, 5]));
+    assert_eq!(list![2, 0.5, 1_u64, -3_i8].sort(), list![-3, 0.5, 1, 2]);
+    assert_eq!(list!["b", "c", "a"].sort(), list!["a", "b", "c"]);
+    assert_eq!(List::Nil.sort(), List::Nil);
+    assert_eq!(
+        list![list![2, 1], list![1, 9]].sort(),
+        list![list![1, 9], list![2, 1]]
+    );
+
+    let l = nums(&[3, 1, 2]);
+    let _ = l.sort();
+    assert_eq!(l, nums(&[3, 1, 2]));
+}
+
+#[test]
+fn test_sort_by_is_stable() {
+    let l = list![pair![1, "a"], pair![0, "b"], pair![1, "c"], pair![0, "d"]];
+    let by_key = |a: &List, b: &List| a.head().partial_cmp(&b.head()).unwrap();
+    assert_eq!(
+        l.sort_by(by_key),
+        list![pair![0, "b"], pair![0, "d"], pair![1, "a"], pair![1, "c"]]
+    );
+    assert_eq!(
+        l.sort_by(|a, b| by_key(b, a)),
+        list![pair![1, "a"], pair![1, "c"], pair![0, "b"], pair![0, "d"]]
+    );
+
+    // 与 2.0 相等的 2 保持原有顺序
+    let mixed = list![2.0, 1, 2];
+    let sorted = mixed.sort();
+    assert!(sorted.tail().head().is_float_value());
+    assert_eq!(mixed.sort_by(|_, _| Ordering::Equal), mixed);
+}
+
+#[test]
+#[should_panic(expected = "sort found incomparable elements")]
+fn test_sort_incomparable() {
+    list![1, "a"].sort();
+}
+
+#[test]
+#[should_panic(expected = "sort_by called on an improper list")]
+fn test_sort_improper() {
+    pair![2, 1].sort();
+}
+
+#[test]
+fn test_member_remove_dedup() {
+    let l = list![1, list![2], "x", 1];
+    assert_eq!(l.member(&list![2.0]), Some(list![list![2], "x", 1]));
+    assert_eq!(l.member(&2.to_listv()), None);
+    // member 返回的子链表与原链表共享
+    l.member(&"x".to_listv())
+        .unwrap()
+        .set_head(sym!(y).to_listv());
+    assert_eq!(l, list![1, list![2], sym!(y), 1]);
+
+    assert_eq!(l.remove(&1.0.to_listv()), list![list![2], sym!(y)]);
+    assert_eq!(l.remove(&3.to_listv()), l);
+    assert_eq!(
+        list![1, 2, 1.0, list![1], 2, list![1_u8]].delete_duplicates(),
+        list![1, 2, list![1]]
+    );
+    assert_eq!(List::Nil.delete_duplicates(), List::Nil);
+}
+
+#[test]
+fn test_unordered_set() {
+    use unordered_set::*;
+    let s1 = nums(&[3, 1, 2]);
+    let s2 = nums(&[4, 2, 3]);
+    assert!(element_of_set(&1.to_listv(), &s1));
+    assert!(!element_of_set(&4.to_listv(), &s1));
+    assert_eq!(adjoin_set(&1.to_listv(), &s1), s1);
+    assert_eq!(adjoin_set(&5.to_listv(), &s1), nums(&[5, 3, 1, 2]));
+    assert_eq!(intersection_set(&s1, &s2), nums(&[3, 2]));
+    assert_eq!(union_set(&s1, &s2), nums(&[1, 4, 2, 3]));
+    assert_eq!(union_set(&List::Nil, &s2), s2);
+}
+
+#[test]
+fn test_ordered_set() {
+    use ordered_set::*;
+    let s1 = nums(&[1, 3, 5, 7]);
+    let s2 = nums(&[2, 3, 4, 7, 9]);
+    assert!(element_of_set(&5.to_listv(), &s1));
+    assert!(!element_of_set(&4.to_listv(), &s1));
+    assert!(!element_of_set(&8.to_listv(), &s1));
+    assert_eq!(adjoin_set(&4.to_listv(), &s1), nums(&[1, 3, 4, 5, 7]));
+    assert_eq!(adjoin_set(&0.to_listv(), &s1), nums(&[0, 1, 3, 5, 7]));
+    assert_eq!(adjoin_set(&8.to_listv(), &s1), nums(&[1, 3, 5, 7, 8]));
+    assert_eq!(adjoin_set(&3.to_listv(), &s1), s1);
+    assert_eq!(intersection_set(&s1, &s2), nums(&[3, 7]));
+    assert_eq!(union_set(&s1, &s2), nums(&[1, 2, 3, 4, 5, 7, 9]));
+    assert_eq!(union_set(&s1, &List::Nil), s1);
+}
+
+#[test]
+fn test_tree_set() {
+    use tree_set::*;
+    // 图 2.16 中的三棵树表示同一集合 {1, 3, 5, 7, 9, 11}
+    let t = list_to_tree(&nums(&[1, 3, 5, 7, 9, 11]));
+    assert_eq!(
+        t,
+        List::parse("(5 (1 () (3 () ())) (9 (7 () ()) (11 () ())))").unwrap()
+    );
+    assert_eq!(tree_to_list(&t), nums(&[1, 3, 5, 7, 9, 11]));
+    assert!(element_of_set(&7.to_listv(), &t));
+    assert!(!element_of_set(&8.to_listv(), &t));
+    assert_eq!(
+        tree_to_list(&adjoin_set(&8.to_listv(), &t)),
+        nums(&[1, 3, 5, 7, 8, 9, 11])
+    );
+    assert_eq!(adjoin_set(&7.to_listv(), &t), t);
+
+    let other = [2, 3, 4, 9]
+        .iter()
+        .fold(List::Nil, |set, &x| adjoin_set(&x.to_listv(), &set));
+    assert_eq!(
+        tree_to_list(&union_set(&t, &other)),
+        nums(&[1, 2, 3, 4, 5, 7, 9, 11])
+    );
+    assert_eq!(tree_to_list(&intersection_set(&t, &other)), nums(&[3, 9]));
+    assert_eq!(intersection_set(&t, &List::Nil), List::Nil);
+}
+
+#[test]
+fn test_large_sets() {
+    let evens: List = (0..20000).step_by(2).map(|x: i32| x.to_listv()).collect();
+    let threes: List = (0..20000).step_by(3).map(|x: i32| x.to_listv()).collect();
+    let both = ordered_set::intersection_set(&evens, &threes);
+    assert_eq!(both.length(), (0..20000).step_by(6).count());
+    let tree = tree_set::list_to_tree(&ordered_set::union_set(&evens, &threes));
+    assert!(tree_set::element_of_set(&9999.to_listv(), &tree));
+    assert!(!tree_set::element_of_set(&9997.to_listv(), &tree));
+}
@@ -0,0 +1,47 @@
+use std::collections::HashSet;
+
+use sicp_rs::ch2::ch2_3::{is_sum, is_variable, make_product, make_sum};
+use sicp_rs::prelude::*;
+
+#[test]
+fn test_symbols_are_interned() {
+    let a = Symbol::new("make-sum");
+    let b = Symbol::new(&String::from("make-sum"));
+    assert_eq!(a, b);
+    assert!(std::ptr::eq(a.as_str(), b.as_str()));
+    assert_ne!(a, sym!(x));
+    assert_eq!(sym!(x), Symbol::from("x"));
+
+    let set: HashSet<Symbol> = [sym!(x), sym!(y), sym!(x)].into_iter().collect();
+    assert_eq!(set.len(), 2);
+    assert!(sym!(a) < sym!(b));
+}
+
+#[test]
+fn test_symbol_values_in_lists() {
+    let l = list![sym!(x), "x", "x".to_string()];
+    assert!(l.head().is_symbol());
+    assert!(!l.tail().head().is_symbol());
+    assert!(l.tail().head().is_string_value());
+    assert_ne!(l.head(), l.tail().head());
+    assert_ne!(l.head(), l.tail().tail().head());
+    assert_eq!(l.to_string(), "(x, (x, (x, Nil)))");
+    assert_eq!(l.to_scheme_string(), r#"(x "x" "x")"#);
+    assert!(!List::Nil.is_symbol());
+    assert!(!list![sym!(x)].is_symbol());
+}
+
+#[test]
+fn test_variables_are_symbols() {
+    assert!(is_variable(&sym!(x).to_listv()));
+    assert!(!is_variable(&"x".to_listv()));
+    assert!(!is_variable(&"x".to_string().to_listv()));
+
+    let exp = make_sum::<i32>(
+        sym!(x).to_listv(),
+        make_product::<i32>(2.to_listv(), sym!(y).to_listv()),
+    );
+    assert!(is_sum(&exp));
+    assert_eq!(exp, List::parse("(+ x (* 2 y))").unwrap());
+    assert!(!is_sum(&list!["+", 1, 2]));
+}
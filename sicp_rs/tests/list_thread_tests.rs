@@ -0,0 +1,73 @@
+#![cfg(feature = "multi_threaded")]
+
+use std::thread;
+
+use sicp_rs::ch2::ch2_5::{ArithmeticContext, install_arithmetic_package, make_integer};
+use sicp_rs::list_impl::{InnerCell, InnerCellExt, Shared};
+use sicp_rs::prelude::*;
+
+fn assert_send_sync<T: Send + Sync>() {}
+
+#[test]
+fn test_types_are_send_sync() {
+    assert_send_sync::<List>();
+    assert_send_sync::<ClosureWrapper>();
+    assert_send_sync::<ArithmeticContext>();
+}
+
+#[test]
+fn test_share_list_between_threads() {
+    let l = list![1, 2, 3];
+    let handles: Vec<_> = (0..4)
+        .map(|i| {
+            let l = l.clone();
+            thread::spawn(move || {
+                l.map(|x| (x.try_as_basis_value::<i32>().unwrap() * i).to_listv())
+            })
+        })
+        .collect();
+    let results: Vec<List> = handles.into_iter().map(|h| h.join().unwrap()).collect();
+    assert_eq!(results[3], list![3, 6, 9]);
+
+    // 其他线程对序对的修改在本线程可见
+    let cell = l.clone();
+    thread::spawn(move || cell.set_head("a".to_listv()))
+        .join()
+        .unwrap();
+    assert_eq!(l, list!["a", 2, 3]);
+}
+
+#[test]
+fn test_shared_counter_closure() {
+    let balance = Shared::new(InnerCell::new(100));
+    let withdraw = ClosureWrapper::new({
+        let balance = balance.clone();
+        move |x: &List| {
+            let mut b = balance.write_cell();
+            *b -= x.try_as_basis_value::<i32>().unwrap();
+            Some((*b).to_listv())
+        }
+    });
+    let handles: Vec<_> = (0..10)
+        .map(|_| {
+            let withdraw = withdraw.clone();
+            thread::spawn(move || withdraw.call(&5.to_listv()))
+        })
+        .collect();
+    handles.into_iter().for_each(|h| {
+        h.join().unwrap();
+    });
+    assert_eq!(*balance.read_cell(), 50);
+}
+
+#[test]
+fn test_arithmetic_context_between_threads() {
+    let arith = ArithmeticContext::new();
+    install_arithmetic_package(&arith);
+    let worker = arith.clone();
+    let sum =
+        thread::spawn(move || worker.add(&make_integer(1, &worker), &make_integer(2, &worker)))
+            .join()
+            .unwrap();
+    assert_eq!(sum, make_integer(3, &arith));
+}
@@ -1,4 +1,8 @@
-use sicp_rs::prelude::ListV;
+use std::cmp::Ordering;
+use std::str::FromStr;
+
+use num::{BigInt, BigRational};
+use sicp_rs::prelude::*;
 
 #[test]
 fn test_listv_is_string() {
@@ -15,6 +19,7 @@ fn test_listv_is_integer() {
 }
 
 #[test]
+#[allow(clippy::approx_constant)]
 fn test_listv_partial_eq() {
     let a: Box<dyn ListV> = Box::new(42);
     let b: Box<dyn ListV> = Box::new(42);
@@ -26,6 +31,7 @@ fn test_listv_partial_eq() {
 }
 
 #[test]
+#[allow(clippy::approx_constant)]
 fn test_listv_partial_cmp() {
     let a: Box<dyn ListV> = Box::new(42);
     let b: Box<dyn ListV> = Box::new(43);
@@ -35,3 +41,95 @@ fn test_listv_partial_cmp() {
     let d: Box<dyn ListV> = Box::new(2.71);
     assert!(c > d);
 }
+
+fn big(s: &str) -> BigInt {
+    BigInt::from_str(s).unwrap()
+}
+fn ratio(n: i64, d: i64) -> BigRational {
+    BigRational::new(n.into(), d.into())
+}
+
+#[test]
+fn test_listv_numeric_tower_types() {
+    let i: Box<dyn ListV> = Box::new(big("123456789012345678901234567890123456789012"));
+    assert!(i.is_integer() && i.is_number());
+    let r: Box<dyn ListV> = Box::new(ratio(1, 3));
+    assert!(r.is_rational() && r.is_number() && !r.is_integer());
+    assert_eq!(r.as_string(), "1/3");
+}
+
+#[test]
+fn test_listv_mixed_numeric_eq() {
+    assert_eq!(1.to_listv(), 1.0.to_listv());
+    assert_ne!(1.to_listv(), 1.000_000_1.to_listv());
+    assert_eq!(big("42").to_listv(), 42u8.to_listv());
+    assert_eq!(ratio(4, 2).to_listv(), 2.to_listv());
+    assert_eq!(ratio(1, 2).to_listv(), 0.5.to_listv());
+    assert_ne!(ratio(1, 3).to_listv(), (1.0 / 3.0).to_listv());
+    // 超出 u128 的整数
+    let huge = big("1000000000000000000000000000000000000000000");
+    assert_eq!(huge.clone().to_listv(), huge.clone().to_listv());
+    assert_ne!(huge.to_listv(), u128::MAX.to_listv());
+    assert_ne!(f64::NAN.to_listv(), f64::NAN.to_listv());
+    assert_ne!(f64::NAN.to_listv(), 0.to_listv());
+}
+
+#[test]
+fn test_listv_mixed_numeric_cmp() {
+    let v = |x: List| x.get_basis_value();
+    assert!(v(1.to_listv()) < v(1.5.to_listv()));
+    assert!(v(ratio(1, 3).to_listv()) > v(0.333.to_listv()));
+    assert!(
+        v(big("-1000000000000000000000000000000000000000").to_listv()) < v(i128::MIN.to_listv())
+    );
+    assert!(v(u128::MAX.to_listv()) < v(f64::INFINITY.to_listv()));
+    assert!(
+        v(f64::NEG_INFINITY.to_listv())
+            < v(big("-1000000000000000000000000000000000000000").to_listv())
+    );
+    assert_eq!(v(f64::NAN.to_listv()).partial_cmp(&v(1.to_listv())), None);
+}
+
+#[test]
+fn test_listv_total_cmp() {
+    let mut values = [
+        f64::NAN.to_listv(),
+        sym!(b).to_listv(),
+        "a".to_string().to_listv(),
+        ratio(1, 2).to_listv(),
+        true.to_listv(),
+        3.to_listv(),
+        f64::NEG_INFINITY.to_listv(),
+        0.25.to_listv(),
+    ];
+    values.sort_by(|a, b| a.get_basis_value().total_cmp(b.get_basis_value().as_ref()));
+    assert_eq!(
+        List::from_slice(&values).to_scheme_string(),
+        r#"(-inf.0 0.25 1/2 3 +nan.0 #t "a" b)"#
+    );
+    let nan = f64::NAN.to_listv().get_basis_value();
+    assert_eq!(nan.total_cmp(nan.as_ref()), Ordering::Equal);
+    let one = 1.to_listv().get_basis_value();
+    assert_eq!(
+        one.total_cmp(1.0.to_listv().get_basis_value().as_ref()),
+        Ordering::Equal
+    );
+}
+
+#[test]
+fn test_parse_big_numbers() {
+    let l = List::parse("(1/3 -6/4 4/2 123456789012345678901234567890123456789012)").unwrap();
+    assert_eq!(
+        l,
+        list![
+            ratio(1, 3),
+            ratio(-3, 2),
+            2,
+            big("123456789012345678901234567890123456789012")
+        ]
+    );
+    assert!(l.tail().tail().head().try_as_basis_value::<i32>().is_ok());
+    assert_eq!(List::parse(&l.to_scheme_string()).unwrap(), l);
+    assert!(List::parse("1/0").unwrap().is_symbol());
+    assert!(List::parse("1/-2").unwrap().is_symbol());
+}
@@ -0,0 +1,198 @@
+use sicp_rs::ch2::ch2_5::{
+    ArithmeticContext, DispatchError, attach_tag, install_arithmetic_package,
+    install_dense_terms_package, install_polynomial_coercion, install_polynomial_package,
+    install_sparse_terms_package, make_complex_from_real_imag, make_float, make_integer,
+    make_polynomial_from_dense, make_rational, pretty_polynomial, raise_to_common_type, type_tag,
+};
+use sicp_rs::prelude::*;
+
+fn arith() -> ArithmeticContext {
+    let mut arith = ArithmeticContext::new();
+    install_arithmetic_package(&arith);
+    install_sparse_terms_package(&arith);
+    install_dense_terms_package(&arith);
+    install_polynomial_package(&arith);
+    install_polynomial_coercion(&mut arith);
+    for tag in ["rational", "float", "complex", "polynomial"] {
+        let arith_ = arith.clone();
+        arith.put(
+            "sum3",
+            list![tag, tag, tag],
+            ClosureWrapper::new(move |args| {
+                let xs = args.map(|x| attach_tag(tag, x));
+                let (x, y, z) = (xs.head(), xs.tail().head(), xs.tail().tail().head());
+                Some(arith_.add(&arith_.add(&x, &y), &z))
+            }),
+        );
+    }
+    arith
+}
+
+fn sum3(arith: &ArithmeticContext, x: &List, y: &List, z: &List) -> Result<List, DispatchError> {
+    arith.try_apply_generic("sum3", &list![x.clone(), y.clone(), z.clone()])
+}
+
+#[test]
+fn test_nary_tower_types() {
+    let arith = arith();
+    let one = make_integer(1, &arith);
+    let half = make_rational(1.to_listv(), 2.to_listv(), &arith);
+    let quarter = make_float(0.25, &arith);
+    let i = make_complex_from_real_imag(0.0.to_listv(), 1.0.to_listv(), &arith);
+
+    let cases = [
+        (
+            &one,
+            &half,
+            &one,
+            "rational",
+            make_rational(5.to_listv(), 2.to_listv(), &arith),
+        ),
+        (
+            &half,
+            &half,
+            &half,
+            "rational",
+            make_rational(3.to_listv(), 2.to_listv(), &arith),
+        ),
+        (&one, &quarter, &half, "float", make_float(1.75, &arith)),
+        (&quarter, &one, &one, "float", make_float(2.25, &arith)),
+        (
+            &half,
+            &i,
+            &one,
+            "complex",
+            make_complex_from_real_imag(1.5.to_listv(), 1.0.to_listv(), &arith),
+        ),
+        (
+            &i,
+            &quarter,
+            &i,
+            "complex",
+            make_complex_from_real_imag(0.25.to_listv(), 2.0.to_listv(), &arith),
+        ),
+    ];
+    for (x, y, z, tag, expected) in cases {
+        let result = sum3(&arith, x, y, z).unwrap();
+        assert_eq!(
+            type_tag(&result),
+            tag.to_listv(),
+            "sum3({}, {}, {})",
+            x,
+            y,
+            z
+        );
+        assert_eq!(arith.is_equal(&result, &expected), true.to_listv());
+    }
+}
+
+#[test]
+fn test_nary_polynomial_coercion() {
+    let arith = arith();
+    let x = sym!(x).to_listv();
+    let p = make_polynomial_from_dense(&x, &list![1, 2], &arith);
+    let one = make_integer(1, &arith);
+    let half = make_rational(1.to_listv(), 2.to_listv(), &arith);
+    let quarter = make_float(0.25, &arith);
+    let i = make_complex_from_real_imag(0.0.to_listv(), 1.0.to_listv(), &arith);
+
+    let cases = [
+        (
+            &p,
+            &one,
+            &one,
+            make_polynomial_from_dense(&x, &list![1, 4], &arith),
+        ),
+        (
+            &one,
+            &p,
+            &one,
+            make_polynomial_from_dense(&x, &list![1, 4], &arith),
+        ),
+        (
+            &quarter,
+            &one,
+            &p,
+            make_polynomial_from_dense(&x, &list![1, 3.25], &arith),
+        ),
+        (
+            &half,
+            &p,
+            &p,
+            make_polynomial_from_dense(
+                &x,
+                &list![2, make_rational(9.to_listv(), 2.to_listv(), &arith)],
+                &arith,
+            ),
+        ),
+    ];
+    for (a, b, c, expected) in cases {
+        let result = sum3(&arith, a, b, c).unwrap();
+        assert_eq!(
+            pretty_polynomial(&result, &arith),
+            pretty_polynomial(&expected, &arith),
+            "sum3({}, {}, {})",
+            a,
+            b,
+            c
+        );
+    }
+    let result = sum3(&arith, &i, &p, &half).unwrap();
+    assert_eq!(type_tag(&result), "polynomial".to_listv());
+}
+
+#[test]
+fn test_nary_coercion_to_later_argument_type() {
+    let mut arith = ArithmeticContext::new();
+    arith.put(
+        "join",
+        list!["b", "b", "b", "b"],
+        ClosureWrapper::new(|args| Some(pair!("b", args.clone()))),
+    );
+    arith.put_coercion(
+        &"a".to_listv(),
+        &"b".to_listv(),
+        ClosureWrapper::new(|args| Some(pair!("b", args.head().tail()))),
+    );
+    let (a, b) = (pair!("a", 1), pair!("b", 2));
+    let args = list![a.clone(), b.clone(), a.clone(), a.clone()];
+    assert_eq!(
+        arith.try_apply_generic("join", &args),
+        Ok(pair!("b", list![1, 2, 1, 1]))
+    );
+    // 没有 b 到 a 的转换，也没有 (a a a a) 的过程
+    let types = |tags: &[&str]| tags.iter().map(|t| t.to_string()).collect::<Vec<_>>();
+    assert_eq!(
+        arith.try_apply_generic(
+            "join",
+            &list![pair!("c", 1), b.clone(), b.clone(), b.clone()]
+        ),
+        Err(DispatchError::NoMethod {
+            op: "join".to_string(),
+            types: types(&["c", "b", "b", "b"]),
+        })
+    );
+    assert_eq!(
+        arith.try_apply_generic("join", &list![a, b]),
+        Err(DispatchError::ArityMismatch {
+            op: "join".to_string(),
+            types: types(&["a", "b"]),
+        })
+    );
+}
+
+#[test]
+fn test_raise_to_common_type() {
+    let arith = arith();
+    let args = list![
+        make_integer(1, &arith),
+        make_float(0.5, &arith),
+        make_rational(1.to_listv(), 2.to_listv(), &arith)
+    ];
+    let raised = raise_to_common_type(&args, &arith).unwrap();
+    assert_eq!(raised.map(type_tag), list!["float", "float", "float"]);
+    assert_eq!(raise_to_common_type(&List::Nil, &arith), None);
+    // polynomial 未在类型格中注册
+    let p = make_polynomial_from_dense(&sym!(x).to_listv(), &list![1, 2], &arith);
+    assert_eq!(raise_to_common_type(&args.append(&list![p]), &arith), None);
+}
@@ -0,0 +1,87 @@
+use std::cmp::Ordering;
+
+use sicp_rs::ch2::ch2_5::{ArithmeticContext, Number, install_arithmetic_package, make_integer};
+use sicp_rs::prelude::*;
+
+fn arith() -> ArithmeticContext {
+    let arith = ArithmeticContext::new();
+    install_arithmetic_package(&arith);
+    arith
+}
+
+#[test]
+fn test_number_from_literals() {
+    let arith = arith();
+    assert_eq!(arith.number(3).type_tag(), "integer");
+    assert_eq!(arith.number(1.5).type_tag(), "float");
+    assert_eq!(arith.number((1, 2)).type_tag(), "rational");
+    assert_eq!(arith.number((1.0, 2.0)).type_tag(), "complex");
+    assert_eq!(
+        Number::from_list(make_integer(3, &arith), &arith),
+        Number::new(3, &arith)
+    );
+    assert_eq!(arith.number(3).into_list(), 3.to_listv());
+}
+
+#[test]
+fn test_number_arithmetic() {
+    let arith = arith();
+    let (a, b) = (arith.number(6), arith.number(4));
+    assert_eq!(&a + &b, arith.number(10));
+    assert_eq!(&a - &b, arith.number(2));
+    assert_eq!(&a * &b, arith.number(24));
+    // 整数除法与 integer 包一致，向零取整
+    assert_eq!(&a / &b, arith.number(1));
+    assert_eq!(&a / arith.number((4, 1)), arith.number((3, 2)));
+    assert_eq!(-a.clone(), arith.number(-6));
+
+    // 字面量可出现在运算符任一侧
+    assert_eq!(a.clone() + 1, arith.number(7));
+    assert_eq!(1 - a.clone(), arith.number(-5));
+    assert_eq!(0.5 * b.clone(), arith.number(2.0));
+    assert_eq!(&b / (1, 2), arith.number(8));
+}
+
+#[test]
+fn test_number_mixed_types() {
+    let arith = arith();
+    let half = arith.number((1, 2));
+    assert_eq!(&half + &half, arith.number(1));
+    assert_eq!(&half + 0.25, arith.number(0.75));
+    let z = arith.number((1.0, 2.0)) + &half;
+    assert_eq!(z, arith.number((1.5, 2.0)));
+    assert_eq!(z - arith.number((0.0, 2.0)), arith.number(1.5));
+}
+
+#[test]
+fn test_number_ordering() {
+    let arith = arith();
+    let mut xs = [
+        arith.number(2),
+        arith.number((-1, 3)),
+        arith.number(0.5),
+        arith.number((3, -4)),
+        arith.number(0),
+    ];
+    xs.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    let shown: Vec<String> = xs.iter().map(|x| x.to_string()).collect();
+    assert_eq!(shown, ["-3/4", "-1/3", "0", "0.5", "2"]);
+
+    assert!(arith.number((1, 2)) <= arith.number(0.5));
+    assert_eq!(
+        arith.number(2).partial_cmp(&arith.number((4, 2))),
+        Some(Ordering::Equal)
+    );
+    assert_eq!(arith.number((1.0, 1.0)).partial_cmp(&arith.number(1)), None);
+}
+
+#[test]
+fn test_number_display() {
+    let arith = arith();
+    assert_eq!(arith.number(-7).to_string(), "-7");
+    assert_eq!(arith.number(2.5).to_string(), "2.5");
+    assert_eq!(arith.number((2, 6)).to_string(), "1/3");
+    assert_eq!(arith.number((1.0, -2.0)).to_string(), "1.0-2.0i");
+    assert_eq!(arith.number((1.0, 2.0)).to_string(), "1.0+2.0i");
+    assert_eq!(format!("{:?}", arith.number(1)), "Number(V(1))");
+}
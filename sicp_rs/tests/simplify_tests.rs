@@ -0,0 +1,184 @@
+use sicp_rs::ch2::ch2_3::simplify::{deriv, infix_to_prefix, prefix_to_infix, simplify};
+use sicp_rs::prelude::*;
+
+fn parse(s: &str) -> List {
+    List::parse(s).unwrap()
+}
+
+fn assert_simplifies(exp: &str, expected: &str) {
+    assert_eq!(simplify(&parse(exp)), parse(expected), "simplify {}", exp);
+}
+
+fn assert_deriv(exp: &str, expected: &str) {
+    let x = sym!(x).to_listv();
+    assert_eq!(deriv(&parse(exp), &x), parse(expected), "deriv {}", exp);
+}
+
+#[test]
+fn test_flatten_and_collect() {
+    assert_simplifies("(+ (* x 0) (* 1 y))", "y");
+    assert_simplifies("(+ x (* 2 x) (- x 3) 3)", "(* 4 x)");
+    assert_simplifies("(+ x (+ y (+ z 1)) 2)", "(+ x y z 3)");
+    assert_simplifies("(* 2 (* x (* 3 y)))", "(* 6 x y)");
+    assert_simplifies("(+ (* x y) (* y x 2))", "(* 3 x y)");
+    assert_simplifies("(- x x)", "0");
+    assert_simplifies("(- x)", "(* -1 x)");
+    assert_simplifies("(+ 1/3 2/3 0.5)", "3/2");
+    assert_simplifies("(f x (+ x x))", "(f x (* 2 x))");
+    // 项的顺序不影响结果
+    assert_eq!(
+        simplify(&parse("(+ (* y 3) x (** x 2))")),
+        simplify(&parse("(+ (** x 2) x (* 3 y))"))
+    );
+}
+
+#[test]
+fn test_powers_and_quotients() {
+    assert_simplifies("(* x (** x 2) (/ 1 x))", "(** x 2)");
+    assert_simplifies("(* x (** x n))", "(** x (+ n 1))");
+    assert_simplifies("(** (** x 2) 3)", "(** x 6)");
+    assert_simplifies("(** (** x 1/2) 2)", "x");
+    assert_simplifies("(** (* 2 x) 3)", "(* 8 (** x 3))");
+    assert_simplifies("(** 2 10)", "1024");
+    assert_simplifies("(** x 0)", "1");
+    assert_simplifies("(/ (* 6 x y) (* 4 y))", "(* 3/2 x)");
+    assert_simplifies("(/ x y z)", "(* x (** y -1) (** z -1))");
+}
+
+#[test]
+fn test_large_powers_stay_symbolic() {
+    assert_eq!(
+        simplify(&parse("(** 2 100)")),
+        parse("1267650600228229401496703205376")
+    );
+    assert_simplifies("(** 2 100000)", "(** 2 100000)");
+    assert_simplifies("(** 10 2147483647)", "(** 10 2147483647)");
+    assert_simplifies("(** (** 3 100000) 2)", "(** 3 200000)");
+    assert_simplifies("(** 1/2 -100000)", "(** 1/2 -100000)");
+}
+
+#[test]
+fn test_expand_sums() {
+    assert_simplifies("(* (+ x 1) (- x 1))", "(+ (** x 2) -1)");
+    assert_simplifies("(- (** (+ x 1) 2) (* x x) (* 2 x) 1)", "0");
+    assert_simplifies("(* 2 (- x y))", "(+ (* 2 x) (* -2 y))");
+    assert_simplifies("(- x (+ x y))", "(* -1 y)");
+    // 负指数的和式不展开
+    assert_simplifies("(* (+ x 1) (/ 1 (+ x 1)))", "1");
+    assert_simplifies("(/ 1 (+ x 1))", "(** (+ x 1) -1)");
+}
+
+#[test]
+fn test_functions() {
+    assert_simplifies(
+        "(+ (sin 0) (cos 0) (exp 0) (log 1) (log (exp y)))",
+        "(+ y 2)",
+    );
+    assert_simplifies("(- (sin x) (sin (+ x 0)))", "0");
+}
+
+#[test]
+#[should_panic(expected = "division by zero")]
+fn test_division_by_zero() {
+    simplify(&parse("(/ x (- y y))"));
+}
+
+#[test]
+fn test_deriv_sum_product_power() {
+    assert_deriv("(+ x 3)", "1");
+    assert_deriv("(* x y)", "y");
+    assert_deriv("(* (* x y) (+ x 3))", "(+ (* 3 y) (* 2 x y))");
+    assert_deriv("(- (* 3 x) (* x x))", "(+ (* -2 x) 3)");
+    assert_deriv("(** x 3)", "(* 3 (** x 2))");
+    assert_deriv("(** x n)", "(* n (** x (+ n -1)))");
+    assert_deriv("(** 2 x)", "(* (** 2 x) (log 2))");
+    assert_deriv("(** x x)", "(+ (* (** x x) (log x)) (** x x))");
+    assert_deriv("(** (+ (* x x) 1) 1/2)", "(* x (** (+ (** x 2) 1) -1/2))");
+}
+
+#[test]
+fn test_deriv_quotient() {
+    assert_deriv("(/ 1 x)", "(* -1 (** x -2))");
+    assert_deriv("(/ x (+ x 1))", "(** (+ x 1) -2)");
+    assert_deriv("(/ (+ x 1) (- x 1))", "(* -2 (** (+ x -1) -2))");
+    assert_deriv("(/ (* x x) x)", "1");
+    assert_deriv("(/ x y z)", "(* (** y -1) (** z -1))");
+}
+
+#[test]
+fn test_deriv_functions() {
+    assert_deriv("(sin (* 2 x))", "(* 2 (cos (* 2 x)))");
+    assert_deriv("(cos (** x 2))", "(* -2 x (sin (** x 2)))");
+    assert_deriv("(exp (* 3 x))", "(* 3 (exp (* 3 x)))");
+    assert_deriv("(log (+ x 1))", "(** (+ x 1) -1)");
+    assert_deriv("(log (exp x))", "1");
+    assert_deriv(
+        "(* (sin x) (cos x))",
+        "(+ (** (cos x) 2) (* -1 (** (sin x) 2)))",
+    );
+}
+
+#[test]
+#[should_panic(expected = "unknown expression type -- DERIV")]
+fn test_deriv_unknown_operator() {
+    deriv(&parse("(tan x)"), &sym!(x).to_listv());
+}
+
+#[test]
+fn test_infix() {
+    // 练习 2.58
+    let exp = parse("(x + 3 * (x + y + 2))");
+    assert_eq!(infix_to_prefix(&exp), parse("(+ x (* 3 (+ (+ x y) 2)))"));
+    let x = sym!(x).to_listv();
+    assert_eq!(deriv(&infix_to_prefix(&exp), &x), parse("4"));
+    assert_eq!(
+        prefix_to_infix(&deriv(&infix_to_prefix(&parse("(x * y * (x + 3))")), &x)),
+        parse("(3 * y + 2 * x * y)")
+    );
+
+    assert_eq!(
+        infix_to_prefix(&parse("(x ** 2 ** 3)")),
+        parse("(** x (** 2 3))")
+    );
+    assert_eq!(
+        infix_to_prefix(&parse("(x - y - z)")),
+        parse("(- (- x y) z)")
+    );
+    assert_eq!(
+        infix_to_prefix(&parse("(- x ** 2 + sin x * 2)")),
+        parse("(+ (- (** x 2)) (* (sin x) 2))")
+    );
+    assert_eq!(infix_to_prefix(&parse("((x))")), x);
+}
+
+#[test]
+fn test_prefix_to_infix() {
+    let cases = [
+        ("(+ x (* 3 x y))", "(x + 3 * x * y)"),
+        ("(* (+ x 1) y)", "((x + 1) * y)"),
+        ("(- x (- y z))", "(x - (y - z))"),
+        ("(/ x (* y z))", "(x / (y * z))"),
+        ("(** (** x 2) 3)", "((x ** 2) ** 3)"),
+        ("(** x (** 2 3))", "(x ** 2 ** 3)"),
+        ("(sin (+ x 1))", "(sin (x + 1))"),
+        ("(* 2 (sin x))", "(2 * sin x)"),
+        ("(- (** x 2))", "(- x ** 2)"),
+    ];
+    for (prefix, infix) in cases {
+        assert_eq!(prefix_to_infix(&parse(prefix)), parse(infix), "{}", prefix);
+        // 中缀解析得到二元形式，化简后相同
+        assert_eq!(
+            simplify(&infix_to_prefix(&parse(infix))),
+            simplify(&parse(prefix)),
+            "{}",
+            infix
+        );
+    }
+    assert_eq!(prefix_to_infix(&sym!(x).to_listv()), sym!(x).to_listv());
+}
+
+#[test]
+#[should_panic(expected = "missing operand -- INFIX")]
+fn test_infix_missing_operand() {
+    infix_to_prefix(&parse("(x +)"));
+}
@@ -0,0 +1,169 @@
+use sicp_rs::ch2::ch2_5::{
+    ArithmeticContext, DispatchError, install_arithmetic_package, make_complex_from_real_imag,
+    make_float, make_integer, make_rational, type_tag,
+};
+use sicp_rs::prelude::*;
+
+fn arith() -> ArithmeticContext {
+    let arith = ArithmeticContext::new();
+    install_arithmetic_package(&arith);
+    arith
+}
+
+fn value(x: &List) -> i32 {
+    *x.try_as_basis_value::<i32>().unwrap()
+}
+
+fn retag(tag: &'static str) -> ClosureWrapper {
+    ClosureWrapper::new(move |args| Some(pair!(tag, args.head())))
+}
+
+#[test]
+fn test_builtin_tower() {
+    let arith = arith();
+    assert_eq!(arith.type_rank(&"integer".to_listv()), Some(0));
+    assert_eq!(arith.type_rank(&"complex".to_listv()), Some(5));
+    assert_eq!(arith.type_rank(&"polynomial".to_listv()), None);
+    assert!(arith.is_subtype(&"integer".to_listv(), &"float".to_listv()));
+    assert!(arith.is_subtype(&"float".to_listv(), &"float".to_listv()));
+    assert!(!arith.is_subtype(&"float".to_listv(), &"rational".to_listv()));
+    assert_eq!(
+        arith.least_common_supertype(&list!["rational", "integer", "float"]),
+        Some("float".to_listv())
+    );
+
+    let three = make_integer(3, &arith);
+    let raised = arith.raise_to(&three, &"complex".to_listv()).unwrap();
+    assert_eq!(
+        raised,
+        make_complex_from_real_imag(3.0.to_listv(), 0.0.to_listv(), &arith)
+    );
+    assert_eq!(
+        arith.raise_to(&make_float(1.5, &arith), &"integer".to_listv()),
+        None
+    );
+    assert_eq!(arith.drop(&raised), three);
+    assert_eq!(
+        arith.drop(&make_float(0.5, &arith)),
+        make_rational(1.to_listv(), 2.to_listv(), &arith)
+    );
+    assert_eq!(arith.raise(&raised), raised);
+}
+
+#[test]
+fn test_user_type_below_integer() {
+    let arith = arith();
+    arith.register_type(
+        "natural",
+        -1,
+        &[("integer", ClosureWrapper::new(|args| Some(args.head())))],
+        None,
+    );
+    let n = pair!("natural", 3);
+    let half = make_rational(1.to_listv(), 2.to_listv(), &arith);
+    assert_eq!(
+        arith.add(&n, &half),
+        make_rational(7.to_listv(), 2.to_listv(), &arith)
+    );
+    assert_eq!(
+        arith.mul(&make_float(0.5, &arith), &n),
+        make_float(1.5, &arith)
+    );
+    // natural 注册后也可通过 apply_generic 调用 raise
+    assert_eq!(arith.apply_generic("raise", &list![n]), Some(3.to_listv()));
+}
+
+// a 有两个超类型 b、c，二者的超类型均为 d
+fn diamond() -> ArithmeticContext {
+    let arith = ArithmeticContext::new();
+    let only_even = |tag: &'static str| {
+        ClosureWrapper::new(move |args: &List| {
+            (value(&args.head()) % 2 == 0).then(|| pair!(tag, args.head()))
+        })
+    };
+    arith.register_type("a", 0, &[("b", retag("b")), ("c", retag("c"))], None);
+    arith.register_type("b", 1, &[("d", retag("d"))], Some(only_even("a")));
+    arith.register_type("c", 1, &[("d", retag("d"))], None);
+    arith.register_type("d", 2, &[], Some(retag("b")));
+    for tag in ["b", "d"] {
+        arith.put(
+            "join",
+            list![tag, tag],
+            ClosureWrapper::new(move |args| {
+                Some(list![tag, value(&args.head()) + value(&args.tail().head())])
+            }),
+        );
+        arith.put(
+            "is_equal",
+            list![tag, tag],
+            ClosureWrapper::new(|args| {
+                Some((value(&args.head()) == value(&args.tail().head())).to_listv())
+            }),
+        );
+    }
+    arith
+}
+
+#[test]
+fn test_diamond_lattice() {
+    let arith = diamond();
+    let (a, b, c) = (pair!("a", 1), pair!("b", 2), pair!("c", 4));
+    let tags = |tags: &[&str]| {
+        tags.iter()
+            .map(|t| t.to_string().to_listv())
+            .collect::<List>()
+    };
+
+    assert_eq!(
+        arith.least_common_supertype(&tags(&["a", "b"])),
+        Some("b".to_listv())
+    );
+    assert_eq!(
+        arith.least_common_supertype(&tags(&["b", "c"])),
+        Some("d".to_listv())
+    );
+    assert_eq!(
+        arith.least_common_supertype(&tags(&["a", "c"])),
+        Some("c".to_listv())
+    );
+    assert_eq!(arith.least_common_supertype(&tags(&["a", "x"])), None);
+
+    assert_eq!(arith.raise(&a), pair!("b", 1));
+    assert_eq!(arith.raise_to(&a, &"d".to_listv()), Some(pair!("d", 1)));
+    assert_eq!(arith.raise_to(&b, &"c".to_listv()), None);
+
+    let join = |x: &List, y: &List| arith.try_apply_generic("join", &list![x.clone(), y.clone()]);
+    assert_eq!(join(&a, &b), Ok(list!["b", 3]));
+    assert_eq!(join(&b, &c), Ok(list!["d", 6]));
+    // 最低公共超类型 c 上没有 join，报告原始的参数类型
+    assert_eq!(
+        join(&a, &c),
+        Err(DispatchError::NoMethod {
+            op: "join".to_string(),
+            types: vec!["a".to_string(), "c".to_string()],
+        })
+    );
+}
+
+#[test]
+fn test_diamond_drop() {
+    let arith = diamond();
+    assert_eq!(arith.drop(&pair!("d", 4)), pair!("a", 4));
+    assert_eq!(arith.drop(&pair!("d", 3)), pair!("b", 3));
+    assert_eq!(
+        arith.drop_to_type(&pair!("d", 4), "b".to_string()),
+        pair!("b", 4)
+    );
+    assert_eq!(arith.project(&pair!("b", 3)), pair!("b", 3));
+    assert_eq!(type_tag(&arith.drop(&pair!("c", 4))), "c".to_listv());
+}
+
+#[test]
+#[allow(deprecated)]
+fn test_find_arithmetic_type_index_shim() {
+    use sicp_rs::ch2::ch2_5::find_arithmetic_type_index;
+    assert_eq!(find_arithmetic_type_index("integer"), 0);
+    assert_eq!(find_arithmetic_type_index("complex"), 3);
+    assert_eq!(find_arithmetic_type_index("bigint"), -1);
+    assert_eq!(find_arithmetic_type_index("polynomial"), -1);
+}